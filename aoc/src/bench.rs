@@ -0,0 +1,268 @@
+//! Times parsing and solving every selected part, and keeps the timings in a CSV file so a run
+//! can be compared with the one before it.
+//!
+//! The file has a header and a row for every day, part and stage (`parse` or `solve`):
+//!
+//! ```text
+//! day,part,stage,iterations,min_ns,median_ns,max_ns
+//! 5,1,parse,10,181204,183377,201350
+//! ```
+//!
+//! Only the rows for the parts that were run are replaced, so benchmarking a single day keeps the
+//! timings every other day had in the file.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use aoc_common::{PartTimings, Timings};
+use crate::cli::BenchOptions;
+use crate::days::Solution;
+
+const HEADER: &str = "day,part,stage,iterations,min_ns,median_ns,max_ns";
+
+/// The timings of one stage of a part, as written in the results file.
+#[derive(Debug, Clone, PartialEq)]
+struct Row {
+    iterations: usize,
+    min_ns: u128,
+    median_ns: u128,
+    max_ns: u128,
+}
+
+/// The rows of the results file keyed by day, part and stage, which also keeps them in file order.
+type Rows = BTreeMap<(u32, u32, String), Row>;
+
+/// Benchmarks each solution against the input `input_for` returns for its day, reading each day's input once.
+pub fn run<F>(solutions: &[&Solution], options: &BenchOptions, input_for: F) -> Result<(), String>
+where
+    F: Fn(u32) -> Result<String, String>,
+{
+    let mut rows = read_rows(&options.results)?;
+
+    let results = time_all(solutions, options.iterations, input_for);
+
+    print_table(&results, &rows);
+
+    merge(&mut rows, &results);
+    fs::write(&options.results, to_csv(&rows))
+        .map_err(|e| format!("Failed to write bench results '{}' - {}", options.results.display(), e))?;
+    println!("\nResults written to {}", options.results.display());
+
+    Ok(())
+}
+
+/// Times every solution, reading each day's input once. A part that fails, or whose input can't be
+/// read, is reported in its row rather than stopping the rest of the run.
+fn time_all<'a, F>(solutions: &[&'a Solution], iterations: usize, input_for: F) -> Vec<(&'a Solution, Result<PartTimings, String>)>
+where
+    F: Fn(u32) -> Result<String, String>,
+{
+    let mut results = vec![];
+    let mut input: Option<(u32, Result<String, String>)> = None;
+    for &solution in solutions.iter() {
+        if input.as_ref().map(|(day, _)| *day) != Some(solution.day) {
+            input = Some((solution.day, input_for(solution.day)));
+        }
+        let result = match &input.as_ref().unwrap().1 {
+            Ok(text) => (solution.time)(text, iterations).map_err(|e| e.to_string()),
+            Err(e) => Err(e.clone()),
+        };
+        results.push((solution, result));
+    }
+    results
+}
+
+fn stages(timings: &PartTimings) -> [(&'static str, &Timings); 2] {
+    [("parse", timings.parse()), ("solve", timings.solve())]
+}
+
+fn print_table(results: &[(&Solution, Result<PartTimings, String>)], previous: &Rows) {
+    println!("Day | Part | Stage | {:>10} | {:>10} | {:>10} | vs previous", "Min", "Median", "Max");
+    println!("----+------+-------+------------+------------+------------+------------");
+    for (solution, result) in results.iter() {
+        match result {
+            Ok(timings) => {
+                for (stage, timings) in stages(timings) {
+                    let change = previous
+                        .get(&(solution.day, solution.part, String::from(stage)))
+                        .map(|before| change(before.median_ns, timings.median().as_nanos()))
+                        .unwrap_or_default();
+                    println!(
+                        "{:>3} | {:>4} | {:<5} | {:>10} | {:>10} | {:>10} | {}",
+                        solution.day,
+                        solution.part,
+                        stage,
+                        format_duration(timings.min()),
+                        format_duration(timings.median()),
+                        format_duration(timings.max()),
+                        change,
+                    );
+                }
+            },
+            Err(e) => println!("{:>3} | {:>4} | error: {}", solution.day, solution.part, e),
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+/// How much the median moved from the previous run, where a negative change is faster.
+fn change(before: u128, after: u128) -> String {
+    if before == 0 {
+        return String::new();
+    }
+    let percent = (after as f64 - before as f64) / before as f64 * 100.0;
+    format!("{:+.1}%", percent)
+}
+
+/// Replaces the rows of every part that was timed, leaving the rest as they were - a failing part
+/// keeps its timings from the last run it passed.
+fn merge(rows: &mut Rows, results: &[(&Solution, Result<PartTimings, String>)]) {
+    for (solution, result) in results.iter() {
+        let Ok(timings) = result else {
+            continue;
+        };
+        for (stage, timings) in stages(timings) {
+            let row = Row {
+                iterations: timings.iterations(),
+                min_ns: timings.min().as_nanos(),
+                median_ns: timings.median().as_nanos(),
+                max_ns: timings.max().as_nanos(),
+            };
+            rows.insert((solution.day, solution.part, String::from(stage)), row);
+        }
+    }
+}
+
+fn to_csv(rows: &Rows) -> String {
+    let mut csv = format!("{}\n", HEADER);
+    for ((day, part, stage), row) in rows.iter() {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            day,
+            part,
+            stage,
+            row.iterations,
+            row.min_ns,
+            row.median_ns,
+            row.max_ns,
+        ));
+    }
+    csv
+}
+
+/// The rows from earlier runs, or none when this is the first run writing to the file.
+fn read_rows(path: &Path) -> Result<Rows, String> {
+    match fs::read_to_string(path) {
+        Ok(csv) => parse_rows(&csv).map_err(|e| format!("Failed to read bench results '{}' - {}", path.display(), e)),
+        Err(_) if !path.exists() => Ok(BTreeMap::new()),
+        Err(e) => Err(format!("Failed to read bench results '{}' - {}", path.display(), e)),
+    }
+}
+
+fn parse_rows(csv: &str) -> Result<Rows, String> {
+    let mut lines = csv.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header == HEADER => {},
+        _ => return Err(format!("expected the header '{}'", HEADER)),
+    }
+
+    let mut rows = BTreeMap::new();
+    for (index, line) in lines.filter(|(_, l)| !l.trim().is_empty()) {
+        let invalid = || format!("line {} is not a row of bench results", index + 1);
+        let fields: Vec<&str> = line.split(',').collect();
+        if fields.len() != 7 {
+            return Err(invalid());
+        }
+        let day = fields[0].parse().map_err(|_| invalid())?;
+        let part = fields[1].parse().map_err(|_| invalid())?;
+        let row = Row {
+            iterations: fields[3].parse().map_err(|_| invalid())?,
+            min_ns: fields[4].parse().map_err(|_| invalid())?,
+            median_ns: fields[5].parse().map_err(|_| invalid())?,
+            max_ns: fields[6].parse().map_err(|_| invalid())?,
+        };
+        rows.insert((day, part, String::from(fields[2])), row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_round_trip() {
+        let solution = crate::days::find(1, 1).unwrap();
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let results = vec![(solution, (solution.time)(input, 3).map_err(|e| e.to_string()))];
+
+        let mut rows = BTreeMap::new();
+        merge(&mut rows, &results);
+        let csv = to_csv(&rows);
+        assert!(csv.starts_with(HEADER));
+        assert_eq!(3, csv.lines().count());
+
+        let rows = parse_rows(&csv).unwrap();
+        assert_eq!(2, rows.len());
+        assert!(rows.contains_key(&(1, 1, String::from("parse"))));
+        assert_eq!(3, rows[&(1, 1, String::from("solve"))].iterations);
+    }
+
+    #[test]
+    fn test_merge_keeps_other_parts() {
+        let mut rows = parse_rows(&format!("{}\n1,1,parse,3,1,2,3\n1,1,solve,3,1,2,3\n2,1,solve,3,4,5,6\n", HEADER)).unwrap();
+
+        let solution = crate::days::find(1, 1).unwrap();
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let failed = crate::days::find(2, 2).unwrap();
+        let results = vec![
+            (solution, (solution.time)(input, 5).map_err(|e| e.to_string())),
+            (failed, Err(String::from("Failed to parse input"))),
+        ];
+        merge(&mut rows, &results);
+
+        assert_eq!(3, rows.len());
+        assert_eq!(5, rows[&(1, 1, String::from("parse"))].iterations);
+        assert_eq!(5, rows[&(1, 1, String::from("solve"))].iterations);
+        // day 2 wasn't timed this run so its row is written back untouched
+        assert_eq!("2,1,solve,3,4,5,6", to_csv(&rows).lines().last().unwrap());
+    }
+
+    #[test]
+    fn test_missing_input_keeps_going() {
+        let solutions = [crate::days::find(1, 1).unwrap(), crate::days::find(2, 1).unwrap(), crate::days::find(2, 2).unwrap()];
+        let input_for = |day| match day {
+            1 => Ok(String::from("199\n200\n208\n")),
+            _ => Err(format!("Failed to read the input for day {}", day)),
+        };
+        let results = time_all(&solutions, 2, input_for);
+
+        assert_eq!(3, results.len());
+        assert!(results[0].1.is_ok());
+        assert_eq!(Some(&String::from("Failed to read the input for day 2")), results[1].1.as_ref().err());
+        assert_eq!(2, results[2].0.part);
+        assert!(results[2].1.is_err());
+    }
+
+    #[test]
+    fn test_parse_rows_errors() {
+        assert!(parse_rows("").is_err());
+        assert!(parse_rows("day,part\n").is_err());
+        assert_eq!(
+            Err(String::from("line 3 is not a row of bench results")),
+            parse_rows(&format!("{}\n1,1,parse,3,1,2,3\n1,1,solve,3,1,two,3\n", HEADER))
+        );
+    }
+
+    #[test]
+    fn test_change() {
+        assert_eq!("-50.0%", change(200, 100));
+        assert_eq!("+10.0%", change(100, 110));
+        assert_eq!("+0.0%", change(100, 100));
+        assert_eq!("", change(0, 100));
+    }
+}
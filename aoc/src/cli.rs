@@ -0,0 +1,534 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use aoc_common::{ImageFormat, InputSource};
+use crate::generate;
+
+pub const USAGE: &str = "Usage:
+    aoc run --day <day> [--part <part>] [--input <path>] [--format <text|json>] [--bench <iterations> [--results <path>]] [--jobs <threads>]
+    aoc run --day <day> [--part <part>] [--input <path>] [--visualize [--delay <ms>]] [--frames <directory>]
+    aoc run --day <day> --part <part> [--input <path>] --export <file>
+    aoc run --all [--format <text|json>] [--bench <iterations> [--results <path>]] [--jobs <threads>]
+    aoc generate --day <day> --size <size> [--seed <seed>] [--output <path>]
+    aoc generate --list
+    aoc serve
+
+An input of - reads the puzzle input from stdin.
+--format json prints a JSON object on its own line for every part, holding the day, part, answer and
+the named values worked out along the way, or the error when the part failed.
+generate writes a random input for a day to stdout (or --output), where the size is a number or
+dimensions like 100x5 - --list shows what each day's size means. The same seed gives the same input.
+--bench times parsing and solving each part over the given number of iterations and writes the
+timings to bench_results.csv (or the --results path), comparing them with the file's previous run.
+--visualize animates the parts that can draw their working in the terminal, waiting --delay
+milliseconds (default 100) between frames. --frames writes every frame to the directory as a plain
+text file instead of (or as well as) animating them.
+--export draws a picture of the part's result into a .ppm, .pgm or .svg file, picked by its extension.
+--jobs sets how many threads the solvers split their work across (default one per core). --all also
+solves that many days at the same time, except with --bench so that the timings don't disturb each other.
+serve reads requests like {\"day\":22,\"part\":2,\"input\":\"...\"} from stdin, one per line, and writes a
+JSON response to stdout for each with the answer and timings, or the error, until stdin is closed.";
+
+pub const DEFAULT_RESULTS: &str = "bench_results.csv";
+
+pub const DEFAULT_DELAY: u64 = 100;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Generate(GenerateOptions),
+    ListGenerators,
+    Serve,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All,
+    Day(u32, Option<u32>),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub selection: Selection,
+    pub input: Option<InputSource>,
+    pub format: Format,
+    pub bench: Option<BenchOptions>,
+    pub visualize: Option<VisualizeOptions>,
+    pub export: Option<ExportOptions>,
+    pub jobs: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GenerateOptions {
+    pub day: u32,
+    pub size: Vec<usize>,
+    pub seed: u64,
+    pub output: Option<PathBuf>,
+}
+
+pub const DEFAULT_SEED: u64 = 2021;
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub iterations: usize,
+    pub results: PathBuf,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VisualizeOptions {
+    pub animate: bool,
+    pub delay: Duration,
+    pub frames: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExportOptions {
+    pub path: PathBuf,
+    pub format: ImageFormat,
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => parse_run(args).map(Command::Run),
+        Some("generate") => parse_generate(args),
+        Some("serve") => match args.next() {
+            Some(arg) => Err(format!("serve takes no options - found '{}'", arg)),
+            None => Ok(Command::Serve),
+        },
+        Some(other) => Err(format!("Unknown command '{}'", other)),
+        None => Err(String::from("Missing command")),
+    }
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<RunOptions, String> {
+    let mut all = false;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::Text;
+    let mut iterations = None;
+    let mut results = None;
+    let mut animate = false;
+    let mut delay = None;
+    let mut frames = None;
+    let mut export = None;
+    let mut jobs = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            "--part" => part = Some(parse_number("--part", args.next())?),
+            "--input" => input = Some(InputSource::from(&expect_value("--input", args.next())?)),
+            "--format" => format = parse_format(args.next())?,
+            "--bench" => iterations = Some(parse_number::<usize>("--bench", args.next())?),
+            "--results" => results = Some(PathBuf::from(expect_value("--results", args.next())?)),
+            "--visualize" => animate = true,
+            "--delay" => delay = Some(parse_number("--delay", args.next())?),
+            "--frames" => frames = Some(PathBuf::from(expect_value("--frames", args.next())?)),
+            "--export" => export = Some(parse_export(args.next())?),
+            "--jobs" => jobs = Some(parse_number::<usize>("--jobs", args.next())?),
+            _ => return Err(format!("Unknown option '{}'", arg)),
+        }
+    }
+
+    let selection = match (all, day) {
+        (true, None) => {
+            if part.is_some() || input.is_some() {
+                return Err(String::from("--all runs every day against its own input and cannot be combined with --part or --input"));
+            }
+            Selection::All
+        },
+        (false, Some(day)) => {
+            if let Some(part) = part {
+                if part != 1 && part != 2 {
+                    return Err(format!("Part should be 1 or 2 - found {}", part));
+                }
+            }
+            Selection::Day(day, part)
+        },
+        (true, Some(_)) => return Err(String::from("--all and --day cannot be used together")),
+        (false, None) => return Err(String::from("Expected either --day or --all")),
+    };
+
+    let bench = match (iterations, results) {
+        (Some(0), _) => return Err(String::from("--bench needs at least 1 iteration")),
+        (Some(iterations), results) => Some(BenchOptions {
+            iterations,
+            results: results.unwrap_or_else(|| PathBuf::from(DEFAULT_RESULTS)),
+        }),
+        (None, Some(_)) => return Err(String::from("--results can only be used with --bench")),
+        (None, None) => None,
+    };
+    if bench.is_some() && format == Format::Json {
+        return Err(String::from("--bench writes its own results file and cannot be combined with --format json"));
+    }
+
+    let visualize = match (animate, delay, frames) {
+        (false, Some(_), _) => return Err(String::from("--delay can only be used with --visualize")),
+        (false, None, None) => None,
+        (animate, delay, frames) => Some(VisualizeOptions {
+            animate,
+            delay: Duration::from_millis(delay.unwrap_or(DEFAULT_DELAY)),
+            frames,
+        }),
+    };
+    if visualize.is_some() {
+        if selection == Selection::All {
+            return Err(String::from("--visualize and --frames draw a single day and cannot be combined with --all"));
+        }
+        if bench.is_some() {
+            return Err(String::from("--bench cannot be combined with --visualize or --frames"));
+        }
+    }
+    if animate && format == Format::Json {
+        return Err(String::from("--visualize draws to the terminal and cannot be combined with --format json"));
+    }
+    if export.is_some() {
+        if !matches!(selection, Selection::Day(_, Some(_))) {
+            return Err(String::from("--export draws a single part and needs both --day and --part"));
+        }
+        if bench.is_some() {
+            return Err(String::from("--bench cannot be combined with --export"));
+        }
+    }
+
+    if jobs == Some(0) {
+        return Err(String::from("--jobs needs at least 1 thread"));
+    }
+
+    Ok(RunOptions {
+        selection,
+        input,
+        format,
+        bench,
+        visualize,
+        export,
+        jobs,
+    })
+}
+
+fn parse_generate<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut list = false;
+    let mut day = None;
+    let mut size = None;
+    let mut seed = DEFAULT_SEED;
+    let mut output = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--list" => list = true,
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            "--size" => size = Some(generate::parse_size(&expect_value("--size", args.next())?)?),
+            "--seed" => seed = parse_number("--seed", args.next())?,
+            "--output" => output = Some(PathBuf::from(expect_value("--output", args.next())?)),
+            _ => return Err(format!("Unknown option '{}'", arg)),
+        }
+    }
+
+    if list {
+        return match day.is_none() && size.is_none() && output.is_none() {
+            true => Ok(Command::ListGenerators),
+            false => Err(String::from("--list cannot be combined with other options")),
+        };
+    }
+
+    Ok(Command::Generate(GenerateOptions {
+        day: day.ok_or_else(|| String::from("Expected --day"))?,
+        size: size.ok_or_else(|| String::from("Expected --size"))?,
+        seed,
+        output,
+    }))
+}
+
+fn expect_value(option: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("Missing value for {}", option))
+}
+
+fn parse_format(value: Option<String>) -> Result<Format, String> {
+    match expect_value("--format", value)?.as_str() {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        other => Err(format!("Expected text or json for --format - found '{}'", other)),
+    }
+}
+
+fn parse_export(value: Option<String>) -> Result<ExportOptions, String> {
+    let path = PathBuf::from(expect_value("--export", value)?);
+    match ImageFormat::from_path(&path) {
+        Some(format) => Ok(ExportOptions {
+            path,
+            format,
+        }),
+        None => Err(format!("Expected a .ppm, .pgm or .svg file for --export - found '{}'", path.display())),
+    }
+}
+
+fn parse_number<T: FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+    let value = expect_value(option, value)?;
+    value.parse().map_err(|_| format!("Expected a number for {} - found '{}'", option, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_run_day_part_input() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day(14, Some(2)),
+                input: Some(InputSource::from("path/input.txt")),
+                format: Format::Text,
+                bench: None,
+                visualize: None,
+                export: None,
+                jobs: None,
+            })),
+            parse("run --day 14 --part 2 --input path/input.txt")
+        );
+    }
+
+    #[test]
+    fn test_run_stdin() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day(1, Some(1)),
+                input: Some(InputSource::Stdin),
+                format: Format::Text,
+                bench: None,
+                visualize: None,
+                export: None,
+                jobs: None,
+            })),
+            parse("run --day 1 --part 1 --input -")
+        );
+    }
+
+    #[test]
+    fn test_run_day() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day(3, None),
+                input: None,
+                format: Format::Text,
+                bench: None,
+                visualize: None,
+                export: None,
+                jobs: None,
+            })),
+            parse("run --day 3")
+        );
+    }
+
+    #[test]
+    fn test_run_all() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::All,
+                input: None,
+                format: Format::Text,
+                bench: None,
+                visualize: None,
+                export: None,
+                jobs: None,
+            })),
+            parse("run --all")
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::All,
+                input: None,
+                format: Format::Json,
+                bench: None,
+                visualize: None,
+                export: None,
+                jobs: Some(4),
+            })),
+            parse("run --all --jobs 4 --format json")
+        );
+    }
+
+    #[test]
+    fn test_run_json() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day(2, None),
+                input: None,
+                format: Format::Json,
+                bench: None,
+                visualize: None,
+                export: None,
+                jobs: None,
+            })),
+            parse("run --day 2 --format json")
+        );
+    }
+
+    #[test]
+    fn test_run_bench() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::All,
+                input: None,
+                format: Format::Text,
+                bench: Some(BenchOptions {
+                    iterations: 10,
+                    results: PathBuf::from(DEFAULT_RESULTS),
+                }),
+                visualize: None,
+                export: None,
+                jobs: None,
+            })),
+            parse("run --all --bench 10")
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day(5, Some(1)),
+                input: None,
+                format: Format::Text,
+                bench: Some(BenchOptions {
+                    iterations: 3,
+                    results: PathBuf::from("before.csv"),
+                }),
+                visualize: None,
+                export: None,
+                jobs: None,
+            })),
+            parse("run --day 5 --part 1 --bench 3 --results before.csv")
+        );
+    }
+
+    #[test]
+    fn test_run_visualize() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day(13, Some(2)),
+                input: None,
+                format: Format::Text,
+                bench: None,
+                visualize: Some(VisualizeOptions {
+                    animate: true,
+                    delay: Duration::from_millis(DEFAULT_DELAY),
+                    frames: None,
+                }),
+                export: None,
+                jobs: None,
+            })),
+            parse("run --day 13 --part 2 --visualize")
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day(11, None),
+                input: None,
+                format: Format::Json,
+                bench: None,
+                visualize: Some(VisualizeOptions {
+                    animate: false,
+                    delay: Duration::from_millis(DEFAULT_DELAY),
+                    frames: Some(PathBuf::from("frames")),
+                }),
+                export: None,
+                jobs: None,
+            })),
+            parse("run --day 11 --frames frames --format json")
+        );
+        assert_eq!(
+            Ok(Duration::from_millis(20)),
+            parse("run --day 9 --visualize --delay 20").map(|c| match c {
+                Command::Run(options) => options.visualize.unwrap().delay,
+                _ => unreachable!(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_run_export() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day(17, Some(2)),
+                input: None,
+                format: Format::Text,
+                bench: None,
+                visualize: None,
+                export: Some(ExportOptions {
+                    path: PathBuf::from("out/arcs.svg"),
+                    format: ImageFormat::Svg,
+                }),
+                jobs: None,
+            })),
+            parse("run --day 17 --part 2 --export out/arcs.svg")
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            Ok(Command::Generate(GenerateOptions {
+                day: 4,
+                size: vec![100, 5],
+                seed: 7,
+                output: Some(PathBuf::from("cards.txt")),
+            })),
+            parse("generate --day 4 --size 100x5 --seed 7 --output cards.txt")
+        );
+        assert_eq!(
+            Ok(Command::Generate(GenerateOptions {
+                day: 1,
+                size: vec![1000000],
+                seed: DEFAULT_SEED,
+                output: None,
+            })),
+            parse("generate --day 1 --size 1000000")
+        );
+        assert_eq!(Ok(Command::ListGenerators), parse("generate --list"));
+    }
+
+    #[test]
+    fn test_serve() {
+        assert_eq!(Ok(Command::Serve), parse("serve"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(parse("").is_err());
+        assert!(parse("walk --day 1").is_err());
+        assert!(parse("run").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --day one").is_err());
+        assert!(parse("run --day 1 --part 3").is_err());
+        assert!(parse("run --all --day 1").is_err());
+        assert!(parse("run --all --input input.txt").is_err());
+        assert!(parse("run --day 1 --speed 11").is_err());
+        assert!(parse("run --day 1 --bench 0").is_err());
+        assert!(parse("run --day 1 --results out.csv").is_err());
+        assert!(parse("run --day 1 --format xml").is_err());
+        assert!(parse("run --day 1 --format json --bench 3").is_err());
+        assert!(parse("run --day 9 --delay 20").is_err());
+        assert!(parse("run --all --visualize").is_err());
+        assert!(parse("run --day 9 --visualize --bench 3").is_err());
+        assert!(parse("run --day 9 --frames out --bench 3").is_err());
+        assert!(parse("run --day 9 --visualize --format json").is_err());
+        assert!(parse("run --day 5 --export vents.ppm").is_err());
+        assert!(parse("run --all --export vents.ppm").is_err());
+        assert!(parse("run --day 5 --part 1 --export vents.png").is_err());
+        assert!(parse("run --day 5 --part 1 --export vents.ppm --bench 2").is_err());
+        assert!(parse("run --all --jobs 0").is_err());
+        assert!(parse("run --all --jobs").is_err());
+        assert!(parse("generate --day 1").is_err());
+        assert!(parse("generate --size 10").is_err());
+        assert!(parse("generate --day 1 --size 10 --seed -1").is_err());
+        assert!(parse("generate --list --day 1").is_err());
+        assert!(parse("serve --day 1").is_err());
+    }
+}
@@ -0,0 +1,213 @@
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use aoc_common::{Answer, Error, Frame, PartTimings, Picture, Puzzle};
+
+pub type Solver = fn(&str) -> Result<Answer, Error>;
+
+/// Solves a part the given number of times, timing parsing apart from solving.
+pub type TimedSolver = fn(&str, usize) -> Result<PartTimings, Error>;
+
+/// Solves a part while reading its input, for parts that never need more than a line at a time.
+pub type StreamSolver = fn(&mut dyn BufRead) -> Result<Answer, Error>;
+
+/// Draws the steps a part goes through on its way to the answer.
+pub type Visualizer = fn(&str) -> Result<Vec<Frame>, Error>;
+
+/// Draws a picture of the part's result, to be written out as an image file.
+pub type Exporter = fn(&str) -> Result<Picture, Error>;
+
+pub struct Solution {
+    pub day: u32,
+    pub part: u32,
+    pub solve: Solver,
+    pub time: TimedSolver,
+    pub stream: Option<StreamSolver>,
+    pub visualize: Option<Visualizer>,
+    pub export: Option<Exporter>,
+}
+
+impl Solution {
+    const fn part1<P: Puzzle>(day: u32) -> Solution {
+        Solution {
+            day,
+            part: 1,
+            solve: aoc_common::solve_part1::<P>,
+            time: aoc_common::time_part1::<P>,
+            stream: None,
+            visualize: None,
+            export: None,
+        }
+    }
+
+    const fn part2<P: Puzzle>(day: u32) -> Solution {
+        Solution {
+            day,
+            part: 2,
+            solve: aoc_common::solve_part2::<P>,
+            time: aoc_common::time_part2::<P>,
+            stream: None,
+            visualize: None,
+            export: None,
+        }
+    }
+
+    const fn streaming(self, stream: StreamSolver) -> Solution {
+        Solution {
+            stream: Some(stream),
+            ..self
+        }
+    }
+
+    const fn visualized(self, visualize: Visualizer) -> Solution {
+        Solution {
+            visualize: Some(visualize),
+            ..self
+        }
+    }
+
+    const fn exported(self, export: Exporter) -> Solution {
+        Solution {
+            export: Some(export),
+            ..self
+        }
+    }
+}
+
+// every implemented day and part, in the order they should be run - day 19 is left out as it
+// can't solve either part yet
+pub const SOLUTIONS: [Solution; 39] = [
+    Solution::part1::<day_01_puzzle_01::Day01>(1)
+        .streaming(day_01_puzzle_01::solve_stream),
+    Solution::part2::<day_01_puzzle_02::Day01>(1)
+        .streaming(aoc_common::solve_lines::<day_01_puzzle_02::sonar_scan::SonarScan>),
+    Solution::part1::<day_02_puzzle_01::Day02>(2)
+        .streaming(aoc_common::solve_lines::<day_02_puzzle_01::submarine::Submarine>),
+    Solution::part2::<day_02_puzzle_02::Day02>(2)
+        .streaming(aoc_common::solve_lines::<day_02_puzzle_02::submarine::Submarine<day_02_puzzle_02::submarine::Aim>>),
+    Solution::part1::<day_03_puzzle_01::Day03>(3),
+    Solution::part2::<day_03_puzzle_02::Day03>(3),
+    Solution::part1::<day_04_puzzle_01::Day04>(4),
+    Solution::part2::<day_04_puzzle_02::Day04>(4),
+    Solution::part1::<day_05_puzzle_01::Day05>(5)
+        .exported(day_05_puzzle_01::export),
+    Solution::part2::<day_05_puzzle_02::Day05>(5)
+        .exported(day_05_puzzle_02::export),
+    Solution::part1::<day_06_puzzle_01::Day06>(6),
+    Solution::part2::<day_06_puzzle_01::Day06>(6),
+    Solution::part1::<day_07_puzzle_01::Day07>(7),
+    Solution::part2::<day_07_puzzle_02::Day07>(7),
+    Solution::part1::<day_08_puzzle_01::Day08>(8),
+    Solution::part2::<day_08_puzzle_02::Day08>(8),
+    Solution::part1::<day_09_puzzle_01::Day09>(9)
+        .streaming(aoc_common::solve_lines::<day_09_puzzle_01::height_map::HeightMap>),
+    Solution::part2::<day_09_puzzle_02::Day09>(9)
+        .visualized(day_09_puzzle_02::visualize)
+        .exported(day_09_puzzle_02::export),
+    Solution::part1::<day_10_puzzle_01::Day10>(10)
+        .streaming(aoc_common::solve_lines::<day_10_puzzle_01::chunk::ChunkChecker>),
+    Solution::part2::<day_10_puzzle_02::Day10>(10)
+        .streaming(aoc_common::solve_lines::<day_10_puzzle_02::chunk::ChunkChecker>),
+    Solution::part1::<day_11_puzzle_01::Day11>(11),
+    Solution::part2::<day_11_puzzle_02::Day11>(11)
+        .visualized(day_11_puzzle_02::visualize),
+    Solution::part1::<day_12_puzzle_01::Day12>(12),
+    Solution::part2::<day_12_puzzle_02::Day12>(12),
+    Solution::part1::<day_13_puzzle_01::Day13>(13),
+    Solution::part2::<day_13_puzzle_02::Day13>(13)
+        .visualized(day_13_puzzle_02::visualize)
+        .exported(day_13_puzzle_02::export),
+    Solution::part1::<day_14_puzzle_01::Day14>(14),
+    Solution::part2::<day_14_puzzle_02::Day14>(14),
+    Solution::part1::<day_15_puzzle_01::Day15>(15)
+        .visualized(day_15_puzzle_01::visualize)
+        .exported(day_15_puzzle_01::export),
+    Solution::part2::<day_15_puzzle_02::Day15>(15)
+        .visualized(day_15_puzzle_02::visualize)
+        .exported(day_15_puzzle_02::export),
+    Solution::part1::<day_16_puzzle_01::Day16>(16),
+    Solution::part2::<day_16_puzzle_02::Day16>(16),
+    Solution::part1::<day_17_puzzle_01::Day17>(17)
+        .exported(day_17_puzzle_01::export),
+    Solution::part2::<day_17_puzzle_02::Day17>(17)
+        .exported(day_17_puzzle_02::export),
+    Solution::part1::<day_20_puzzle_01::Day20>(20)
+        .exported(day_20_puzzle_01::export),
+    Solution::part2::<day_20_puzzle_02::Day20>(20)
+        .visualized(day_20_puzzle_02::visualize)
+        .exported(day_20_puzzle_02::export),
+    Solution::part1::<day_21_puzzle_01::Day21>(21),
+    Solution::part1::<day_22_puzzle_01::Day22>(22),
+    Solution::part2::<day_22_puzzle_02::Day22>(22),
+];
+
+pub fn find(day: u32, part: u32) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.day == day && s.part == part)
+}
+
+pub fn for_day(day: u32) -> Vec<&'static Solution> {
+    SOLUTIONS.iter().filter(|s| s.day == day).collect()
+}
+
+/// Both parts of a day share the input checked into the day's first puzzle crate.
+pub fn default_input(day: u32) -> PathBuf {
+    day_directory(day).join("input.txt")
+}
+
+/// Example inputs with their known answers live alongside the day's input, one directory per example.
+#[cfg(test)]
+pub fn fixtures_directory(day: u32) -> PathBuf {
+    day_directory(day).join("fixtures")
+}
+
+fn day_directory(day: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate should live inside the workspace")
+        .join(format!("day_{:02}_puzzle_01", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(14, find(14, 2).unwrap().day);
+        assert_eq!(2, find(14, 2).unwrap().part);
+        assert!(find(18, 1).is_none());
+        assert!(find(1, 3).is_none());
+    }
+
+    #[test]
+    fn test_streaming_matches_solve() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        for solution in for_day(1) {
+            let stream = solution.stream.expect("day 1 should stream");
+            assert_eq!((solution.solve)(input), stream(&mut input.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_visualize_matches_solve() {
+        let input = "6,10\n0,14\n9,10\n0,3\n10,4\n4,11\n6,0\n6,12\n4,1\n0,13\n10,12\n3,4\n3,0\n8,4\n1,10\n2,14\n8,10\n9,0\n\nfold along y=7\nfold along x=5\n";
+        let solution = find(13, 2).unwrap();
+        let frames = (solution.visualize.expect("day 13 part 2 should visualize"))(input).unwrap();
+
+        // the last frame is the folded paper the answer is read from
+        let last = frames.last().unwrap().to_string();
+        assert_eq!((solution.solve)(input).unwrap().to_string(), last.split_once('\n').unwrap().1);
+    }
+
+    #[test]
+    fn test_solutions_in_order() {
+        for pair in SOLUTIONS.windows(2) {
+            assert!((pair[0].day, pair[0].part) < (pair[1].day, pair[1].part));
+        }
+    }
+
+    #[test]
+    fn test_default_input() {
+        let path = default_input(4);
+        assert!(path.ends_with("day_04_puzzle_01/input.txt"));
+    }
+}
@@ -0,0 +1,123 @@
+//! Runs every solver against the example inputs checked in under each day's `fixtures` directory.
+//!
+//! A fixture is a directory holding an `input.txt` and the expected answer for each part it covers
+//! in `part1.txt` and/or `part2.txt`. Adding a directory is all it takes for it to be checked.
+
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+use crate::days;
+
+struct Fixture {
+    day: u32,
+    part: u32,
+    name: String,
+    input: PathBuf,
+    expected: PathBuf,
+}
+
+fn discover() -> Vec<Fixture> {
+    let mut fixtures = vec![];
+    for day in 1..=25 {
+        let directory = days::fixtures_directory(day);
+        let Ok(entries) = fs::read_dir(&directory) else {
+            continue;
+        };
+        let mut examples: Vec<PathBuf> = entries.map(|e| e.unwrap().path()).filter(|p| p.is_dir()).collect();
+        examples.sort();
+        for example in examples.into_iter() {
+            for part in 1..=2 {
+                let expected = example.join(format!("part{}.txt", part));
+                if expected.exists() {
+                    fixtures.push(Fixture {
+                        day,
+                        part,
+                        name: example.file_name().unwrap().to_string_lossy().into_owned(),
+                        input: example.join("input.txt"),
+                        expected,
+                    });
+                }
+            }
+        }
+    }
+    fixtures
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| panic!("Failed to read fixture '{}' - {}", path.display(), e))
+}
+
+/// Checks a single fixture, describing what went wrong if the answer is not the expected one.
+fn check(fixture: &Fixture) -> Result<(), String> {
+    let solution = days::find(fixture.day, fixture.part)
+        .ok_or_else(|| String::from("there is no solver for this part"))?;
+    let input = read(&fixture.input);
+    // a solver that panics on one example should not hide the results of the others
+    let actual = panic::catch_unwind(|| (solution.solve)(&input))
+        .map_err(|_| String::from("the solver panicked"))?
+        .map_err(|e| e.to_string())?;
+
+    let expected = read(&fixture.expected);
+    let actual = actual.to_string();
+    if expected.trim_end() == actual.trim_end() {
+        Ok(())
+    } else {
+        Err(format!("the answer changed\n{}", diff(expected.trim_end(), actual.trim_end())))
+    }
+}
+
+/// A line based diff of expected against actual, so a changed multi-line answer shows just the rows that moved.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    // longest common subsequence lengths of every pair of suffixes
+    let mut common = vec![vec![0; actual.len() + 1]; expected.len() + 1];
+    for e in (0..expected.len()).rev() {
+        for a in (0..actual.len()).rev() {
+            common[e][a] = if expected[e] == actual[a] {
+                common[e + 1][a + 1] + 1
+            } else {
+                common[e + 1][a].max(common[e][a + 1])
+            };
+        }
+    }
+
+    let mut output = vec![String::from("--- expected"), String::from("+++ actual")];
+    let (mut e, mut a) = (0, 0);
+    while e < expected.len() || a < actual.len() {
+        if e < expected.len() && a < actual.len() && expected[e] == actual[a] {
+            output.push(format!("  {}", expected[e]));
+            e += 1;
+            a += 1;
+        } else if a == actual.len() || (e < expected.len() && common[e + 1][a] >= common[e][a + 1]) {
+            output.push(format!("- {}", expected[e]));
+            e += 1;
+        } else {
+            output.push(format!("+ {}", actual[a]));
+            a += 1;
+        }
+    }
+    output.join("\n")
+}
+
+#[test]
+fn test_fixtures() {
+    let fixtures = discover();
+    assert!(!fixtures.is_empty(), "No fixtures were found");
+
+    // every fixture runs so one report shows all of the changed answers
+    let failures: Vec<String> = fixtures
+        .iter()
+        .filter_map(|f| check(f).err().map(|e| format!("day {} part {} ({}): {}", f.day, f.part, f.name, e)))
+        .collect();
+
+    assert!(failures.is_empty(), "{} of {} fixtures failed\n\n{}", failures.len(), fixtures.len(), failures.join("\n\n"));
+}
+
+#[test]
+fn test_diff() {
+    assert_eq!("--- expected\n+++ actual\n- 5\n+ 6", diff("5", "6"));
+    assert_eq!("--- expected\n+++ actual\n  #..\n- .#.\n+ ##.\n  ..#", diff("#..\n.#.\n..#", "#..\n##.\n..#"));
+    assert_eq!("--- expected\n+++ actual\n  a\n+ b", diff("a", "a\nb"));
+}
@@ -0,0 +1,611 @@
+//! Writes puzzle inputs of any size for stress testing and profiling the solvers.
+//!
+//! Every generator takes the dimensions it is asked for (lines, cards, grid width and height...)
+//! and draws everything else from a seeded `Rng`, so the same day, size and seed always give
+//! the same input.
+
+use std::collections::HashSet;
+use std::io::{self, Write};
+use aoc_common::Rng;
+
+type Generate = fn(&mut Rng, &[usize], &mut dyn Write) -> io::Result<()>;
+
+pub struct Generator {
+    pub day: u32,
+    /// What each dimension of the size means, in the order they are given.
+    pub dimensions: &'static [&'static str],
+    generate: Generate,
+}
+
+impl Generator {
+    pub fn size_description(&self) -> String {
+        self.dimensions.iter().map(|d| format!("<{}>", d)).collect::<Vec<String>>().join("x")
+    }
+
+    pub fn generate(&self, size: &[usize], seed: u64, output: &mut dyn Write) -> Result<(), String> {
+        if size.len() != self.dimensions.len() {
+            return Err(format!("Day {} expects a size of {}", self.day, self.size_description()));
+        }
+        if let Some(index) = size.iter().position(|&s| s == 0) {
+            return Err(format!("The {} should be at least 1", self.dimensions[index]));
+        }
+        (self.generate)(&mut Rng::new(seed), size, output).map_err(|e| match e.kind() {
+            // a generator turns down a size it cannot make a valid input for
+            io::ErrorKind::InvalidInput => e.to_string(),
+            _ => format!("Failed to write generated input - {}", e),
+        })
+    }
+}
+
+pub const GENERATORS: [Generator; 20] = [
+    Generator { day: 1, dimensions: &["depths"], generate: depths },
+    Generator { day: 2, dimensions: &["commands"], generate: commands },
+    Generator { day: 3, dimensions: &["numbers", "bits"], generate: diagnostics },
+    Generator { day: 4, dimensions: &["cards", "card size"], generate: bingo },
+    Generator { day: 5, dimensions: &["lines"], generate: vents },
+    Generator { day: 6, dimensions: &["fish"], generate: lanternfish },
+    Generator { day: 7, dimensions: &["crabs"], generate: crabs },
+    Generator { day: 8, dimensions: &["entries"], generate: displays },
+    Generator { day: 9, dimensions: &["width", "height"], generate: heights },
+    Generator { day: 10, dimensions: &["lines", "length"], generate: chunks },
+    Generator { day: 11, dimensions: &["width", "height"], generate: octopuses },
+    Generator { day: 12, dimensions: &["caves"], generate: caves },
+    Generator { day: 13, dimensions: &["dots"], generate: paper },
+    Generator { day: 14, dimensions: &["template length"], generate: polymer },
+    Generator { day: 15, dimensions: &["width", "height"], generate: risk_levels },
+    Generator { day: 16, dimensions: &["literals"], generate: transmission },
+    Generator { day: 17, dimensions: &["distance"], generate: target_area },
+    Generator { day: 20, dimensions: &["width", "height"], generate: image },
+    Generator { day: 21, dimensions: &["highest starting position"], generate: starting_positions },
+    Generator { day: 22, dimensions: &["steps"], generate: reboot_steps },
+];
+
+pub fn find(day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+/// Parses a size like `1000` or `100x5`.
+pub fn parse_size(size: &str) -> Result<Vec<usize>, String> {
+    size.split('x')
+        .map(|d| d.parse().map_err(|_| format!("Expected a size like 1000 or 100x5 - found '{}'", size)))
+        .collect()
+}
+
+fn depths(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let mut depth = rng.between(100, 200);
+    for _ in 0..size[0] {
+        writeln!(output, "{}", depth)?;
+        // mostly heading deeper, the way the sea floor does in the real input
+        depth = (depth + rng.between(-10, 20)).max(0);
+    }
+    Ok(())
+}
+
+fn commands(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let mut aim = 0;
+    for _ in 0..size[0] {
+        let value = rng.between(1, 9);
+        match rng.below(10) {
+            0..=4 => writeln!(output, "forward {}", value)?,
+            // never going up past the surface keeps the depth positive in both puzzles
+            5..=6 if aim >= value => {
+                aim -= value;
+                writeln!(output, "up {}", value)?
+            },
+            _ => {
+                aim += value;
+                writeln!(output, "down {}", value)?
+            },
+        }
+    }
+    Ok(())
+}
+
+fn diagnostics(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let (count, bits) = (size[0], size[1]);
+    if bits > 63 || count as u64 > 1 << bits {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{} bits cannot hold {} different numbers", bits, count)));
+    }
+    // the life support ratings narrow down to a single number, which needs every number to be different
+    let mut seen = HashSet::new();
+    while seen.len() < count {
+        let number = rng.below(1 << bits);
+        if seen.insert(number) {
+            writeln!(output, "{:0width$b}", number, width = bits)?;
+        }
+    }
+    Ok(())
+}
+
+fn bingo(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let (cards, card_size) = (size[0], size[1]);
+    let range = (card_size * card_size * 4).max(100) as u64;
+
+    let mut balls: Vec<u64> = (0..range).collect();
+    rng.shuffle(&mut balls);
+    writeln!(output, "{}", balls.iter().map(u64::to_string).collect::<Vec<String>>().join(","))?;
+
+    let width = (range - 1).to_string().len();
+    for _ in 0..cards {
+        writeln!(output)?;
+        rng.shuffle(&mut balls);
+        for row in balls[..card_size * card_size].chunks(card_size) {
+            let row: Vec<String> = row.iter().map(|n| format!("{:>width$}", n, width = width)).collect();
+            writeln!(output, "{}", row.join(" "))?;
+        }
+    }
+    Ok(())
+}
+
+fn vents(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size[0] {
+        let (x1, y1) = (rng.between(0, 899), rng.between(0, 899));
+        let length = rng.between(1, 989 - x1.max(y1)).min(500);
+        let (x2, y2) = match rng.below(3) {
+            0 => (x1 + length, y1),
+            1 => (x1, y1 + length),
+            _ => (x1 + length, y1 + length),
+        };
+        // lines run in either direction
+        if rng.chance(1, 2) {
+            writeln!(output, "{},{} -> {},{}", x1, y1, x2, y2)?;
+        } else {
+            writeln!(output, "{},{} -> {},{}", x2, y2, x1, y1)?;
+        }
+    }
+    Ok(())
+}
+
+fn lanternfish(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let timers: Vec<String> = (0..size[0]).map(|_| rng.between(1, 5).to_string()).collect();
+    writeln!(output, "{}", timers.join(","))
+}
+
+fn crabs(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let positions: Vec<String> = (0..size[0]).map(|_| rng.between(0, 1999).to_string()).collect();
+    writeln!(output, "{}", positions.join(","))
+}
+
+const DIGIT_SEGMENTS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+fn displays(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size[0] {
+        let mut wires: Vec<char> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wires);
+        let wired = |digit: usize, rng: &mut Rng| {
+            let mut pattern: Vec<char> = DIGIT_SEGMENTS[digit].chars().map(|s| wires[(s as u8 - b'a') as usize]).collect();
+            rng.shuffle(&mut pattern);
+            pattern.into_iter().collect::<String>()
+        };
+
+        let mut digits: Vec<usize> = (0..10).collect();
+        rng.shuffle(&mut digits);
+        let patterns: Vec<String> = digits.iter().map(|&d| wired(d, rng)).collect();
+        let outputs: Vec<String> = (0..4).map(|_| {
+            let digit = rng.below(10) as usize;
+            wired(digit, rng)
+        }).collect();
+        writeln!(output, "{} | {}", patterns.join(" "), outputs.join(" "))?;
+    }
+    Ok(())
+}
+
+fn digit_grid(rng: &mut Rng, width: usize, height: usize, low: i64, high: i64, output: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..height {
+        let row: String = (0..width).map(|_| char::from(b'0' + rng.between(low, high) as u8)).collect();
+        writeln!(output, "{}", row)?;
+    }
+    Ok(())
+}
+
+fn heights(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    // a third of the real map is 9s, which is what walls it off into separate basins
+    for _ in 0..size[1] {
+        let row: String = (0..size[0])
+            .map(|_| if rng.chance(1, 3) { '9' } else { char::from(b'0' + rng.below(9) as u8) })
+            .collect();
+        writeln!(output, "{}", row)?;
+    }
+    Ok(())
+}
+
+const CHUNKS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+fn chunks(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let mut incomplete = 0;
+    for index in 0..size[0] {
+        let mut line = String::new();
+        let mut open = vec![];
+        for _ in 0..size[1] {
+            if open.is_empty() || rng.chance(3, 5) {
+                let &(opening, closing) = rng.pick(&CHUNKS);
+                line.push(opening);
+                open.push(closing);
+            } else {
+                line.push(open.pop().unwrap());
+            }
+        }
+        if open.is_empty() {
+            let &(opening, closing) = rng.pick(&CHUNKS);
+            line.push(opening);
+            open.push(closing);
+        }
+        // every line is either corrupted by closing the wrong chunk or left incomplete, and the last
+        // line makes sure the number left incomplete is odd so there is a middle score to find
+        let corrupted = if index + 1 == size[0] {
+            incomplete % 2 == 1
+        } else {
+            rng.chance(1, 2)
+        };
+        if corrupted {
+            let expected = *open.last().unwrap();
+            let wrong: Vec<char> = CHUNKS.iter().map(|&(_, c)| c).filter(|&c| c != expected).collect();
+            line.push(*rng.pick(&wrong));
+        } else {
+            incomplete += 1;
+        }
+        writeln!(output, "{}", line)?;
+    }
+    Ok(())
+}
+
+fn octopuses(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    digit_grid(rng, size[0], size[1], 0, 9, output)
+}
+
+fn caves(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    if size[0] < 2 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "There have to be at least 2 caves, the start and the end"));
+    }
+    let mut names = vec![String::from("start"), String::from("end")];
+    for i in 0..size[0] - 2 {
+        let name = cave_name(i);
+        // about one cave in four is large
+        names.push(if rng.chance(1, 4) { name.to_uppercase() } else { name });
+    }
+    let large = |name: &str| name.chars().all(|c| c.is_ascii_uppercase());
+
+    let mut connections = HashSet::new();
+    let mut connect = |a: usize, b: usize, output: &mut dyn Write| -> io::Result<()> {
+        // two connected large caves could be walked between forever
+        if a == b || (large(&names[a]) && large(&names[b])) || !connections.insert((a.min(b), a.max(b))) {
+            return Ok(());
+        }
+        writeln!(output, "{}-{}", names[a], names[b])
+    };
+
+    // linking every cave to one before it keeps the whole network reachable from the start
+    let mut order: Vec<usize> = (1..names.len()).collect();
+    rng.shuffle(&mut order);
+    order.insert(0, 0);
+    for i in 1..order.len() {
+        let mut previous = order[rng.below(i as u64) as usize];
+        if large(&names[previous]) && large(&names[order[i]]) {
+            previous = 0;
+        }
+        connect(previous, order[i], output)?;
+    }
+    for _ in 0..names.len() / 2 {
+        let a = rng.below(names.len() as u64) as usize;
+        let b = rng.below(names.len() as u64) as usize;
+        connect(a, b, output)?;
+    }
+    Ok(())
+}
+
+/// A two or more letter name for the nth cave - aa, ab, ..., zz, aaa...
+fn cave_name(mut index: usize) -> String {
+    let mut name = vec![];
+    for _ in 0..2 {
+        name.push(b'a' + (index % 26) as u8);
+        index /= 26;
+    }
+    while index > 0 {
+        index -= 1;
+        name.push(b'a' + (index % 26) as u8);
+        index /= 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+fn paper(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    // folding a sheet in half again and again until the code is a readable 40x6, like the real input
+    let (mut width, mut height) = (40, 6);
+    let mut folds = vec![];
+    while width < 1000 || height < 800 {
+        folds.push(('x', width));
+        width = width * 2 + 1;
+        folds.push(('y', height));
+        height = height * 2 + 1;
+    }
+    folds.reverse();
+
+    let lands_on_fold = |mut x: usize, mut y: usize| {
+        for &(axis, offset) in folds.iter() {
+            let coordinate = if axis == 'x' { &mut x } else { &mut y };
+            if *coordinate == offset {
+                return true;
+            }
+            if *coordinate > offset {
+                *coordinate = 2 * offset - *coordinate;
+            }
+        }
+        false
+    };
+
+    let mut dots = HashSet::new();
+    let count = size[0].min(width * height / 2);
+    while dots.len() < count {
+        let (x, y) = (rng.below(width as u64) as usize, rng.below(height as u64) as usize);
+        if !lands_on_fold(x, y) && dots.insert((x, y)) {
+            writeln!(output, "{},{}", x, y)?;
+        }
+    }
+
+    writeln!(output)?;
+    for (axis, offset) in folds.iter() {
+        writeln!(output, "fold along {}={}", axis, offset)?;
+    }
+    Ok(())
+}
+
+const ELEMENTS: [char; 10] = ['B', 'C', 'F', 'H', 'K', 'N', 'O', 'P', 'S', 'V'];
+
+fn polymer(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let template: String = (0..size[0].max(2)).map(|_| *rng.pick(&ELEMENTS)).collect();
+    writeln!(output, "{}\n", template)?;
+    for a in ELEMENTS.iter() {
+        for b in ELEMENTS.iter() {
+            writeln!(output, "{}{} -> {}", a, b, rng.pick(&ELEMENTS))?;
+        }
+    }
+    Ok(())
+}
+
+fn risk_levels(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    digit_grid(rng, size[0], size[1], 1, 9, output)
+}
+
+fn transmission(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let mut bits = packet(rng, size[0]);
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+    let hex: String = bits
+        .as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let value = nibble.iter().fold(0, |v, &b| v * 2 + (b - b'0') as u32);
+            char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+    writeln!(output, "{}", hex)
+}
+
+/// The bits of a packet holding the given number of literal values between it and its sub-packets.
+fn packet(rng: &mut Rng, literals: usize) -> String {
+    let version = format!("{:03b}", rng.below(8));
+    if literals == 1 {
+        return version + "100" + &literal_bits(rng.below(1000));
+    }
+
+    let children = rng.between(2, literals.min(5) as i64) as usize;
+    // a product of only small literals keeps every value within a u64
+    let (type_id, sub_packets) = if children == literals && children <= 4 && rng.chance(1, 5) {
+        (1, (0..children).map(|_| format!("{:03b}100{}", rng.below(8), literal_bits(rng.between(1, 9) as u64))).collect())
+    } else {
+        let type_id = if children == 2 { *rng.pick(&[0, 2, 3, 5, 6, 7]) } else { *rng.pick(&[0, 2, 3]) };
+        (type_id, split(rng, literals, children).into_iter().map(|l| packet(rng, l)).collect::<Vec<String>>())
+    };
+
+    let contents = sub_packets.concat();
+    let length = if contents.len() < 1 << 15 && rng.chance(1, 2) {
+        format!("0{:015b}", contents.len())
+    } else {
+        format!("1{:011b}", sub_packets.len())
+    };
+    format!("{}{:03b}{}{}", version, type_id, length, contents)
+}
+
+fn literal_bits(value: u64) -> String {
+    let binary = format!("{:b}", value);
+    let padded = format!("{}{}", "0".repeat((4 - binary.len() % 4) % 4), binary);
+    let groups: Vec<&str> = padded.as_bytes().chunks(4).map(|g| std::str::from_utf8(g).unwrap()).collect();
+    groups
+        .iter()
+        .enumerate()
+        .map(|(i, group)| format!("{}{}", if i + 1 < groups.len() { "1" } else { "0" }, group))
+        .collect()
+}
+
+/// Splits a total into the given number of parts, each at least 1.
+fn split(rng: &mut Rng, total: usize, parts: usize) -> Vec<usize> {
+    let mut cuts: Vec<usize> = (1..total).collect();
+    rng.shuffle(&mut cuts);
+    let mut cuts: Vec<usize> = cuts.into_iter().take(parts - 1).collect();
+    cuts.sort();
+    cuts.push(total);
+    let mut previous = 0;
+    cuts.into_iter()
+        .map(|cut| {
+            let part = cut - previous;
+            previous = cut;
+            part
+        })
+        .collect()
+}
+
+fn target_area(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    // like the real input the target is ahead of the launcher and below it, about as deep as it is far away,
+    // and firing straight at its near top corner always hits so there is a trajectory to find
+    let near = size[0] as i64;
+    let far = near + rng.between(1, near / 4 + 1);
+    let bottom = -rng.between(near / 2 + 2, near + 2);
+    let top = bottom + rng.between(1, (-bottom - 1).min(near / 4 + 1));
+    writeln!(output, "target area: x={}..{}, y={}..{}", near, far, bottom, top)
+}
+
+fn image(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    let mut algorithm: Vec<char> = (0..512).map(|_| if rng.chance(1, 2) { '#' } else { '.' }).collect();
+    // an algorithm that lights the empty background must also turn it back off, or the lit count is infinite
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+    writeln!(output, "{}\n", algorithm.into_iter().collect::<String>())?;
+    for _ in 0..size[1] {
+        let row: String = (0..size[0]).map(|_| if rng.chance(1, 2) { '#' } else { '.' }).collect();
+        writeln!(output, "{}", row)?;
+    }
+    Ok(())
+}
+
+fn starting_positions(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    // the board only has 10 spaces, so a larger size still starts both players on it
+    let highest = size[0].min(10) as i64;
+    writeln!(output, "Player 1 starting position: {}", rng.between(1, highest))?;
+    writeln!(output, "Player 2 starting position: {}", rng.between(1, highest))
+}
+
+fn reboot_steps(rng: &mut Rng, size: &[usize], output: &mut dyn Write) -> io::Result<()> {
+    // like the real input the first steps stay inside the initialization area and the rest are large cuboids
+    // kept clear of it by lying entirely outside it along at least one axis
+    let initialization = size[0] / 20 + 1;
+    for step in 0..size[0] {
+        let state = if step == 0 || rng.chance(2, 3) { "on" } else { "off" };
+        let outside = if step < initialization { None } else { Some(rng.below(3)) };
+        let ranges: Vec<String> = (0..3)
+            .map(|axis| {
+                let (start, end) = match outside {
+                    None => {
+                        let start = rng.between(-50, 49);
+                        (start, (start + rng.between(1, 50)).min(50))
+                    },
+                    Some(outside) if outside == axis => {
+                        let start = rng.between(51, 99_999);
+                        let end = (start + rng.between(1, 50_000)).min(100_000);
+                        if rng.chance(1, 2) { (start, end) } else { (-end, -start) }
+                    },
+                    Some(_) => {
+                        let start = rng.between(-100_000, 99_999);
+                        (start, (start + rng.between(1, 50_000)).min(100_000))
+                    },
+                };
+                format!("{}..{}", start, end)
+            })
+            .collect();
+        writeln!(output, "{} x={},y={},z={}", state, ranges[0], ranges[1], ranges[2])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Error;
+    use crate::days;
+
+    fn generate(day: u32, size: &[usize], seed: u64) -> String {
+        let mut output = vec![];
+        find(day).unwrap().generate(size, seed, &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_reproducible() {
+        assert_eq!(generate(4, &[10, 5], 42), generate(4, &[10, 5], 42));
+        assert_ne!(generate(4, &[10, 5], 42), generate(4, &[10, 5], 43));
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(Ok(vec![1000]), parse_size("1000"));
+        assert_eq!(Ok(vec![100, 5]), parse_size("100x5"));
+        assert!(parse_size("100x").is_err());
+        assert!(parse_size("big").is_err());
+    }
+
+    #[test]
+    fn test_size_checked() {
+        let mut output = vec![];
+        assert_eq!(Err(String::from("Day 9 expects a size of <width>x<height>")), find(9).unwrap().generate(&[10], 1, &mut output));
+        assert_eq!(Err(String::from("The cards should be at least 1")), find(4).unwrap().generate(&[0, 5], 1, &mut output));
+    }
+
+    #[test]
+    fn test_every_day_has_a_generator() {
+        for solution in days::SOLUTIONS.iter() {
+            assert!(find(solution.day).is_some(), "Day {} can be solved but has no input generator", solution.day);
+        }
+    }
+
+    #[test]
+    fn test_caves_size_checked() {
+        let mut output = vec![];
+        assert_eq!(Err(String::from("There have to be at least 2 caves, the start and the end")), find(12).unwrap().generate(&[1], 1, &mut output));
+        assert_eq!("start-end\n", generate(12, &[2], 1));
+    }
+
+    #[test]
+    fn test_target_area_ahead_and_below() {
+        for seed in 0..20 {
+            for distance in [1, 2, 12, 150] {
+                let input = generate(17, &[distance], seed);
+                assert!(day_17_puzzle_01::load_target(&input).is_ok(), "seed {} with distance {}: {}", seed, distance, input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunks_odd_incomplete() {
+        // day 10 part 2 takes the middle score of the incomplete lines, so there has to be an odd number of them
+        for seed in 0..20 {
+            for lines in [1, 2, 5, 12] {
+                let input = generate(10, &[lines, 8], seed);
+                let incomplete = input.lines().filter(|line| {
+                    let mut open = vec![];
+                    for c in line.chars() {
+                        match CHUNKS.iter().find(|&&(opening, _)| opening == c) {
+                            Some(&(_, closing)) => open.push(closing),
+                            None if open.pop() != Some(c) => return false,
+                            None => {},
+                        }
+                    }
+                    true
+                }).count();
+                assert_eq!(1, incomplete % 2, "seed {} with {} lines:\n{}", seed, lines, input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cave_name() {
+        assert_eq!("aa", cave_name(0));
+        assert_eq!("zz", cave_name(675));
+        assert_eq!("aaa", cave_name(676));
+    }
+
+    #[test]
+    fn test_literal_bits() {
+        assert_eq!("101111111000101", literal_bits(2021));
+        assert_eq!("00000", literal_bits(0));
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        // small sizes keep the solvers quick, and each generated input has to be one every part can work through -
+        // though a random input may have no answer, like octopi that never synchronize their flashes
+        for generator in GENERATORS.iter() {
+            for seed in [1, 42, 2021] {
+                let size: Vec<usize> = generator.dimensions.iter().map(|_| 12).collect();
+                let input = generate(generator.day, &size, seed);
+                for solution in days::for_day(generator.day) {
+                    match (solution.solve)(&input) {
+                        Ok(_) | Err(Error::NoSolution(_)) => {},
+                        Err(e) => panic!("Day {} part {} failed on an input generated with seed {} - {}\n{}", solution.day, solution.part, seed, e, input),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+mod bench;
+mod cli;
+mod days;
+#[cfg(test)]
+mod fixtures;
+mod generate;
+mod serve;
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::process;
+use std::thread;
+use aoc_common::{Answer, Error, InputSource, Json, Pool};
+use crate::cli::{Command, ExportOptions, Format, GenerateOptions, RunOptions, Selection, VisualizeOptions};
+use crate::days::Solution;
+
+fn main() {
+    let command = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run(options) => run(options),
+        Command::Generate(options) => generate(options),
+        Command::ListGenerators => {
+            println!("Day | Size");
+            println!("----+-----");
+            for generator in generate::GENERATORS.iter() {
+                println!("{:>3} | {}", generator.day, generator.size_description());
+            }
+            Ok(())
+        },
+        Command::Serve => serve::serve(io::stdin().lock(), io::stdout().lock()),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+fn run(options: RunOptions) -> Result<(), String> {
+    if let Some(jobs) = options.jobs {
+        aoc_common::set_jobs(jobs);
+    }
+
+    match options.selection {
+        Selection::All => match &options.bench {
+            Some(bench) => {
+                let solutions: Vec<&Solution> = days::SOLUTIONS.iter().collect();
+                bench::run(&solutions, bench, |day| read_input(&InputSource::File(days::default_input(day))))
+            },
+            None => run_all(&options.format),
+        },
+        Selection::Day(day, part) => {
+            let solutions = match part {
+                Some(part) => days::find(day, part).into_iter().collect(),
+                None => days::for_day(day),
+            };
+            if solutions.is_empty() {
+                return match part {
+                    Some(part) => Err(format!("Day {} part {} has not been implemented", day, part)),
+                    None => Err(format!("Day {} has not been implemented", day)),
+                };
+            }
+
+            let source = options.input.unwrap_or_else(|| InputSource::File(days::default_input(day)));
+
+            if let Some(bench) = &options.bench {
+                let input = read_input(&source)?;
+                return bench::run(&solutions, bench, |_| Ok(input.clone()));
+            }
+
+            // a single part that can stream never holds the input in memory, which lets huge
+            // generated inputs be piped through it - anything else reads the input once for every part
+            if let [solution] = solutions.as_slice() {
+                if let (Some(stream), None, None) = (solution.stream, &options.visualize, &options.export) {
+                    let mut reader = source.open().map_err(|e| format!("Failed to read input '{}' - {}", source, e))?;
+                    return report(&options.format, solution, stream(&mut reader));
+                }
+            }
+
+            let input = read_input(&source)?;
+            if let Some(visualize_options) = &options.visualize {
+                visualize(&solutions, &input, visualize_options)?;
+            }
+            for solution in solutions.iter() {
+                report(&options.format, solution, (solution.solve)(&input))?;
+            }
+            if let Some(export_options) = &options.export {
+                export(solutions[0], &input, export_options)?;
+            }
+
+            Ok(())
+        },
+    }
+}
+
+fn generate(options: GenerateOptions) -> Result<(), String> {
+    let generator = generate::find(options.day).ok_or_else(|| format!("There is no input generator for day {}", options.day))?;
+
+    let mut output: Box<dyn Write> = match &options.output {
+        Some(path) => Box::new(File::create(path).map_err(|e| format!("Failed to create '{}' - {}", path.display(), e))?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut output = BufWriter::new(&mut output);
+    generator.generate(&options.size, options.seed, &mut output)?;
+    output.flush().map_err(|e| format!("Failed to write generated input - {}", e))
+}
+
+/// Draws every selected part that can be drawn, animating the frames and/or writing them out as text files.
+fn visualize(solutions: &[&Solution], input: &str, options: &VisualizeOptions) -> Result<(), String> {
+    let visualized: Vec<_> = solutions.iter().filter_map(|s| s.visualize.map(|v| (s, v))).collect();
+    if visualized.is_empty() {
+        let days: Vec<String> = days::SOLUTIONS.iter()
+            .filter(|s| s.visualize.is_some())
+            .map(|s| format!("day {} part {}", s.day, s.part))
+            .collect();
+        return Err(format!("Nothing to visualize - the parts that can be drawn are {}", days.join(", ")));
+    }
+
+    for (solution, visualizer) in visualized {
+        let frames = visualizer(input).map_err(|e| format!("Day {} part {} failed - {}", solution.day, solution.part, e))?;
+
+        if let Some(directory) = &options.frames {
+            fs::create_dir_all(directory).map_err(|e| format!("Failed to create '{}' - {}", directory.display(), e))?;
+            for (i, frame) in frames.iter().enumerate() {
+                let path = directory.join(format!("day_{:02}_part_{}_{:04}.txt", solution.day, solution.part, i + 1));
+                fs::write(&path, frame.to_string()).map_err(|e| format!("Failed to write '{}' - {}", path.display(), e))?;
+            }
+        }
+
+        if options.animate {
+            let mut stdout = io::stdout().lock();
+            for frame in frames.iter() {
+                // clear the screen and move back to the top left so each frame replaces the last
+                write!(stdout, "\x1b[2J\x1b[H{}", frame.to_ansi())
+                    .and_then(|_| stdout.flush())
+                    .map_err(|e| format!("Failed to draw frame - {}", e))?;
+                thread::sleep(options.delay);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a picture of the part's result to the file, in the format its extension asked for.
+fn export(solution: &Solution, input: &str, options: &ExportOptions) -> Result<(), String> {
+    let exporter = solution.export.ok_or_else(|| {
+        let parts: Vec<String> = days::SOLUTIONS.iter()
+            .filter(|s| s.export.is_some())
+            .map(|s| format!("day {} part {}", s.day, s.part))
+            .collect();
+        format!("Day {} part {} has no picture to export - the parts that do are {}", solution.day, solution.part, parts.join(", "))
+    })?;
+    let picture = exporter(input).map_err(|e| format!("Day {} part {} failed - {}", solution.day, solution.part, e))?;
+
+    // drawn in memory first so a picture that can't be written in the format leaves no empty file behind
+    let path = &options.path;
+    let mut image = vec![];
+    picture.write(options.format, &mut image).map_err(|e| format!("Failed to export '{}' - {}", path.display(), e))?;
+    fs::write(path, image).map_err(|e| format!("Failed to write '{}' - {}", path.display(), e))
+}
+
+/// Prints the answer to a part, or with JSON output the error as well, failing when the part did.
+fn report(format: &Format, solution: &Solution, result: Result<Answer, Error>) -> Result<(), String> {
+    if *format == Format::Json {
+        println!("{}", to_json(solution, &result));
+    }
+    let answer = result.map_err(|e| format!("Day {} part {} failed - {}", solution.day, solution.part, e))?;
+    if *format == Format::Text {
+        println!("Day {} part {}: {}", solution.day, solution.part, answer);
+    }
+    Ok(())
+}
+
+fn to_json(solution: &Solution, result: &Result<Answer, Error>) -> Json {
+    let json = Json::object().with("day", solution.day).with("part", solution.part);
+    match result {
+        Ok(answer) => json
+            .with("answer", answer.value())
+            .with("details", Json::Object(answer.details().to_vec())),
+        Err(e) => json.with("error", e.to_string()),
+    }
+}
+
+fn run_all(format: &Format) -> Result<(), String> {
+    // the days are solved at the same time but reported in order once they have all finished
+    let results = Pool::configured().map(&days::SOLUTIONS, |solution| {
+        let source = InputSource::File(days::default_input(solution.day));
+        match source.read_to_string() {
+            Ok(input) => (solution.solve)(&input),
+            Err(e) => Err(Error::Input(format!("'{}' - {}", source, e))),
+        }
+    });
+
+    let mut rows = vec![];
+    for (solution, result) in days::SOLUTIONS.iter().zip(results) {
+        if *format == Format::Json {
+            // every part gets its line, failed or not, so one bad day doesn't hide the rest
+            println!("{}", to_json(solution, &result));
+            continue;
+        }
+        // a failing day, or one whose input can't be read, is reported in its row rather than stopping the rest of the table
+        let answer = match result {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {}", e),
+        };
+        rows.push((solution, answer));
+    }
+
+    if *format == Format::Text {
+        print_table(&rows);
+    }
+
+    Ok(())
+}
+
+fn read_input(source: &InputSource) -> Result<String, String> {
+    source.read_to_string().map_err(|e| format!("Failed to read input '{}' - {}", source, e))
+}
+
+fn print_table(rows: &[(&Solution, String)]) {
+    println!("Day | Part | Answer");
+    println!("----+------+-------");
+    for (solution, answer) in rows.iter() {
+        let mut lines = answer.trim_end().lines();
+        println!("{:>3} | {:>4} | {}", solution.day, solution.part, lines.next().unwrap_or(""));
+        // multi-line answers (like day 13's folded paper) continue under the answer column
+        for line in lines {
+            println!("    |      | {}", line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let solution = days::find(2, 1).unwrap();
+        let answer = (solution.solve)("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n");
+        assert_eq!(
+            r#"{"day":2,"part":1,"answer":"150","details":{"horizontal_position":15,"depth":10}}"#,
+            to_json(solution, &answer).to_string()
+        );
+
+        let failed = Err(Error::NoSolution(String::from("no winner")));
+        assert_eq!(r#"{"day":2,"part":1,"error":"No solution - no winner"}"#, to_json(solution, &failed).to_string());
+    }
+}
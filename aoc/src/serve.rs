@@ -0,0 +1,141 @@
+use std::any::Any;
+use std::io::{BufRead, Write};
+use std::panic;
+use std::time::Duration;
+use aoc_common::Json;
+use crate::days::{self, Solution};
+
+/// Answers requests read one per line until the input ends, writing one response line for each.
+///
+/// A request is an object like `{"day":22,"part":2,"input":"..."}`, with an optional `id` of any kind
+/// that is copied into the response so a client can match them up. The response holds the day, part,
+/// answer and details as `--format json` prints them, along with how long parsing and solving took,
+/// or an `error` when the request or the part failed. Everything a solver keeps between calls (like
+/// its compiled regexes) stays in memory from one request to the next.
+pub fn serve<R: BufRead, W: Write>(requests: R, mut responses: W) -> Result<(), String> {
+    for line in requests.lines() {
+        let line = line.map_err(|e| format!("Failed to read request - {}", e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        // flushed every time, as the client waits for each response before sending its next request
+        writeln!(responses, "{}", respond(&line))
+            .and_then(|_| responses.flush())
+            .map_err(|e| format!("Failed to write response - {}", e))?;
+    }
+    Ok(())
+}
+
+fn respond(line: &str) -> Json {
+    let request = match Json::parse(line) {
+        Ok(request) => request,
+        Err(e) => return Json::object().with("error", format!("Invalid request - {}", e)),
+    };
+
+    let response = match read_request(&request) {
+        Ok((solution, input)) => solve(solution, input),
+        Err(message) => Json::object().with("error", message),
+    };
+    match request.get("id") {
+        Some(id) => response.with("id", id.clone()),
+        None => response,
+    }
+}
+
+fn read_request(request: &Json) -> Result<(&'static Solution, &str), String> {
+    let number = |field: &str| {
+        request.get(field)
+            .and_then(Json::as_number)
+            .and_then(|n| u32::try_from(n).ok())
+            .ok_or_else(|| format!("Invalid request - expected a number for '{}'", field))
+    };
+    let day = number("day")?;
+    let part = number("part")?;
+    let input = request.get("input")
+        .and_then(Json::as_str)
+        .ok_or_else(|| String::from("Invalid request - expected a string for 'input'"))?;
+
+    let solution = days::find(day, part).ok_or_else(|| format!("Day {} part {} has not been implemented", day, part))?;
+    Ok((solution, input))
+}
+
+fn solve(solution: &Solution, input: &str) -> Json {
+    // a solver that panics on a bad input fails its own request rather than taking the server down
+    match panic::catch_unwind(|| (solution.time)(input, 1)) {
+        Ok(Ok(timings)) => crate::to_json(solution, &Ok(timings.answer().clone())).with(
+            "timings",
+            Json::object()
+                .with("parse_ns", nanos(timings.parse().median()))
+                .with("solve_ns", nanos(timings.solve().median())),
+        ),
+        Ok(Err(e)) => crate::to_json(solution, &Err(e)),
+        Err(payload) => Json::object()
+            .with("day", solution.day)
+            .with("part", solution.part)
+            .with("error", format!("Panicked - {}", panic_message(payload.as_ref()))),
+    }
+}
+
+fn nanos(duration: Duration) -> Json {
+    Json::Number(duration.as_nanos() as i128)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message,
+        _ => "no message",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COURSE: &str = r#""forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n""#;
+
+    fn serve_lines(requests: &str) -> Vec<Json> {
+        let mut responses = vec![];
+        serve(requests.as_bytes(), &mut responses).unwrap();
+        String::from_utf8(responses).unwrap().lines().map(|line| Json::parse(line).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_serve() {
+        let requests = format!(
+            "{{\"day\":2,\"part\":1,\"input\":{}}}\n\n{{\"id\":\"second\",\"day\":2,\"part\":2,\"input\":{}}}\n",
+            COURSE, COURSE
+        );
+        let responses = serve_lines(&requests);
+        assert_eq!(2, responses.len());
+
+        assert_eq!(Some("150"), responses[0].get("answer").and_then(Json::as_str));
+        assert_eq!(Some(15), responses[0].get("details").and_then(|d| d.get("horizontal_position")).and_then(Json::as_number));
+        assert!(responses[0].get("timings").and_then(|t| t.get("solve_ns")).and_then(Json::as_number).is_some());
+        assert_eq!(None, responses[0].get("id"));
+
+        assert_eq!(Some("900"), responses[1].get("answer").and_then(Json::as_str));
+        assert_eq!(Some("second"), responses[1].get("id").and_then(Json::as_str));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |request: &str| respond(request).get("error").and_then(Json::as_str).map(String::from);
+
+        assert_eq!(
+            Some(String::from("Invalid request - line 1, column 9: expected a value but found '}'")),
+            error(r#"{"day": }"#)
+        );
+        assert_eq!(Some(String::from("Invalid request - expected a number for 'part'")), error(r#"{"day":2,"input":""}"#));
+        assert_eq!(Some(String::from("Invalid request - expected a string for 'input'")), error(r#"{"day":2,"part":1}"#));
+        assert_eq!(Some(String::from("Day 19 part 1 has not been implemented")), error(r#"{"day":19,"part":1,"input":""}"#));
+        assert_eq!(
+            Some(String::from("Failed to parse input\n  line 1, column 1: expected 'forward', 'down' or 'up' but found 'sideways'")),
+            error(r#"{"day":2,"part":1,"input":"sideways 5"}"#)
+        );
+
+        let failed = respond(r#"{"id":7,"day":2,"part":1,"input":"sideways 5"}"#);
+        assert_eq!(Some(2), failed.get("day").and_then(Json::as_number));
+        assert_eq!(Some(7), failed.get("id").and_then(Json::as_number));
+    }
+}
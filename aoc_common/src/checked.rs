@@ -0,0 +1,70 @@
+use crate::puzzle::Error;
+
+/// Whether arithmetic through `Checked` reports overflow. It always does in debug builds, and in
+/// release builds when `aoc_common` is built with the `checked` feature - otherwise it wraps around,
+/// just like the plain operators do in release builds.
+pub const OVERFLOW_CHECKED: bool = cfg!(any(debug_assertions, feature = "checked"));
+
+/// Arithmetic that fails with `Error::Overflow` naming the operation, rather than wrapping around or
+/// panicking wherever the result happens to be used.
+///
+/// The operation is a short description of what was being worked out, like `"depth + aim * units"`,
+/// so the error says which value got too big for its type.
+pub trait Checked: Sized {
+    fn add_checked(self, other: Self, operation: &str) -> Result<Self, Error>;
+
+    fn sub_checked(self, other: Self, operation: &str) -> Result<Self, Error>;
+
+    fn mul_checked(self, other: Self, operation: &str) -> Result<Self, Error>;
+}
+
+macro_rules! checked_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Checked for $t {
+                fn add_checked(self, other: $t, operation: &str) -> Result<$t, Error> {
+                    checked(self.checked_add(other), self.wrapping_add(other), operation)
+                }
+
+                fn sub_checked(self, other: $t, operation: &str) -> Result<$t, Error> {
+                    checked(self.checked_sub(other), self.wrapping_sub(other), operation)
+                }
+
+                fn mul_checked(self, other: $t, operation: &str) -> Result<$t, Error> {
+                    checked(self.checked_mul(other), self.wrapping_mul(other), operation)
+                }
+            }
+        )*
+    };
+}
+
+checked_integer!(i32, i64, u8, u16, u32, u64, usize);
+
+fn checked<T>(checked: Option<T>, wrapped: T, operation: &str) -> Result<T, Error> {
+    match checked {
+        Some(value) => Ok(value),
+        None if OVERFLOW_CHECKED => Err(Error::Overflow(String::from(operation))),
+        None => Ok(wrapped),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_range() {
+        assert_eq!(Ok(7), 3.add_checked(4, "sum"));
+        assert_eq!(Ok(-1), 3.sub_checked(4, "difference"));
+        assert_eq!(Ok(12u16), 3u16.mul_checked(4, "product"));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // tests are built with debug assertions, so overflow is always checked here
+        assert_eq!(Err(Error::Overflow(String::from("score + position"))), u16::MAX.add_checked(1, "score + position"));
+        assert_eq!(Err(Error::Overflow(String::from("max - min"))), 0u64.sub_checked(1, "max - min"));
+        assert_eq!(Err(Error::Overflow(String::from("depth * position"))), i32::MAX.mul_checked(2, "depth * position"));
+        assert_eq!("Overflow while working out depth * position", i32::MAX.mul_checked(2, "depth * position").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,168 @@
+use std::str::FromStr;
+use crate::parse::ParseError;
+
+/// Reads tokens from left to right through a single line of input, keeping track of the column so
+/// that anything unexpected can be reported as a `ParseError`.
+///
+/// Whitespace between tokens is skipped.
+pub struct Cursor<'a> {
+    line: &'a str,
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: &'a str) -> Cursor<'a> {
+        Cursor {
+            line,
+            offset: 0,
+        }
+    }
+
+    /// The 1-based column of the next character to be read.
+    pub fn column(&self) -> usize {
+        self.line[..self.offset].chars().count() + 1
+    }
+
+    pub fn is_done(&mut self) -> bool {
+        self.skip_whitespace();
+        self.offset == self.line.len()
+    }
+
+    /// Consumes `literal` if it is next, without reporting an error if it is not.
+    pub fn accept(&mut self, literal: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(literal) {
+            self.offset += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.accept(literal) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("'{}'", literal)))
+        }
+    }
+
+    /// Reads a run of letters, like a command name or a polymer pair.
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        let length = self.rest().find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(self.rest().len());
+        if length == 0 {
+            return Err(self.error(expected));
+        }
+        let word = &self.rest()[..length];
+        self.offset += length;
+        Ok(word)
+    }
+
+    /// Reads a word that must be exactly `count` letters long, like a pair of polymer elements.
+    pub fn letters(&mut self, count: usize, expected: &str) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        let length = self.rest().find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(self.rest().len());
+        if length != count {
+            return Err(self.error(expected));
+        }
+        self.word(expected)
+    }
+
+    /// Reads an integer with an optional leading minus sign.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let sign = if rest.starts_with('-') { 1 } else { 0 };
+        let length = sign + rest[sign..].find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len() - sign);
+        match rest[..length].parse() {
+            // a lone minus sign has no digits and fails to parse
+            Ok(number) if length > sign => {
+                self.offset += length;
+                Ok(number)
+            },
+            _ => Err(self.error("a number")),
+        }
+    }
+
+    /// Checks that nothing but whitespace is left on the line.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        if self.is_done() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    /// Builds an error at the current column, describing the next token as what was found.
+    pub fn error(&mut self, expected: &str) -> ParseError {
+        self.skip_whitespace();
+        ParseError::new(self.column(), expected, self.next_token())
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.line[self.offset..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    fn next_token(&self) -> &'a str {
+        let rest = self.rest();
+        match rest.chars().next() {
+            Some(c) if c.is_alphanumeric() || c == '-' => {
+                let length = rest[1..].find(|c: char| !c.is_alphanumeric()).map(|l| l + 1).unwrap_or(rest.len());
+                &rest[..length]
+            },
+            Some(c) => &rest[..c.len_utf8()],
+            None => "",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let mut cursor = Cursor::new("  forward -12 -> x=3..5");
+        assert_eq!(Ok("forward"), cursor.word("a command"));
+        assert_eq!(Ok(-12), cursor.number::<i32>());
+        assert_eq!(Ok(()), cursor.expect("->"));
+        assert!(!cursor.accept("y="));
+        assert!(cursor.accept("x="));
+        assert_eq!(Ok(3), cursor.number::<u32>());
+        assert_eq!(Ok(()), cursor.expect(".."));
+        assert_eq!(Ok(5), cursor.number::<u32>());
+        assert_eq!(Ok(()), cursor.end());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut cursor = Cursor::new("forward five");
+        assert_eq!(Ok("forward"), cursor.word("a command"));
+        assert_eq!(Err(ParseError::new(9, "a number", "five")), cursor.number::<i32>());
+
+        let mut cursor = Cursor::new("1,2 3");
+        assert_eq!(Ok(1), cursor.number::<i32>());
+        assert_eq!(Ok(()), cursor.expect(","));
+        assert_eq!(Ok(2), cursor.number::<i32>());
+        assert_eq!(Err(ParseError::new(5, "end of line", "3")), cursor.end());
+
+        let mut cursor = Cursor::new("-");
+        assert_eq!(Err(ParseError::new(1, "a number", "-")), cursor.number::<i32>());
+
+        let mut cursor = Cursor::new("-4");
+        assert_eq!(Err(ParseError::new(1, "a number", "-4")), cursor.number::<u32>());
+
+        let mut cursor = Cursor::new("up");
+        assert_eq!(Ok("up"), cursor.word("a command"));
+        assert_eq!(Err(ParseError::new(3, "a number", "")), cursor.number::<i32>());
+
+        let mut cursor = Cursor::new("CHH -> B");
+        assert_eq!(Err(ParseError::new(1, "a pair", "CHH")), cursor.letters(2, "a pair"));
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt::{self, Display};
+use crate::grid::Grid;
+use crate::point::Position;
+
+/// The terminal colours a cell can be drawn in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Colour {
+    Plain,
+    Dim,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Bright,
+}
+
+impl Colour {
+    /// Colours that stand apart from each other, for telling neighbouring regions apart.
+    pub const PALETTE: [Colour; 6] = [Colour::Red, Colour::Green, Colour::Yellow, Colour::Blue, Colour::Magenta, Colour::Cyan];
+
+    fn ansi_code(&self) -> &'static str {
+        match self {
+            Colour::Plain => "0",
+            Colour::Dim => "2",
+            Colour::Red => "31",
+            Colour::Green => "32",
+            Colour::Yellow => "33",
+            Colour::Blue => "34",
+            Colour::Magenta => "35",
+            Colour::Cyan => "36",
+            Colour::Bright => "1;97",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    symbol: char,
+    colour: Colour,
+}
+
+impl Cell {
+    pub fn new(symbol: char) -> Cell {
+        Cell::coloured(symbol, Colour::Plain)
+    }
+
+    pub fn coloured(symbol: char, colour: Colour) -> Cell {
+        Cell {
+            symbol,
+            colour,
+        }
+    }
+
+    pub fn symbol(&self) -> char {
+        self.symbol
+    }
+
+    pub fn colour(&self) -> Colour {
+        self.colour
+    }
+}
+
+/// A snapshot of a puzzle's 2D state, drawn either in colour for the terminal or as plain text.
+///
+/// The plain text form (its `Display`) has a title line followed by one line per row, so frames
+/// written out by `aoc run --frames` can be compared with an ordinary diff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    title: String,
+    cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn new(title: &str, cells: Grid<Cell>) -> Frame {
+        Frame {
+            title: String::from(title),
+            cells,
+        }
+    }
+
+    /// Draws every value of a grid as the cell `draw` picks for it.
+    pub fn from_grid<T, F>(title: &str, grid: &Grid<T>, draw: F) -> Frame
+    where
+        F: Fn(Position, &T) -> Cell,
+    {
+        let mut cells = Grid::new();
+        for (y, row) in grid.rows().enumerate() {
+            cells.push_row(row.iter().enumerate().map(|(x, value)| draw(Position::new(x, y), value)).collect());
+        }
+        Frame::new(title, cells)
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// The frame with ANSI colour codes, only switching colour where it changes along a row.
+    pub fn to_ansi(&self) -> String {
+        let mut output = format!("\x1b[1m{}\x1b[0m\n", self.title);
+        for row in self.cells.rows() {
+            let mut colour = Colour::Plain;
+            for cell in row.iter() {
+                if cell.colour != colour {
+                    output.push_str(&format!("\x1b[0;{}m", cell.colour.ansi_code()));
+                    colour = cell.colour;
+                }
+                output.push(cell.symbol);
+            }
+            if colour != Colour::Plain {
+                output.push_str("\x1b[0m");
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.title)?;
+        for row in self.cells.rows() {
+            writeln!(f, "{}", row.iter().map(|c| c.symbol).collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid = Grid::from_rows(vec![vec![1, 9], vec![9, 2]]);
+        Frame::from_grid("Step 1", &grid, |_, &v| {
+            if v == 9 {
+                Cell::coloured('#', Colour::Red)
+            } else {
+                Cell::new(char::from_digit(v, 10).unwrap())
+            }
+        })
+    }
+
+    #[test]
+    fn test_plain() {
+        assert_eq!("Step 1\n1#\n#2\n", frame().to_string());
+    }
+
+    #[test]
+    fn test_ansi() {
+        assert_eq!("\x1b[1mStep 1\x1b[0m\n1\x1b[0;31m#\x1b[0m\n\x1b[0;31m#\x1b[0;0m2\n", frame().to_ansi());
+    }
+}
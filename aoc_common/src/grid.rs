@@ -0,0 +1,269 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use crate::point::Position;
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new() -> Grid<T> {
+        Grid {
+            cells: vec![],
+            width: 0,
+            height: 0,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let mut grid = Grid::new();
+        for row in rows.into_iter() {
+            grid.push_row(row);
+        }
+        grid
+    }
+
+    pub fn push_row(&mut self, mut row: Vec<T>) {
+        if self.height == 0 {
+            self.width = row.len();
+        } else if self.width != row.len() {
+            panic!("Grid has a width of {}, cannot add a row of width {}", self.width, row.len());
+        }
+
+        self.cells.append(&mut row);
+        self.height += 1;
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x() < self.width && position.y() < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[self.index_of(position)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Looks up a cell with signed coordinates, so callers can probe past any edge of the grid.
+    pub fn at(&self, x: isize, y: isize) -> Option<&T> {
+        if x < 0 || y < 0 {
+            None
+        } else {
+            self.get(Position::new(x as usize, y as usize))
+        }
+    }
+
+    /// The top-left cell, or `None` if the grid is empty.
+    pub fn first_position(&self) -> Option<Position> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(Position::new(0, 0))
+        }
+    }
+
+    /// The bottom-right cell, or `None` if the grid is empty.
+    pub fn last_position(&self) -> Option<Position> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(Position::new(self.width - 1, self.height - 1))
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Position::new(i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero so an empty grid uses a width of one - there are no cells to chunk anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells directly above, left, right and below, skipping any that fall outside the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &NEIGHBOURS_4)
+    }
+
+    /// The orthogonal and diagonal neighbours, skipping any that fall outside the grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &NEIGHBOURS_8)
+    }
+
+    fn neighbours(&self, position: Position, deltas: &'static [(isize, isize)]) -> impl Iterator<Item = Position> + '_ {
+        deltas
+            .iter()
+            .filter_map(move |(delta_x, delta_y)| position.offset(*delta_x, *delta_y))
+            .filter(move |p| self.contains(*p))
+    }
+
+    fn index_of(&self, position: Position) -> usize {
+        position.x() + position.y() * self.width
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Grid<T> {
+        Grid::new()
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("Position ({}, {}) is outside of the {}x{} grid", position.x(), position.y(), self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("Position ({}, {}) is outside of the {}x{} grid", position.x(), position.y(), width, height))
+    }
+}
+
+/// Writes each row on its own line with no separator between cells, which suits single character cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row.iter() {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn demo() -> Grid<u32> {
+        Grid::from_rows(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+        ])
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = demo();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(6, grid.len());
+        assert_eq!(4, grid[Position::new(0, 1)]);
+        assert_eq!(Some(&6), grid.get(Position::new(2, 1)));
+        assert_eq!(None, grid.get(Position::new(3, 0)));
+        assert_eq!(None, grid.at(-1, 0));
+        assert_eq!(Some(&2), grid.at(1, 0));
+        assert_eq!(Some(Position::new(2, 1)), grid.last_position());
+    }
+
+    #[test]
+    #[should_panic(expected = "Grid has a width of 3, cannot add a row of width 2")]
+    fn test_ragged_rows() {
+        let mut grid = demo();
+        grid.push_row(vec![7, 8]);
+    }
+
+    #[test]
+    fn test_positions() {
+        let grid = demo();
+        let positions = grid.positions().collect::<Vec<Position>>();
+        assert_eq!(Position::new(0, 0), positions[0]);
+        assert_eq!(Position::new(2, 0), positions[2]);
+        assert_eq!(Position::new(0, 1), positions[3]);
+        assert_eq!(vec![(Position::new(1, 1), &5)], grid.iter().filter(|(_, v)| **v == 5).collect::<Vec<(Position, &u32)>>());
+    }
+
+    #[test]
+    fn test_neighbours_4() {
+        let grid = demo();
+        let corner = grid.neighbours_4(Position::new(0, 0)).collect::<Vec<Position>>();
+        assert_eq!(vec![Position::new(1, 0), Position::new(0, 1)], corner);
+
+        let edge = grid.neighbours_4(Position::new(1, 1)).collect::<Vec<Position>>();
+        assert_eq!(vec![Position::new(1, 0), Position::new(0, 1), Position::new(2, 1)], edge);
+    }
+
+    #[test]
+    fn test_neighbours_8() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(3, grid.neighbours_8(Position::new(0, 0)).count());
+        assert_eq!(5, grid.neighbours_8(Position::new(1, 0)).count());
+        assert_eq!(8, grid.neighbours_8(Position::new(1, 1)).count());
+        assert!(!grid.neighbours_8(Position::new(1, 1)).any(|p| p == Position::new(1, 1)));
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Grid::from_rows(vec![
+            vec!['#', '.'],
+            vec!['.', '#'],
+        ]);
+        assert_eq!("#.\n.#", grid.to_string());
+    }
+}
@@ -0,0 +1,334 @@
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::path::Path;
+use crate::grid::Grid;
+use crate::point::{Point, Position};
+
+/// The image formats a picture can be exported as, picked from the exported file's extension.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageFormat {
+    /// Binary (P6) portable pixmap, in full colour.
+    Ppm,
+    /// Binary (P5) portable graymap.
+    Pgm,
+    Svg,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Option<ImageFormat> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "ppm" => Some(ImageFormat::Ppm),
+            "pgm" => Some(ImageFormat::Pgm),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgb {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+    pub const RED: Rgb = Rgb::new(220, 40, 40);
+
+    /// Colours that stand apart from each other, for telling neighbouring regions apart.
+    pub const PALETTE: [Rgb; 6] = [
+        Rgb::new(230, 80, 80),
+        Rgb::new(90, 200, 90),
+        Rgb::new(240, 200, 60),
+        Rgb::new(80, 130, 230),
+        Rgb::new(200, 90, 210),
+        Rgb::new(70, 200, 210),
+    ];
+
+    pub const fn new(red: u8, green: u8, blue: u8) -> Rgb {
+        Rgb {
+            red,
+            green,
+            blue,
+        }
+    }
+
+    pub const fn grey(level: u8) -> Rgb {
+        Rgb::new(level, level, level)
+    }
+
+    /// Runs from black through red and yellow to white as `value` goes from 0 up to `max`.
+    pub fn heat(value: u32, max: u32) -> Rgb {
+        if max == 0 {
+            return Rgb::BLACK;
+        }
+        let level = (value.min(max) as u64 * 765 / max as u64) as u32;
+        Rgb::new(level.min(255) as u8, level.saturating_sub(255).min(255) as u8, level.saturating_sub(510) as u8)
+    }
+
+    /// The perceived brightness, for writing the colour to a graymap.
+    pub fn luma(&self) -> u8 {
+        ((self.red as u32 * 299 + self.green as u32 * 587 + self.blue as u32 * 114) / 1000) as u8
+    }
+
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+/// A picture made of a grid of pixels, each drawn `scale` pixels wide and high when written out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Raster {
+    pixels: Grid<Rgb>,
+    scale: usize,
+}
+
+impl Raster {
+    pub fn new(pixels: Grid<Rgb>) -> Raster {
+        Raster {
+            pixels,
+            scale: 1,
+        }
+    }
+
+    /// Paints every value of a grid as the colour `paint` picks for it.
+    pub fn from_grid<T, F>(grid: &Grid<T>, paint: F) -> Raster
+    where
+        F: Fn(Position, &T) -> Rgb,
+    {
+        let mut pixels = Grid::new();
+        for (y, row) in grid.rows().enumerate() {
+            pixels.push_row(row.iter().enumerate().map(|(x, value)| paint(Position::new(x, y), value)).collect());
+        }
+        Raster::new(pixels)
+    }
+
+    /// Counts how many times each point appears, over the smallest area covering all of them, and
+    /// paints the counts from black up to white for the most often seen.
+    pub fn heat_map(points: &[Point]) -> Raster {
+        let (Some(min_x), Some(min_y)) = (points.iter().map(|p| p.x()).min(), points.iter().map(|p| p.y()).min()) else {
+            return Raster::new(Grid::new());
+        };
+        let width = points.iter().map(|p| p.x()).max().unwrap().abs_diff(min_x) as usize + 1;
+        let height = points.iter().map(|p| p.y()).max().unwrap().abs_diff(min_y) as usize + 1;
+
+        let mut counts = Grid::filled(width, height, 0);
+        for point in points.iter() {
+            counts[Position::new(point.x().abs_diff(min_x) as usize, point.y().abs_diff(min_y) as usize)] += 1;
+        }
+        let max = counts.values().copied().max().unwrap_or(0);
+        Raster::from_grid(&counts, |_, &count| Rgb::heat(count, max))
+    }
+
+    pub fn scaled(self, scale: usize) -> Raster {
+        Raster {
+            scale: scale.max(1),
+            ..self
+        }
+    }
+
+    pub fn pixels(&self) -> &Grid<Rgb> {
+        &self.pixels
+    }
+
+    pub fn write_ppm(&self, output: &mut dyn Write) -> io::Result<()> {
+        self.write_netpbm(output, "P6", |pixel, bytes| bytes.extend([pixel.red, pixel.green, pixel.blue]))
+    }
+
+    pub fn write_pgm(&self, output: &mut dyn Write) -> io::Result<()> {
+        self.write_netpbm(output, "P5", |pixel, bytes| bytes.push(pixel.luma()))
+    }
+
+    fn write_netpbm<F>(&self, output: &mut dyn Write, magic: &str, encode: F) -> io::Result<()>
+    where
+        F: Fn(&Rgb, &mut Vec<u8>),
+    {
+        write!(output, "{}\n{} {}\n255\n", magic, self.pixels.width() * self.scale, self.pixels.height() * self.scale)?;
+        for row in self.pixels.rows() {
+            let mut bytes = vec![];
+            for pixel in row.iter() {
+                for _ in 0..self.scale {
+                    encode(pixel, &mut bytes);
+                }
+            }
+            for _ in 0..self.scale {
+                output.write_all(&bytes)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Each run of same coloured pixels along a row becomes a single rectangle, which keeps the
+    /// file small for pictures that are mostly background.
+    pub fn to_svg(&self) -> Svg {
+        let scale = self.scale as i64;
+        let mut svg = Svg::new(0, 0, self.pixels.width() as i64 * scale, self.pixels.height() as i64 * scale);
+        for (y, row) in self.pixels.rows().enumerate() {
+            let mut start = 0;
+            for x in 1..=row.len() {
+                if x == row.len() || row[x] != row[start] {
+                    svg.rect(start as i64 * scale, y as i64 * scale, (x - start) as i64 * scale, scale, row[start]);
+                    start = x;
+                }
+            }
+        }
+        svg
+    }
+}
+
+/// A picture made of shapes, drawn over the region of the plane given by its view box.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svg {
+    view_box: (i64, i64, i64, i64),
+    size: (i64, i64),
+    elements: Vec<String>,
+}
+
+impl Svg {
+    pub fn new(min_x: i64, min_y: i64, width: i64, height: i64) -> Svg {
+        Svg {
+            view_box: (min_x, min_y, width.max(1), height.max(1)),
+            size: (width.max(1), height.max(1)),
+            elements: vec![],
+        }
+    }
+
+    /// Draws the view box stretched to the given size, for plots far taller than they are wide (or
+    /// the other way round). Lines keep their width however the picture is stretched.
+    pub fn sized(self, width: i64, height: i64) -> Svg {
+        Svg {
+            size: (width.max(1), height.max(1)),
+            ..self
+        }
+    }
+
+    pub fn rect(&mut self, x: i64, y: i64, width: i64, height: i64, fill: Rgb) {
+        self.elements.push(format!(r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#, x, y, width, height, fill.hex()));
+    }
+
+    pub fn polyline(&mut self, points: &[(i64, i64)], stroke: Rgb) {
+        let mut coordinates = String::new();
+        for (i, (x, y)) in points.iter().enumerate() {
+            let separator = if i == 0 { "" } else { " " };
+            write!(coordinates, "{}{},{}", separator, x, y).unwrap();
+        }
+        self.elements.push(format!(
+            r#"<polyline points="{}" fill="none" stroke="{}" vector-effect="non-scaling-stroke"/>"#,
+            coordinates,
+            stroke.hex()
+        ));
+    }
+
+    pub fn write_svg(&self, output: &mut dyn Write) -> io::Result<()> {
+        let (min_x, min_y, width, height) = self.view_box;
+        writeln!(
+            output,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}" preserveAspectRatio="none">"#,
+            min_x, min_y, width, height, self.size.0, self.size.1
+        )?;
+        for element in self.elements.iter() {
+            writeln!(output, "{}", element)?;
+        }
+        writeln!(output, "</svg>")
+    }
+}
+
+/// A picture of a puzzle's result, ready to be written in whichever format was asked for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Picture {
+    Raster(Raster),
+    /// Shapes have no pixels to write, so can only be exported as SVG.
+    Vector(Svg),
+}
+
+impl Picture {
+    pub fn write(&self, format: ImageFormat, output: &mut dyn Write) -> io::Result<()> {
+        match (self, format) {
+            (Picture::Raster(raster), ImageFormat::Ppm) => raster.write_ppm(output),
+            (Picture::Raster(raster), ImageFormat::Pgm) => raster.write_pgm(output),
+            (Picture::Raster(raster), ImageFormat::Svg) => raster.to_svg().write_svg(output),
+            (Picture::Vector(svg), ImageFormat::Svg) => svg.write_svg(output),
+            (Picture::Vector(_), _) => Err(io::Error::new(io::ErrorKind::InvalidInput, "This picture is made of shapes and can only be exported as SVG")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raster() -> Raster {
+        Raster::new(Grid::from_rows(vec![vec![Rgb::WHITE, Rgb::RED], vec![Rgb::BLACK, Rgb::BLACK]]))
+    }
+
+    fn written(picture: &Picture, format: ImageFormat) -> Vec<u8> {
+        let mut output = vec![];
+        picture.write(format, &mut output).unwrap();
+        output
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(Some(ImageFormat::Ppm), ImageFormat::from_path(Path::new("out/basins.ppm")));
+        assert_eq!(Some(ImageFormat::Svg), ImageFormat::from_path(Path::new("arcs.SVG")));
+        assert_eq!(None, ImageFormat::from_path(Path::new("image.png")));
+        assert_eq!(None, ImageFormat::from_path(Path::new("image")));
+    }
+
+    #[test]
+    fn test_heat() {
+        assert_eq!(Rgb::BLACK, Rgb::heat(0, 4));
+        assert_eq!(Rgb::WHITE, Rgb::heat(4, 4));
+        assert_eq!(Rgb::new(255, 127, 0), Rgb::heat(2, 4));
+        assert_eq!(Rgb::BLACK, Rgb::heat(3, 0));
+    }
+
+    #[test]
+    fn test_heat_map() {
+        let points = vec![Point::new(-1, 2), Point::new(0, 2), Point::new(0, 2), Point::new(0, 3)];
+        let raster = Raster::heat_map(&points);
+        assert_eq!(
+            Grid::from_rows(vec![vec![Rgb::new(255, 127, 0), Rgb::WHITE], vec![Rgb::BLACK, Rgb::new(255, 127, 0)]]),
+            *raster.pixels()
+        );
+        assert!(Raster::heat_map(&[]).pixels().is_empty());
+    }
+
+    #[test]
+    fn test_ppm() {
+        let picture = Picture::Raster(raster());
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([255, 255, 255, 220, 40, 40, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(expected, written(&picture, ImageFormat::Ppm));
+
+        let picture = Picture::Raster(raster().scaled(2));
+        let mut expected = b"P5\n4 4\n255\n".to_vec();
+        expected.extend([255, 255, 93, 93, 255, 255, 93, 93, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(expected, written(&picture, ImageFormat::Pgm));
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = String::from_utf8(written(&Picture::Raster(raster().scaled(3)), ImageFormat::Svg)).unwrap();
+        assert_eq!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 6 6" width="6" height="6" preserveAspectRatio="none">
+<rect x="0" y="0" width="3" height="3" fill="#ffffff"/>
+<rect x="3" y="0" width="3" height="3" fill="#dc2828"/>
+<rect x="0" y="3" width="6" height="3" fill="#000000"/>
+</svg>
+"##,
+            svg
+        );
+
+        let mut shapes = Svg::new(-1, -10, 20, 12).sized(200, 120);
+        shapes.polyline(&[(0, 0), (5, -4), (9, -7)], Rgb::RED);
+        let picture = Picture::Vector(shapes);
+        let svg = String::from_utf8(written(&picture, ImageFormat::Svg)).unwrap();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-1 -10 20 12" width="200" height="120""#));
+        assert!(svg.contains(r##"<polyline points="0,0 5,-4 9,-7" fill="none" stroke="#dc2828" vector-effect="non-scaling-stroke"/>"##));
+
+        assert!(picture.write(ImageFormat::Ppm, &mut vec![]).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt::{self, Display};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+/// Where puzzle input is read from - a file, or stdin when given `-`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn from(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Uses the first command line argument, falling back to the day's own input file when there is none.
+    pub fn from_args(default_path: &str) -> InputSource {
+        match std::env::args().nth(1) {
+            Some(arg) => InputSource::from(&arg),
+            None => InputSource::File(PathBuf::from(default_path)),
+        }
+    }
+
+    /// Opens the input for reading line by line without loading all of it.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+        }
+    }
+
+    pub fn read_to_string(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            },
+            InputSource::File(path) => fs::read_to_string(path),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(InputSource::Stdin, InputSource::from("-"));
+        assert_eq!(InputSource::File(PathBuf::from("input.txt")), InputSource::from("input.txt"));
+        assert_eq!("stdin", InputSource::Stdin.to_string());
+    }
+}
@@ -0,0 +1,411 @@
+use std::fmt::{self, Display, Write};
+use crate::parse::ParseError;
+
+/// A JSON value, just enough to report answers and the values that led to them without pulling in a serializer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(i128),
+    String(String),
+    Array(Vec<Json>),
+    /// Keys stay in the order they were added so output is stable from run to run.
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn object() -> Json {
+        Json::Object(vec![])
+    }
+
+    /// Adds a field to an object, panicking when used on any other kind of value.
+    pub fn with(self, key: &str, value: impl Into<Json>) -> Json {
+        match self {
+            Json::Object(mut fields) => {
+                fields.push((String::from(key), value.into()));
+                Json::Object(fields)
+            },
+            other => panic!("Expected an object to add '{}' to - found {}", key, other),
+        }
+    }
+
+    /// Reads a single JSON value, such as a request sent to `aoc serve`.
+    ///
+    /// Numbers have to be integers, as those are the only numbers a `Json` can hold.
+    pub fn parse(text: &str) -> Result<Json, ParseError> {
+        let mut reader = Reader {
+            text,
+            offset: 0,
+        };
+        let value = reader.value()?;
+        reader.skip_whitespace();
+        if reader.offset < text.len() {
+            return Err(reader.error("end of input"));
+        }
+        Ok(value)
+    }
+
+    /// The value of an object's field, or `None` for a missing field or a value that isn't an object.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_number(&self) -> Option<i128> {
+        match self {
+            Json::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// Reads JSON text from left to right, keeping the offset so errors can give a line and column.
+struct Reader<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn value(&mut self) -> Result<Json, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => self.string().map(Json::String),
+            Some('-') | Some('0'..='9') => self.number(),
+            _ if self.accept("null") => Ok(Json::Null),
+            _ if self.accept("true") => Ok(Json::Bool(true)),
+            _ if self.accept("false") => Ok(Json::Bool(false)),
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, ParseError> {
+        self.expect('{')?;
+        let mut fields = vec![];
+        self.skip_whitespace();
+        if self.accept("}") {
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("a field name"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            if !self.accept(",") {
+                self.expect('}')?;
+                return Ok(Json::Object(fields));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, ParseError> {
+        self.expect('[')?;
+        let mut values = vec![];
+        self.skip_whitespace();
+        if self.accept("]") {
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            if !self.accept(",") {
+                self.expect(']')?;
+                return Ok(Json::Array(values));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.offset += 1;
+                    return Ok(value);
+                },
+                Some('\\') => {
+                    self.offset += 1;
+                    value.push(self.escape()?);
+                },
+                Some(c) if (c as u32) < 0x20 => return Err(self.error("an escaped control character")),
+                Some(c) => {
+                    value.push(c);
+                    self.offset += c.len_utf8();
+                },
+                None => return Err(self.error("'\"'")),
+            }
+        }
+    }
+
+    /// The character an escape stands for, reading what follows the backslash.
+    fn escape(&mut self) -> Result<char, ParseError> {
+        let c = match self.peek() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                self.offset += 1;
+                let code = self.hex_code()?;
+                // characters outside the basic plane are written as a pair of surrogates
+                let code = if (0xd800..0xdc00).contains(&code) && self.accept("\\u") {
+                    let low = self.hex_code()?;
+                    0x10000 + ((code - 0xd800) << 10) + (low.wrapping_sub(0xdc00) & 0x3ff)
+                } else {
+                    code
+                };
+                return char::from_u32(code).ok_or_else(|| self.error("a valid unicode escape"));
+            },
+            _ => return Err(self.error("an escape")),
+        };
+        self.offset += 1;
+        Ok(c)
+    }
+
+    fn hex_code(&mut self) -> Result<u32, ParseError> {
+        let digits = self.text.get(self.offset..self.offset + 4).filter(|d| d.chars().all(|c| c.is_ascii_hexdigit()));
+        match digits {
+            Some(digits) => {
+                self.offset += 4;
+                Ok(u32::from_str_radix(digits, 16).unwrap())
+            },
+            None => Err(self.error("4 hex digits")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, ParseError> {
+        let rest = &self.text[self.offset..];
+        let sign = if rest.starts_with('-') { 1 } else { 0 };
+        let length = sign + rest[sign..].find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len() - sign);
+        let fraction = rest[length..].starts_with(['.', 'e', 'E']);
+        match rest[..length].parse() {
+            Ok(number) if !fraction => {
+                self.offset += length;
+                Ok(Json::Number(number))
+            },
+            _ => Err(self.error("an integer")),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.offset..].chars().next()
+    }
+
+    fn accept(&mut self, literal: &str) -> bool {
+        if self.text[self.offset..].starts_with(literal) {
+            self.offset += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.peek() == Some(c) {
+            self.offset += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("'{}'", c)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.offset..];
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    /// Builds an error at the current offset, describing the next token as what was found.
+    fn error(&self, expected: &str) -> ParseError {
+        let before = &self.text[..self.offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+
+        let rest = &self.text[self.offset..];
+        let found = match rest.chars().next() {
+            Some(c) if c.is_alphanumeric() || c == '-' => {
+                let length = rest[1..].find(|c: char| !c.is_alphanumeric()).map(|l| l + 1).unwrap_or(rest.len());
+                &rest[..length]
+            },
+            Some(c) => &rest[..c.len_utf8()],
+            None => "",
+        };
+        ParseError::new(column, expected, found).on_line(before.matches('\n').count() + 1)
+    }
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Number(value) => write!(f, "{}", value),
+            Json::String(value) => write_string(f, value),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            },
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            },
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+macro_rules! json_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Json {
+                fn from(value: $t) -> Json {
+                    Json::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+json_from_number!(i32, i64, u8, u16, u32, u64, usize);
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Json {
+        Json::Bool(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Json {
+        Json::String(String::from(value))
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Json {
+        Json::String(value)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(values: Vec<T>) -> Json {
+        Json::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Json {
+        value.map(Into::into).unwrap_or(Json::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let json = Json::object()
+            .with("day", 4)
+            .with("answer", "4512")
+            .with("winners", vec![Json::object().with("board", 2usize), Json::Null])
+            .with("solved", true)
+            .with("missing", None as Option<u32>);
+        assert_eq!(
+            r#"{"day":4,"answer":"4512","winners":[{"board":2},null],"solved":true,"missing":null}"#,
+            json.to_string()
+        );
+    }
+
+    #[test]
+    fn test_escapes() {
+        assert_eq!(r##""#..\n\"a\"\\\t\u0001""##, Json::from("#..\n\"a\"\\\t\u{1}").to_string());
+    }
+
+    #[test]
+    fn test_parse() {
+        let text = r#"{"day":4,"answer":"4512","winners":[{"board":2},null],"solved":true,"missing":null,"change":-3}"#;
+        let json = Json::parse(text).unwrap();
+        assert_eq!(text, json.to_string());
+        assert_eq!(Some(4), json.get("day").and_then(Json::as_number));
+        assert_eq!(Some("4512"), json.get("answer").and_then(Json::as_str));
+        assert_eq!(None, json.get("board"));
+
+        let spaced = Json::parse(" { \"input\" : [ 1 , 2 ] ,\n \"part\":2 } ").unwrap();
+        assert_eq!(r#"{"input":[1,2],"part":2}"#, spaced.to_string());
+        assert_eq!(Json::object(), Json::parse("{}").unwrap());
+    }
+
+    #[test]
+    fn test_parse_escapes() {
+        let escaped = Json::from("#..\n\"a\"\\\t\u{1}");
+        assert_eq!(escaped, Json::parse(&escaped.to_string()).unwrap());
+        assert_eq!(Json::from("é / 🦀"), Json::parse(r#""\u00e9 \/ \ud83e\udd80""#).unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "line 1, column 9: expected a value but found '}'",
+            Json::parse(r#"{"day": }"#).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2, column 9: expected an integer but found '1'",
+            Json::parse("{\n\"part\": 1.5}").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 11: expected '}' but found end of line",
+            Json::parse(r#"{"day": 22"#).unwrap_err().to_string()
+        );
+        assert!(Json::parse(r#""unterminated"#).is_err());
+        assert!(Json::parse(r#"{"a":1} {"#).is_err());
+        assert!(Json::parse(r#"{day:1}"#).is_err());
+        assert!(Json::parse(r#""\x""#).is_err());
+    }
+}
@@ -0,0 +1,27 @@
+mod checked;
+mod cursor;
+mod frame;
+mod grid;
+mod image;
+mod input;
+mod json;
+mod parse;
+mod point;
+mod pool;
+mod puzzle;
+mod random;
+mod timing;
+
+pub use checked::{Checked, OVERFLOW_CHECKED};
+pub use cursor::Cursor;
+pub use frame::{Frame, Cell, Colour};
+pub use grid::Grid;
+pub use image::{ImageFormat, Rgb, Raster, Svg, Picture};
+pub use input::InputSource;
+pub use json::Json;
+pub use parse::{ParseError, numbered_lines, parse_lines, parse_numbered_lines, parse_comma_separated, parse_whitespace_separated, parse_digit_row, parse_digit_grid};
+pub use point::{Point, Position};
+pub use pool::{Pool, set_jobs, jobs};
+pub use random::Rng;
+pub use puzzle::{Puzzle, LineSolver, Answer, Error, solve_part1, solve_part2, solve_lines, solve_lines_with};
+pub use timing::{Timings, PartTimings, time_part1, time_part2};
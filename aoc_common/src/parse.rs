@@ -0,0 +1,214 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use crate::grid::Grid;
+use crate::puzzle::Error;
+
+/// Where and why a piece of input could not be understood.
+///
+/// Lines and columns both start at 1. Parsers that only see a single line report line 1 and let the
+/// caller move the error onto the right line with `on_line`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    expected: String,
+    found: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            line: 1,
+            column,
+            expected: String::from(expected),
+            found: String::from(found),
+        }
+    }
+
+    pub fn on_line(self, line: usize) -> ParseError {
+        ParseError {
+            line,
+            ..self
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// The text that was there instead, empty if the line ended early.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {} but found ", self.line, self.column, self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "'{}'", self.found)
+        }
+    }
+}
+
+/// The non-blank lines of the input along with their 1-based line numbers.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+        .filter(|(_, line)| !line.trim().is_empty())
+}
+
+/// Parses every non-blank line, reporting the errors from all of the bad lines rather than stopping at the first.
+pub fn parse_lines<T, F: Fn(&str) -> Result<T, ParseError>>(input: &str, parse: F) -> Result<Vec<T>, Error> {
+    parse_numbered_lines(numbered_lines(input), parse)
+}
+
+/// Like `parse_lines` for callers that have already taken some lines off the front, such as a header.
+pub fn parse_numbered_lines<'a, T, I, F>(lines: I, parse: F) -> Result<Vec<T>, Error>
+where
+    I: Iterator<Item = (usize, &'a str)>,
+    F: Fn(&'a str) -> Result<T, ParseError>,
+{
+    let mut values = vec![];
+    let mut errors = vec![];
+    for (number, line) in lines {
+        match parse(line) {
+            Ok(value) => values.push(value),
+            Err(error) => errors.push(error.on_line(number)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(Error::Parse(errors))
+    }
+}
+
+/// Parses a list like `3,4,3,1,2`, ignoring whitespace around each value and any trailing comma.
+pub fn parse_comma_separated<T: FromStr>(input: &str) -> Result<Vec<T>, Error> {
+    parse_lines(input, |line| {
+        let mut offset = 0;
+        let mut values = vec![];
+        for value in line.split(',') {
+            let column = column_of(line, offset + value.len() - value.trim_start().len());
+            offset += value.len() + 1;
+            values.push((column, value.trim()));
+        }
+        parse_values(values.into_iter())
+    }).map(|lines| lines.into_iter().flatten().collect())
+}
+
+/// Parses a list like ` 2 13 14  8 74` where values are separated by any run of whitespace.
+pub fn parse_whitespace_separated<T: FromStr>(input: &str) -> Result<Vec<T>, Error> {
+    parse_lines(input, |line| {
+        let values = line
+            .split_whitespace()
+            // split_whitespace hands back slices of the line so the offset falls out of the pointers
+            .map(|v| (column_of(line, v.as_ptr() as usize - line.as_ptr() as usize), v));
+        parse_values(values)
+    }).map(|lines| lines.into_iter().flatten().collect())
+}
+
+/// Parses a row of single digits like `2199943210`.
+pub fn parse_digit_row(input: &str) -> Result<Vec<u32>, ParseError> {
+    let indent = input.len() - input.trim_start().len();
+    input
+        .trim()
+        .chars()
+        .enumerate()
+        .map(|(i, c)| c.to_digit(10).ok_or_else(|| ParseError::new(column_of(input, indent) + i, "a digit", &c.to_string())))
+        .collect()
+}
+
+/// Parses one row of digits per line into a grid, skipping blank lines.
+pub fn parse_digit_grid(input: &str) -> Result<Grid<u32>, Error> {
+    let rows = parse_lines(input, parse_digit_row)?;
+    let mut grid = Grid::new();
+    let mut errors = vec![];
+    for ((number, line), row) in numbered_lines(input).zip(rows) {
+        if !grid.is_empty() && row.len() != grid.width() {
+            let expected = format!("a row of {} digits", grid.width());
+            errors.push(ParseError::new(1, &expected, line.trim()).on_line(number));
+        } else {
+            grid.push_row(row);
+        }
+    }
+    if errors.is_empty() {
+        Ok(grid)
+    } else {
+        Err(Error::Parse(errors))
+    }
+}
+
+fn parse_values<'a, T: FromStr, I: Iterator<Item = (usize, &'a str)>>(values: I) -> Result<Vec<T>, ParseError> {
+    values
+        .filter(|(_, v)| !v.is_empty())
+        .map(|(column, v)| v.parse().map_err(|_| ParseError::new(column, "a number", v)))
+        .collect()
+}
+
+/// Converts a byte offset into a 1-based column.
+fn column_of(line: &str, offset: usize) -> usize {
+    line[..offset].chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comma_separated() {
+        assert_eq!(Ok(vec![3, 4, 3, 1, 2]), parse_comma_separated::<u32>("3,4,3,1,2\n"));
+        assert_eq!(Ok(vec![16, -1, 2]), parse_comma_separated::<i32>(" 16, -1,2,"));
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(4, "a number", "x")])), parse_comma_separated::<u32>("1, x"));
+    }
+
+    #[test]
+    fn test_whitespace_separated() {
+        assert_eq!(Ok(vec![2, 13, 14, 8, 74]), parse_whitespace_separated::<u32>(" 2 13 14  8 74"));
+        assert_eq!(Ok(vec![]), parse_whitespace_separated::<u32>("   "));
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(7, "a number", "x3")])), parse_whitespace_separated::<u32>(" 2 13 x3"));
+    }
+
+    #[test]
+    fn test_digit_row() {
+        assert_eq!(Ok(vec![2, 1, 9, 9, 0]), parse_digit_row("21990"));
+        assert_eq!(Err(ParseError::new(3, "a digit", "a")), parse_digit_row("21a90"));
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        let grid = parse_digit_grid("
+            123
+            456
+        ").unwrap();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(1, "a row of 3 digits", "45").on_line(2)])), parse_digit_grid("123\n45"));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line: &str| line.trim().parse::<u32>().map_err(|_| ParseError::new(1, "a number", line.trim()));
+        assert_eq!(Ok(vec![1, 2]), parse_lines("1\n\n2\n", parse));
+
+        // every bad line is reported, not just the first
+        let errors = vec![
+            ParseError::new(1, "a number", "x").on_line(2),
+            ParseError::new(1, "a number", "y").on_line(4),
+        ];
+        assert_eq!(Err(Error::Parse(errors)), parse_lines("1\nx\n3\ny", parse));
+    }
+}
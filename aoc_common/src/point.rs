@@ -0,0 +1,97 @@
+use std::cmp;
+
+/// A cell in a `Grid`, with `(0, 0)` in the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    // y comes first so the derived ordering is row by row
+    y: usize,
+    x: usize,
+}
+
+impl Position {
+    pub fn new(x: usize, y: usize) -> Position {
+        Position {
+            x,
+            y,
+        }
+    }
+
+    pub fn x(&self) -> usize {
+        self.x
+    }
+
+    pub fn y(&self) -> usize {
+        self.y
+    }
+
+    /// Moves the position by the given deltas, or `None` if that would go past the top or left edge.
+    pub fn offset(&self, delta_x: isize, delta_y: isize) -> Option<Position> {
+        let x = self.x.checked_add_signed(delta_x)?;
+        let y = self.y.checked_add_signed(delta_y)?;
+        Some(Position::new(x, y))
+    }
+
+    pub fn manhattan_distance(&self, other: &Position) -> usize {
+        (cmp::max(self.x, other.x) - cmp::min(self.x, other.x)) + (cmp::max(self.y, other.y) - cmp::min(self.y, other.y))
+    }
+}
+
+/// A location on an unbounded plane where coordinates can go negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Point {
+        Point {
+            x,
+            y,
+        }
+    }
+
+    pub fn origin() -> Point {
+        Point::new(0, 0)
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_offset() {
+        let position = Position::new(1, 0);
+        assert_eq!(Some(Position::new(0, 0)), position.offset(-1, 0));
+        assert_eq!(Some(Position::new(2, 1)), position.offset(1, 1));
+        assert_eq!(None, position.offset(0, -1));
+        assert_eq!(None, position.offset(-2, 0));
+    }
+
+    #[test]
+    fn test_position_order() {
+        // row-major so sorted positions read like the grid
+        assert!(Position::new(9, 0) < Position::new(0, 1));
+        assert!(Position::new(0, 1) < Position::new(1, 1));
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(7, Position::new(1, 2).manhattan_distance(&Position::new(4, 6)));
+        assert_eq!(7, Position::new(4, 6).manhattan_distance(&Position::new(1, 2)));
+        assert_eq!(12, Point::new(-3, 2).manhattan_distance(&Point::new(4, -3)));
+    }
+}
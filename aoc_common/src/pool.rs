@@ -0,0 +1,130 @@
+use std::cell::Cell;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// The number of threads `Pool::configured` splits work across, 0 until `set_jobs` is called.
+static JOBS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static IN_WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets how many threads solvers split their work across for the rest of the process, as `aoc run --jobs` does.
+pub fn set_jobs(jobs: usize) {
+    JOBS.store(jobs.max(1), Ordering::Relaxed);
+}
+
+/// The number of threads set by `set_jobs`, or one for every core when it hasn't been called.
+pub fn jobs() -> usize {
+    match JOBS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+        jobs => jobs,
+    }
+}
+
+/// Splits independent pieces of work across a fixed number of scoped threads.
+///
+/// The threads only live as long as a call to `map`, so the work can borrow from the caller. Work
+/// handed to a pool from inside another pool's thread (like a solver's inner loop while the runner
+/// is solving days at the same time) runs on that thread instead, which keeps the number of threads
+/// at the number of jobs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pool {
+    jobs: usize,
+}
+
+impl Pool {
+    pub fn new(jobs: usize) -> Pool {
+        Pool {
+            jobs: jobs.max(1),
+        }
+    }
+
+    /// A pool with the number of threads set by `set_jobs`.
+    pub fn configured() -> Pool {
+        Pool::new(jobs())
+    }
+
+    pub fn jobs(&self) -> usize {
+        self.jobs
+    }
+
+    /// Runs `work` on every item, giving back the results in the same order as the items.
+    ///
+    /// Threads take the next item as soon as they finish one, so a few slow items don't hold up the
+    /// rest. A panic in `work` is passed on to the caller once every thread has stopped.
+    pub fn map<T, R, F>(&self, items: &[T], work: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        let workers = self.jobs.min(items.len());
+        if workers <= 1 || IN_WORKER.with(Cell::get) {
+            return items.iter().map(work).collect();
+        }
+
+        let next = AtomicUsize::new(0);
+        let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| scope.spawn(|| {
+                    IN_WORKER.with(|in_worker| in_worker.set(true));
+                    let mut done = vec![];
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        if index >= items.len() {
+                            return done;
+                        }
+                        done.push((index, work(&items[index])));
+                    }
+                }))
+                .collect();
+
+            handles.into_iter()
+                .flat_map(|handle| handle.join().unwrap_or_else(|payload| panic::resume_unwind(payload)))
+                .collect()
+        });
+
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let numbers: Vec<u64> = (0..1000).collect();
+        let squares: Vec<u64> = numbers.iter().map(|n| n * n).collect();
+
+        assert_eq!(squares, Pool::new(4).map(&numbers, |n| n * n));
+        assert_eq!(squares, Pool::new(1).map(&numbers, |n| n * n));
+        assert_eq!(Vec::<u64>::new(), Pool::new(4).map(&[], |n: &u64| n * n));
+    }
+
+    #[test]
+    fn test_nested() {
+        let outer = Pool::new(3);
+        let threads = outer.map(&[0, 1, 2], |_| {
+            // work handed out from a pool thread stays on that thread
+            let inner: Vec<thread::ThreadId> = Pool::new(3).map(&[0, 1, 2], |_| thread::current().id());
+            inner.iter().all(|&id| id == thread::current().id())
+        });
+        assert_eq!(vec![true, true, true], threads);
+    }
+
+    #[test]
+    #[should_panic(expected = "item 7 is unlucky")]
+    fn test_panic() {
+        let numbers: Vec<u32> = (0..20).collect();
+        Pool::new(4).map(&numbers, |&n| {
+            if n == 7 {
+                panic!("item {} is unlucky", n);
+            }
+            n
+        });
+    }
+}
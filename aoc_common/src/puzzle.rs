@@ -0,0 +1,268 @@
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use crate::json::Json;
+use crate::parse::ParseError;
+
+/// A single day of the advent calendar.
+///
+/// The input is parsed once and then shared by both parts. Each `day_XX_puzzle_YY` crate
+/// implements the parts it knows how to solve and leaves the other as `Error::Unsolved`.
+pub trait Puzzle {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(_input: &Self::Input) -> Result<Answer, Error> {
+        Err(Error::Unsolved(1))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer, Error> {
+        Err(Error::Unsolved(2))
+    }
+}
+
+pub fn solve_part1<P: Puzzle>(input: &str) -> Result<Answer, Error> {
+    P::part1(&P::parse(input)?)
+}
+
+pub fn solve_part2<P: Puzzle>(input: &str) -> Result<Answer, Error> {
+    P::part2(&P::parse(input)?)
+}
+
+/// A part that can be solved by feeding it one line at a time, so the whole input never has to be held in memory.
+///
+/// A line that fails to parse should return `Error::Parse`, so the rest of the input can still be checked.
+/// Any other error stops the input being read.
+pub trait LineSolver: Default {
+    fn process_line(&mut self, line: &str) -> Result<(), Error>;
+
+    fn finish(self) -> Result<Answer, Error>;
+}
+
+/// Streams every non-blank line through the solver. Bad lines are collected and reported together
+/// once the input has been read, like `parse_lines` does.
+pub fn solve_lines<S: LineSolver>(reader: &mut dyn BufRead) -> Result<Answer, Error> {
+    solve_lines_with(S::default(), reader)
+}
+
+/// Like `solve_lines` for a solver that needs setting up, such as one with a different window size than its default.
+pub fn solve_lines_with<S: LineSolver>(mut solver: S, reader: &mut dyn BufRead) -> Result<Answer, Error> {
+    let mut errors = vec![];
+    let mut line = String::new();
+    let mut number = 0;
+    loop {
+        // reusing the buffer keeps memory flat no matter how long the input is
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => number += 1,
+            Err(e) => return Err(Error::Input(e.to_string())),
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        match solver.process_line(line.trim_end_matches(['\n', '\r'])) {
+            Ok(()) => {},
+            Err(Error::Parse(line_errors)) => errors.extend(line_errors.into_iter().map(|e| e.on_line(number))),
+            Err(error) => return Err(error),
+        }
+    }
+
+    if errors.is_empty() {
+        solver.finish()
+    } else {
+        Err(Error::Parse(errors))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    value: String,
+    details: Vec<(String, Json)>,
+}
+
+impl Answer {
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Records a named value that was worked out on the way to the answer, like the position and
+    /// depth that are multiplied together on day 2.
+    pub fn with_detail(mut self, name: &str, value: impl Into<Json>) -> Answer {
+        self.details.push((String::from(name), value.into()));
+        self
+    }
+
+    pub fn details(&self) -> &[(String, Json)] {
+        &self.details
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Answer {
+                    Answer {
+                        value: value.to_string(),
+                        details: vec![],
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(i32, i64, u8, u16, u32, u64, usize, String, &str);
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The puzzle crate does not implement this part.
+    Unsolved(u32),
+    /// The input could not be read.
+    Input(String),
+    /// The input could not be understood, with one entry for every bad line.
+    Parse(Vec<ParseError>),
+    /// The input was understood but has no answer.
+    NoSolution(String),
+    /// A value worked out along the way was too big for its type, naming the operation that overflowed.
+    Overflow(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unsolved(part) => write!(f, "Part {} is not solved by this puzzle", part),
+            Error::Input(message) => write!(f, "Failed to read input - {}", message),
+            Error::Parse(errors) => {
+                write!(f, "Failed to parse input")?;
+                for error in errors.iter() {
+                    write!(f, "\n  {}", error)?;
+                }
+                Ok(())
+            },
+            Error::NoSolution(message) => write!(f, "No solution - {}", message),
+            Error::Overflow(operation) => write!(f, "Overflow while working out {}", operation),
+        }
+    }
+}
+
+impl Error {
+    /// Moves every parse error onto the line, for errors from parsers that only see a single line.
+    pub fn on_line(self, line: usize) -> Error {
+        match self {
+            Error::Parse(errors) => Error::Parse(errors.into_iter().map(|e| e.on_line(line)).collect()),
+            error => error,
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Error {
+        Error::Parse(vec![error])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Doubler;
+
+    impl Puzzle for Doubler {
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>, Error> {
+            input
+                .split(',')
+                .map(|v| v.trim().parse().map_err(|_| ParseError::new(1, "a number", v.trim()).into()))
+                .collect()
+        }
+
+        fn part1(input: &Vec<u32>) -> Result<Answer, Error> {
+            Ok(input.iter().map(|v| v * 2).sum::<u32>().into())
+        }
+    }
+
+    #[derive(Default)]
+    struct Summer {
+        total: u32,
+    }
+
+    impl LineSolver for Summer {
+        fn process_line(&mut self, line: &str) -> Result<(), Error> {
+            let value = line.trim().parse::<u32>().map_err(|_| ParseError::new(1, "a number", line.trim()))?;
+            self.total = self.total.checked_add(value).ok_or_else(|| Error::Overflow(String::from("total + value")))?;
+            Ok(())
+        }
+
+        fn finish(self) -> Result<Answer, Error> {
+            Ok(self.total.into())
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Ok(Answer::from("12")), solve_part1::<Doubler>("1, 2, 3"));
+    }
+
+    #[test]
+    fn test_details() {
+        let answer = Answer::from(12).with_detail("position", 3).with_detail("depth", 4);
+        assert_eq!("12", answer.value());
+        assert_eq!(&[(String::from("position"), Json::from(3)), (String::from("depth"), Json::from(4))], answer.details());
+    }
+
+    #[test]
+    fn test_unsolved() {
+        assert_eq!(Err(Error::Unsolved(2)), solve_part2::<Doubler>("1, 2, 3"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(1, "a number", "x")])), solve_part1::<Doubler>("1,x"));
+    }
+
+    #[test]
+    fn test_solve_lines() {
+        assert_eq!(Ok(Answer::from(6)), solve_lines::<Summer>(&mut "1\n2\r\n\n3".as_bytes()));
+
+        let errors = vec![
+            ParseError::new(1, "a number", "x").on_line(2),
+            ParseError::new(1, "a number", "y").on_line(4),
+        ];
+        assert_eq!(Err(Error::Parse(errors)), solve_lines::<Summer>(&mut "1\nx\n\ny".as_bytes()));
+
+        // anything other than a bad line stops the input being read there
+        assert_eq!(Err(Error::Overflow(String::from("total + value"))), solve_lines::<Summer>(&mut "4294967295\n1\nx".as_bytes()));
+
+
+        // a solver that has been set up carries on from where it was
+        assert_eq!(Ok(Answer::from(16)), solve_lines_with(Summer { total: 10 }, &mut "1\n2\n3".as_bytes()));
+    }
+
+    #[test]
+    fn test_error_on_line() {
+        let errors = Error::Parse(vec![ParseError::new(3, "a number", "x"), ParseError::new(5, "a number", "y")]);
+        let moved = vec![ParseError::new(3, "a number", "x").on_line(4), ParseError::new(5, "a number", "y").on_line(4)];
+        assert_eq!(Error::Parse(moved), errors.on_line(4));
+        assert_eq!(Error::Unsolved(1), Error::Unsolved(1).on_line(4));
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let error = Error::Parse(vec![
+            ParseError::new(3, "a number", "x").on_line(2),
+            ParseError::new(5, "'->'", "").on_line(7),
+        ]);
+        assert_eq!("Failed to parse input\n  line 2, column 3: expected a number but found 'x'\n  line 7, column 5: expected '->' but found end of line", error.to_string());
+    }
+}
@@ -0,0 +1,105 @@
+/// A small seedable pseudo-random number generator (SplitMix64) for generating puzzle inputs.
+///
+/// The same seed always gives the same sequence on every platform, so a generated input can be
+/// reproduced from its seed alone. It is not suitable for anything that needs to be unpredictable.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng {
+            state: seed,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..bound`, without the bias a plain modulo would give.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            panic!("Expected a bound above 0 to pick a value below");
+        }
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+
+    /// A value in `low..=high`.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        if low > high {
+            panic!("Expected {} to be no more than {}", low, high);
+        }
+        let span = high.abs_diff(low);
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        low.wrapping_add(self.below(span + 1) as i64)
+    }
+
+    /// True `numerator` times out of every `denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, values: &'a [T]) -> &'a T {
+        &values[self.below(values.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            values.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reproducible() {
+        let first: Vec<u64> = (0..5).scan(Rng::new(42), |rng, _| Some(rng.next_u64())).collect();
+        let second: Vec<u64> = (0..5).scan(Rng::new(42), |rng, _| Some(rng.next_u64())).collect();
+        assert_eq!(first, second);
+
+        let other: Vec<u64> = (0..5).scan(Rng::new(43), |rng, _| Some(rng.next_u64())).collect();
+        assert_ne!(first, other);
+
+        // the sequence is part of the generated inputs, so it must never change between versions
+        assert_eq!(0xe220a8397b1dcdaf, Rng::new(0).next_u64());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!(rng.below(10) < 10);
+            let value = rng.between(-3, 3);
+            assert!((-3..=3).contains(&value));
+        }
+        assert_eq!(5, rng.between(5, 5));
+        rng.between(i64::MIN, i64::MAX);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut values: Vec<u32> = (0..20).collect();
+        Rng::new(1).shuffle(&mut values);
+        assert_ne!((0..20).collect::<Vec<u32>>(), values);
+        values.sort();
+        assert_eq!((0..20).collect::<Vec<u32>>(), values);
+    }
+}
@@ -0,0 +1,141 @@
+use std::hint;
+use std::time::{Duration, Instant};
+use crate::puzzle::{Puzzle, Answer, Error};
+
+/// The spread of the times taken over a number of runs of the same work.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn from(mut samples: Vec<Duration>) -> Timings {
+        if samples.is_empty() {
+            panic!("Expected at least one sample to be timed");
+        }
+        samples.sort();
+        Timings {
+            samples,
+        }
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    pub fn median(&self) -> Duration {
+        let middle = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[middle - 1] + self.samples[middle]) / 2
+        } else {
+            self.samples[middle]
+        }
+    }
+}
+
+/// How long a part took, with parsing the input timed apart from solving it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartTimings {
+    parse: Timings,
+    solve: Timings,
+    answer: Answer,
+}
+
+impl PartTimings {
+    pub fn parse(&self) -> &Timings {
+        &self.parse
+    }
+
+    pub fn solve(&self) -> &Timings {
+        &self.solve
+    }
+
+    pub fn answer(&self) -> &Answer {
+        &self.answer
+    }
+}
+
+pub fn time_part1<P: Puzzle>(input: &str, iterations: usize) -> Result<PartTimings, Error> {
+    time::<P>(input, iterations, P::part1)
+}
+
+pub fn time_part2<P: Puzzle>(input: &str, iterations: usize) -> Result<PartTimings, Error> {
+    time::<P>(input, iterations, P::part2)
+}
+
+fn time<P: Puzzle>(input: &str, iterations: usize, part: fn(&P::Input) -> Result<Answer, Error>) -> Result<PartTimings, Error> {
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut solve_samples = Vec::with_capacity(iterations);
+    let mut answer = None;
+
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        // black_box stops the optimizer from skipping work whose result is never looked at
+        let parsed = hint::black_box(P::parse(hint::black_box(input))?);
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        let solved = hint::black_box(part(&parsed)?);
+        solve_samples.push(start.elapsed());
+
+        answer = Some(solved);
+    }
+
+    Ok(PartTimings {
+        parse: Timings::from(parse_samples),
+        solve: Timings::from(solve_samples),
+        answer: answer.unwrap(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Timings {
+        Timings::from(values.iter().map(|&v| Duration::from_millis(v)).collect())
+    }
+
+    #[test]
+    fn test_timings() {
+        let timings = millis(&[7, 3, 5, 1, 9]);
+        assert_eq!(5, timings.iterations());
+        assert_eq!(Duration::from_millis(1), timings.min());
+        assert_eq!(Duration::from_millis(5), timings.median());
+        assert_eq!(Duration::from_millis(9), timings.max());
+
+        assert_eq!(Duration::from_millis(4), millis(&[6, 2, 8, 2]).median());
+    }
+
+    struct Counter;
+
+    impl Puzzle for Counter {
+        type Input = usize;
+
+        fn parse(input: &str) -> Result<usize, Error> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(input: &usize) -> Result<Answer, Error> {
+            Ok((*input).into())
+        }
+    }
+
+    #[test]
+    fn test_time_part() {
+        let timings = time_part1::<Counter>("a\nb\nc", 4).unwrap();
+        assert_eq!(&Answer::from(3), timings.answer());
+        assert_eq!(4, timings.parse().iterations());
+        assert_eq!(4, timings.solve().iterations());
+
+        assert_eq!(Err(Error::Unsolved(2)), time_part2::<Counter>("a", 4));
+    }
+}
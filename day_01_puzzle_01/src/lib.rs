@@ -0,0 +1,45 @@
+use std::io::BufRead;
+use aoc_common::{Puzzle, Answer, Error};
+use day_01_puzzle_02::sonar_scan::{self, SonarScan};
+
+pub struct Day01;
+
+/// The first puzzle compares the depths themselves, which is a scan with a window of one depth.
+pub fn depth_scan() -> SonarScan {
+    SonarScan::with_window(1)
+}
+
+/// Streams the depths through the scan so the report can be any size.
+pub fn solve_stream(reader: &mut dyn BufRead) -> Result<Answer, Error> {
+    aoc_common::solve_lines_with(depth_scan(), reader)
+}
+
+impl Puzzle for Day01 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, Error> {
+        aoc_common::parse_lines(input, sonar_scan::parse_depth)
+    }
+
+    fn part1(depths: &Vec<i32>) -> Result<Answer, Error> {
+        let mut scan = depth_scan();
+        for depth in depths.iter() {
+            scan.process_depth(*depth);
+        }
+
+        Ok(scan.answer())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn test_example() {
+        assert_eq!("7", aoc_common::solve_part1::<Day01>(EXAMPLE).unwrap().value());
+        assert_eq!(aoc_common::solve_part1::<Day01>(EXAMPLE), solve_stream(&mut EXAMPLE.as_bytes()));
+    }
+}
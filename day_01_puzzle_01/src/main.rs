@@ -1,19 +1,15 @@
-mod sonar_scan;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use sonar_scan::SonarScan;
+use aoc_common::InputSource;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_01_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
-
-    let mut scan = SonarScan::new();
-    for line in lines {
-        let depth = line.expect("Error reading line").trim().parse().expect("Line should be an integer");
-        scan.process_depth(depth);
+    let mut input = InputSource::from_args("./day_01_puzzle_01/input.txt").open()?;
+
+    // the depths are streamed through the scan so the report can be any size
+    match day_01_puzzle_01::solve_stream(&mut input) {
+        Ok(count) => println!("{}", count),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
-
-    println!("{}", scan.depth_increase_count());
     Ok(())
 }
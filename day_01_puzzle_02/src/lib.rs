@@ -0,0 +1,23 @@
+pub mod sonar_scan;
+
+use aoc_common::{Puzzle, Answer, Error};
+use sonar_scan::SonarScan;
+
+pub struct Day01;
+
+impl Puzzle for Day01 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, Error> {
+        aoc_common::parse_lines(input, sonar_scan::parse_depth)
+    }
+
+    fn part2(depths: &Vec<i32>) -> Result<Answer, Error> {
+        let mut scan = SonarScan::new();
+        for depth in depths.iter() {
+            scan.process_depth(*depth);
+        }
+
+        Ok(scan.answer())
+    }
+}
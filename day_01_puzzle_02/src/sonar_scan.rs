@@ -1,64 +1,290 @@
-static SLIDING_WINDOW_SIZE: i32 = 3;
+use std::collections::VecDeque;
+use aoc_common::{LineSolver, Answer, Error, ParseError, Cursor, Json};
 
-struct SlidingWindowState {
-    pub depth_sum: i32,
-    pub depth_count: i32,
+/// The number of depths summed together when no window size is given, as the puzzle asks for.
+pub const DEFAULT_WINDOW_SIZE: usize = 3;
+
+/// A depth that was too far from the mean of the depths before it to be trusted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Anomaly {
+    index: usize,
+    depth: i32,
 }
 
-impl SlidingWindowState {
-    fn new() -> SlidingWindowState {
-        SlidingWindowState {
-            depth_sum: 0,
-            depth_count: 0,
+impl Anomaly {
+    pub fn new(index: usize, depth: i32) -> Anomaly {
+        Anomaly {
+            index,
+            depth,
         }
     }
 
-    fn add(&mut self, depth: i32) -> bool {
-        if self.depth_count < SLIDING_WINDOW_SIZE {
-            self.depth_sum += depth;
-            self.depth_count += 1;
+    /// Where the depth came in the sweep, counting from 0.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn depth(&self) -> i32 {
+        self.depth
+    }
+}
+
+/// Looks out for spikes and dropouts by comparing each depth with the rolling mean of the depths before it.
+///
+/// The mean is taken over the last window's worth of depths that were not anomalies themselves, so a
+/// spike doesn't drag the mean along with it and get the depths after it flagged as well. That means
+/// the first depth is always trusted.
+///
+/// A real step change in depth would otherwise be flagged forever, so once more depths in a row than
+/// the window holds have been flagged, the latest of them are trusted as the new mean instead.
+struct AnomalyDetector {
+    threshold: i64,
+    exclude: bool,
+    trusted: VecDeque<i32>,
+    trusted_sum: i64,
+    flagged_run: VecDeque<i32>,
+    anomalies: Vec<Anomaly>,
+}
+
+impl AnomalyDetector {
+    fn new(threshold: u32, exclude: bool) -> AnomalyDetector {
+        AnomalyDetector {
+            threshold: threshold as i64,
+            exclude,
+            trusted: VecDeque::new(),
+            trusted_sum: 0,
+            flagged_run: VecDeque::new(),
+            anomalies: vec![],
+        }
+    }
+
+    /// Checks the depth against the mean, returning whether it is an anomaly.
+    fn check(&mut self, index: usize, depth: i32, window_size: usize) -> bool {
+        let count = self.trusted.len() as i64;
+        // comparing against the sum rather than the mean keeps everything in whole numbers
+        if count > 0 && (depth as i64 * count - self.trusted_sum).abs() > self.threshold * count {
+            self.flagged_run.push_back(depth);
+            if self.flagged_run.len() <= window_size {
+                self.anomalies.push(Anomaly::new(index, depth));
+                return true;
+            }
+
+            // the depth has moved rather than spiked, so the mean starts again from where it is now
+            self.flagged_run.pop_front();
+            self.trusted = std::mem::take(&mut self.flagged_run);
+            self.trusted_sum = self.trusted.iter().map(|&d| d as i64).sum();
+            return false;
+        }
+
+        self.flagged_run.clear();
+        if self.trusted.len() == window_size {
+            self.trusted_sum -= self.trusted.pop_front().unwrap() as i64;
         }
-        self.depth_count == SLIDING_WINDOW_SIZE
+        self.trusted.push_back(depth);
+        self.trusted_sum += depth as i64;
+        false
     }
 }
 
+/// Compares the sums of each window of consecutive depths with the window before it, one depth at a time.
+///
+/// Only the depths in the current window are kept (in a ring buffer along with their running sum),
+/// so a report of any length can be streamed through it. A window of 1 compares the depths themselves.
+///
+/// With an anomaly threshold it also flags the depths that are further than that from the mean of
+/// the window before them, and can leave them out of the windows altogether.
 pub struct SonarScan {
-    sliding_windows: Vec<SlidingWindowState>,
-    depth_increase_count: i32,
+    window_size: usize,
+    depth_count: usize,
+    anomaly_detector: Option<AnomalyDetector>,
+    window: VecDeque<i32>,
+    window_sum: i64,
+    depth_increase_count: usize,
+    depth_decrease_count: usize,
+    unchanged_count: usize,
+    descent_run: usize,
+    longest_descent: usize,
+    min_window_sum: Option<i64>,
+    max_window_sum: Option<i64>,
 }
 
 impl SonarScan {
     pub fn new() -> SonarScan {
+        SonarScan::with_window(DEFAULT_WINDOW_SIZE)
+    }
+
+    pub fn with_window(window_size: usize) -> SonarScan {
+        if window_size == 0 {
+            panic!("Expected a window of at least 1 depth");
+        }
         SonarScan {
-            sliding_windows: vec!(),
+            window_size,
+            depth_count: 0,
+            anomaly_detector: None,
+            window: VecDeque::with_capacity(window_size),
+            window_sum: 0,
             depth_increase_count: 0,
+            depth_decrease_count: 0,
+            unchanged_count: 0,
+            descent_run: 0,
+            longest_descent: 0,
+            min_window_sum: None,
+            max_window_sum: None,
+        }
+    }
+
+    /// Flags every depth that differs from the rolling mean of the depths before it by more than `threshold`.
+    pub fn with_anomaly_threshold(self, threshold: u32) -> SonarScan {
+        SonarScan {
+            anomaly_detector: Some(AnomalyDetector::new(threshold, false)),
+            ..self
+        }
+    }
+
+    /// Flags depths like `with_anomaly_threshold` and also leaves them out of the windows, so they
+    /// count towards none of the statistics.
+    pub fn excluding_anomalies(self, threshold: u32) -> SonarScan {
+        SonarScan {
+            anomaly_detector: Some(AnomalyDetector::new(threshold, true)),
+            ..self
         }
     }
 
-    pub fn depth_increase_count(&self) -> i32 {
+    /// The depths flagged as anomalies so far, empty without an anomaly threshold.
+    pub fn anomalies(&self) -> &[Anomaly] {
+        match &self.anomaly_detector {
+            Some(detector) => &detector.anomalies,
+            None => &[],
+        }
+    }
+
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    pub fn depth_increase_count(&self) -> usize {
         self.depth_increase_count
     }
 
+    pub fn depth_decrease_count(&self) -> usize {
+        self.depth_decrease_count
+    }
+
+    /// The number of windows with the same sum as the window before.
+    pub fn unchanged_count(&self) -> usize {
+        self.unchanged_count
+    }
+
+    /// The most times in a row that a window was deeper than the one before.
+    pub fn longest_descent(&self) -> usize {
+        self.longest_descent
+    }
+
+    /// The smallest sum of a full window, or `None` before the first window has filled.
+    pub fn min_window_sum(&self) -> Option<i64> {
+        self.min_window_sum
+    }
+
+    pub fn max_window_sum(&self) -> Option<i64> {
+        self.max_window_sum
+    }
+
     pub fn process_depth(&mut self, next_depth: i32) {
-        let mut filled_windows = 0;
-        for window in self.sliding_windows.iter_mut() {
-            if window.add(next_depth) {
-                filled_windows += 1;
+        let index = self.depth_count;
+        self.depth_count += 1;
+        if let Some(detector) = &mut self.anomaly_detector {
+            if detector.check(index, next_depth, self.window_size) && detector.exclude {
+                return;
             }
         }
-        let mut new_window = SlidingWindowState::new();
-        new_window.add(next_depth);
-        self.sliding_windows.push(new_window);
 
-        if filled_windows > 1 {
-            let first = &self.sliding_windows[0];
-            let second = &self.sliding_windows[1];
-            if second.depth_sum > first.depth_sum {
-                self.depth_increase_count += 1;
+        let previous_sum = self.full_window_sum();
+
+        if self.window.len() == self.window_size {
+            // the oldest depth leaves the window as the new one joins it
+            self.window_sum -= self.window.pop_front().unwrap() as i64;
+        }
+        self.window.push_back(next_depth);
+        self.window_sum += next_depth as i64;
+
+        if let Some(sum) = self.full_window_sum() {
+            self.min_window_sum = Some(self.min_window_sum.map_or(sum, |min| min.min(sum)));
+            self.max_window_sum = Some(self.max_window_sum.map_or(sum, |max| max.max(sum)));
+
+            if let Some(previous_sum) = previous_sum {
+                self.compare(previous_sum, sum);
+            }
+        }
+    }
+
+    fn full_window_sum(&self) -> Option<i64> {
+        if self.window.len() == self.window_size {
+            Some(self.window_sum)
+        } else {
+            None
+        }
+    }
+
+    fn compare(&mut self, previous_sum: i64, sum: i64) {
+        if sum > previous_sum {
+            self.depth_increase_count += 1;
+            self.descent_run += 1;
+            self.longest_descent = self.longest_descent.max(self.descent_run);
+        } else {
+            if sum < previous_sum {
+                self.depth_decrease_count += 1;
+            } else {
+                self.unchanged_count += 1;
             }
-            self.sliding_windows.remove(0);
+            self.descent_run = 0;
         }
     }
+
+    /// The number of increases, along with the rest of the statistics (and any anomalies) as details.
+    pub fn answer(&self) -> Answer {
+        let answer = Answer::from(self.depth_increase_count)
+            .with_detail("window_size", self.window_size)
+            .with_detail("decreases", self.depth_decrease_count)
+            .with_detail("unchanged", self.unchanged_count)
+            .with_detail("longest_descent", self.longest_descent)
+            .with_detail("min_window_sum", self.min_window_sum)
+            .with_detail("max_window_sum", self.max_window_sum);
+
+        match &self.anomaly_detector {
+            Some(detector) => {
+                let anomalies: Vec<Json> = detector.anomalies.iter()
+                    .map(|a| Json::object().with("index", a.index).with("depth", a.depth))
+                    .collect();
+                answer.with_detail("anomalies", anomalies)
+            },
+            None => answer,
+        }
+    }
+}
+
+impl Default for SonarScan {
+    fn default() -> SonarScan {
+        SonarScan::new()
+    }
+}
+
+impl LineSolver for SonarScan {
+    fn process_line(&mut self, line: &str) -> Result<(), Error> {
+        self.process_depth(parse_depth(line)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Answer, Error> {
+        Ok(self.answer())
+    }
+}
+
+/// Parses a single depth reading from the sonar report.
+pub fn parse_depth(line: &str) -> Result<i32, ParseError> {
+    let mut cursor = Cursor::new(line);
+    let depth = cursor.number()?;
+    cursor.end()?;
+    Ok(depth)
 }
 
 #[cfg(test)]
@@ -89,4 +315,117 @@ mod tests {
         scan.process_depth(140); //       D E
         assert_eq!(2, scan.depth_increase_count());
     }
-}
\ No newline at end of file
+
+    const EXAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    fn scan_example(window_size: usize) -> SonarScan {
+        let mut scan = SonarScan::with_window(window_size);
+        for depth in EXAMPLE {
+            scan.process_depth(depth);
+        }
+        scan
+    }
+
+    #[test]
+    fn test_single_depth_window() {
+        // a window of one depth compares the depths themselves, like the first puzzle
+        let scan = scan_example(1);
+        assert_eq!(7, scan.depth_increase_count());
+        assert_eq!(2, scan.depth_decrease_count());
+        assert_eq!(0, scan.unchanged_count());
+        assert_eq!(3, scan.longest_descent());
+        assert_eq!(Some(199), scan.min_window_sum());
+        assert_eq!(Some(269), scan.max_window_sum());
+    }
+
+    #[test]
+    fn test_stats() {
+        // window sums: 607 618 618 617 647 716 769 792
+        let scan = scan_example(3);
+        assert_eq!(5, scan.depth_increase_count());
+        assert_eq!(1, scan.depth_decrease_count());
+        assert_eq!(1, scan.unchanged_count());
+        assert_eq!(4, scan.longest_descent());
+        assert_eq!(Some(607), scan.min_window_sum());
+        assert_eq!(Some(792), scan.max_window_sum());
+
+        let scan = scan_example(10);
+        assert_eq!(0, scan.depth_increase_count());
+        assert_eq!(Some(2256), scan.min_window_sum());
+        assert_eq!(None, scan_example(11).max_window_sum());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a window of at least 1 depth")]
+    fn test_empty_window() {
+        SonarScan::with_window(0);
+    }
+
+    // the example with a spike at index 4 and a dropout at index 9
+    const SPIKED: [i32; 12] = [199, 200, 208, 210, 2000, 200, 207, 240, 269, 0, 260, 263];
+
+    fn scan_spiked(scan: SonarScan) -> SonarScan {
+        let mut scan = scan;
+        for depth in SPIKED {
+            scan.process_depth(depth);
+        }
+        scan
+    }
+
+    #[test]
+    fn test_anomalies() {
+        let scan = scan_spiked(SonarScan::new().with_anomaly_threshold(100));
+        assert_eq!(&[Anomaly::new(4, 2000), Anomaly::new(9, 0)], scan.anomalies());
+        // the spike is still summed into its windows
+        assert_eq!(4, scan.depth_increase_count());
+        assert_eq!(Some(2418), scan.max_window_sum());
+
+        // nothing in the clean example is that far from the mean
+        let mut clean = SonarScan::new().with_anomaly_threshold(100);
+        for depth in EXAMPLE {
+            clean.process_depth(depth);
+        }
+        assert!(clean.anomalies().is_empty());
+        assert!(SonarScan::new().anomalies().is_empty());
+    }
+
+    #[test]
+    fn test_excluding_anomalies() {
+        // with the spike and dropout left out, the windows are the same as the clean example's
+        let scan = scan_spiked(SonarScan::new().excluding_anomalies(100));
+        assert_eq!(2, scan.anomalies().len());
+        assert_eq!(5, scan.depth_increase_count());
+        assert_eq!(1, scan.depth_decrease_count());
+        assert_eq!(Some(607), scan.min_window_sum());
+        assert_eq!(Some(792), scan.max_window_sum());
+
+        let scan = scan_spiked(SonarScan::with_window(1).excluding_anomalies(100));
+        assert_eq!(&[Anomaly::new(4, 2000), Anomaly::new(9, 0)], scan.anomalies());
+        assert_eq!(7, scan.depth_increase_count());
+    }
+
+    #[test]
+    fn test_zero_threshold() {
+        // every depth that isn't exactly the mean of the ones before it is an anomaly
+        // - but with a window of one, a second anomaly in a row is taken as the depth having moved
+        let scan = scan_spiked(SonarScan::with_window(1).excluding_anomalies(0));
+        let flagged: Vec<usize> = scan.anomalies().iter().map(Anomaly::index).collect();
+        assert_eq!(vec![1, 3, 5, 7, 9, 11], flagged);
+        // the windows are 199, 208, 2000, 207, 269 and 260
+        assert_eq!(3, scan.depth_increase_count());
+        assert_eq!(Some(2000), scan.max_window_sum());
+    }
+
+    #[test]
+    fn test_step_change() {
+        // a window's worth of depths in a row far from the mean are flagged, after which the new depth is trusted
+        let mut scan = SonarScan::new().excluding_anomalies(50);
+        for depth in [100, 100, 100, 500, 500, 500, 500, 500, 510] {
+            scan.process_depth(depth);
+        }
+        assert_eq!(&[Anomaly::new(3, 500), Anomaly::new(4, 500), Anomaly::new(5, 500)], scan.anomalies());
+        // the windows are 300, 700, 1100 and 1510 as the trusted depths move into them
+        assert_eq!(3, scan.depth_increase_count());
+        assert_eq!(Some(1510), scan.max_window_sum());
+    }
+}
@@ -0,0 +1,24 @@
+pub mod script;
+pub mod submarine;
+
+use aoc_common::{Puzzle, Answer, Error};
+use submarine::{Command, Submarine};
+
+pub struct Day02;
+
+impl Puzzle for Day02 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Vec<Command>, Error> {
+        aoc_common::parse_lines(input, Command::parse)
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<Answer, Error> {
+        let mut sub = Submarine::new();
+        for command in commands.iter() {
+            sub.execute(command)?;
+        }
+
+        sub.answer()
+    }
+}
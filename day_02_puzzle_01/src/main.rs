@@ -1,18 +1,16 @@
-mod submarine;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use submarine::Submarine;
+use aoc_common::InputSource;
+use day_02_puzzle_01::Day02;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_02_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
+    let input = InputSource::from_args("./day_02_puzzle_01/input.txt").read_to_string()?;
 
-    let mut sub = Submarine::new();
-    for line in lines {
-        sub.execute_command(&line.expect("Expected a line"));
+    match aoc_common::solve_part1::<Day02>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
-    println!("Submarines ends up at horizontal position {} and depth {}", sub.horizontal_position(), sub.depth());
-    println!("Multiplied together they are {}", sub.horizontal_position() * sub.depth());
-    Ok(())}
+    Ok(())
+}
\ No newline at end of file
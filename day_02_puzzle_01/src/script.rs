@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+use aoc_common::{Error, ParseError, Cursor};
+use crate::submarine::Command;
+
+const KEYWORDS: [&str; 6] = ["forward", "down", "up", "back", "repeat", "let"];
+const EXPECTED_STATEMENT: &str = "'forward', 'down', 'up', 'back', 'repeat' or 'let'";
+
+/// A scripted course, compiled down to the commands the submarine already follows.
+///
+/// A script is the plain course format plus a few extras, with any number of statements on a line:
+///
+/// ```text
+/// # a comment runs to the end of the line
+/// let leg = 7
+/// repeat 5 { forward leg down 1 }
+/// back 3
+/// ```
+///
+/// `back N` is `forward -N`. Names are made of letters, hold a number from their `let` onwards and
+/// can be used anywhere a number can. Repeats are kept as they are written and only expanded as the
+/// commands are read, so a large count doesn't take any more memory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Script {
+	statements: Vec<Statement>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Statement {
+	Command(Command),
+	Repeat(u32, Vec<Statement>),
+}
+
+impl Script {
+	pub fn parse(input: &str) -> Result<Script, Error> {
+		let (tokens, end) = tokenize(input)?;
+		let mut parser = Parser {
+			tokens,
+			next: 0,
+			end,
+			variables: HashMap::new(),
+		};
+		let statements = parser.statements(false)?;
+		Ok(Script { statements })
+	}
+
+	/// Every command in the script in the order the submarine follows them, with the repeats expanded.
+	pub fn commands(&self) -> Commands<'_> {
+		Commands {
+			stack: vec![(&self.statements, 0, 0)],
+		}
+	}
+}
+
+/// Walks through a script's commands, going round each repeat as many times as it says.
+pub struct Commands<'a> {
+	// each block being followed, the index of its next statement and how many more times it repeats
+	stack: Vec<(&'a [Statement], usize, u32)>,
+}
+
+impl Iterator for Commands<'_> {
+	type Item = Command;
+
+	fn next(&mut self) -> Option<Command> {
+		loop {
+			let (block, index, repeats) = self.stack.last_mut()?;
+			if let Some(statement) = block.get(*index) {
+				*index += 1;
+				match statement {
+					Statement::Command(command) => return Some(*command),
+					Statement::Repeat(count, body) => self.stack.push((body, 0, count - 1)),
+				}
+			} else if *repeats > 0 {
+				*repeats -= 1;
+				*index = 0;
+			} else {
+				self.stack.pop();
+			}
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind<'a> {
+	Word(&'a str),
+	Number(i32),
+	Open,
+	Close,
+	Equals,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+	kind: Kind<'a>,
+	line: usize,
+	column: usize,
+	text: &'a str,
+}
+
+impl Token<'_> {
+	fn error(&self, expected: &str) -> ParseError {
+		ParseError::new(self.column, expected, self.text).on_line(self.line)
+	}
+}
+
+/// Splits the script into tokens, giving back where the script ends for errors about anything missing from the end.
+fn tokenize(input: &str) -> Result<(Vec<Token<'_>>, (usize, usize)), Error> {
+	let mut tokens = vec![];
+	let mut errors = vec![];
+	let mut end = (1, 1);
+	for (number, line) in aoc_common::numbered_lines(input) {
+		let code = line.split('#').next().unwrap_or_default();
+		let mut cursor = Cursor::new(code);
+		while !cursor.is_done() {
+			let column = cursor.column();
+			let kind = if cursor.accept("{") {
+				Kind::Open
+			} else if cursor.accept("}") {
+				Kind::Close
+			} else if cursor.accept("=") {
+				Kind::Equals
+			} else if let Ok(word) = cursor.word("") {
+				Kind::Word(word)
+			} else {
+				match cursor.number() {
+					Ok(value) => Kind::Number(value),
+					Err(_) => {
+						errors.push(cursor.error("a command, a number, '{', '}' or '='").on_line(number));
+						break;
+					},
+				}
+			};
+			let text = &code[offset_of(code, column)..offset_of(code, cursor.column())];
+			tokens.push(Token { kind, line: number, column, text });
+		}
+		end = (number, cursor.column());
+	}
+	if errors.is_empty() {
+		Ok((tokens, end))
+	} else {
+		Err(Error::Parse(errors))
+	}
+}
+
+/// The byte offset of a 1-based column.
+fn offset_of(line: &str, column: usize) -> usize {
+	line.char_indices().nth(column - 1).map_or(line.len(), |(i, _)| i)
+}
+
+struct Parser<'a> {
+	tokens: Vec<Token<'a>>,
+	next: usize,
+	end: (usize, usize),
+	variables: HashMap<&'a str, i32>,
+}
+
+impl<'a> Parser<'a> {
+	/// Reads statements up to the end of the script, or up to and including the `}` closing a repeat.
+	fn statements(&mut self, in_repeat: bool) -> Result<Vec<Statement>, ParseError> {
+		let mut statements = vec![];
+		loop {
+			let token = match self.peek() {
+				Some(token) => token,
+				None if in_repeat => return Err(self.error_at_end("'}'")),
+				None => return Ok(statements),
+			};
+			if token.kind == Kind::Close && in_repeat {
+				self.next += 1;
+				return Ok(statements);
+			}
+			if let Some(statement) = self.statement()? {
+				statements.push(statement);
+			}
+		}
+	}
+
+	/// Reads one statement, which is `None` for a `let` or a repeat that has no commands to follow.
+	fn statement(&mut self) -> Result<Option<Statement>, ParseError> {
+		let token = self.take(EXPECTED_STATEMENT)?;
+		let command = match token.kind {
+			Kind::Word("forward") => Command::Forward,
+			Kind::Word("down") => Command::Down,
+			Kind::Word("up") => Command::Up,
+			Kind::Word("back") => {
+				let distance = self.take("a number")?;
+				let value = self.value(distance)?;
+				let reversed = value.checked_neg().ok_or_else(|| distance.error("a distance that can be reversed"))?;
+				return Ok(Some(Statement::Command(Command::Forward(reversed))));
+			},
+			Kind::Word("repeat") => return self.repeat(),
+			Kind::Word("let") => {
+				self.define()?;
+				return Ok(None);
+			},
+			_ => return Err(token.error(EXPECTED_STATEMENT)),
+		};
+		let value = self.take("a number")?;
+		Ok(Some(Statement::Command(command(self.value(value)?))))
+	}
+
+	fn repeat(&mut self) -> Result<Option<Statement>, ParseError> {
+		let count_token = self.take("a number")?;
+		let count = u32::try_from(self.value(count_token)?).map_err(|_| count_token.error("a repeat count of 0 or more"))?;
+		let open = self.take("'{'")?;
+		if open.kind != Kind::Open {
+			return Err(open.error("'{'"));
+		}
+		let body = self.statements(true)?;
+		// leaving out repeats with nothing to do means every repeat gives at least one command each time round
+		if count == 0 || body.is_empty() {
+			Ok(None)
+		} else {
+			Ok(Some(Statement::Repeat(count, body)))
+		}
+	}
+
+	fn define(&mut self) -> Result<(), ParseError> {
+		let name = self.take("a name")?;
+		let name = match name.kind {
+			Kind::Word(word) if !KEYWORDS.contains(&word) => word,
+			_ => return Err(name.error("a name")),
+		};
+		let equals = self.take("'='")?;
+		if equals.kind != Kind::Equals {
+			return Err(equals.error("'='"));
+		}
+		let value = self.take("a number")?;
+		let value = self.value(value)?;
+		self.variables.insert(name, value);
+		Ok(())
+	}
+
+	/// The number a token stands for, either written out or through a name defined earlier.
+	fn value(&self, token: Token<'a>) -> Result<i32, ParseError> {
+		match token.kind {
+			Kind::Number(value) => Ok(value),
+			Kind::Word(name) => self.variables.get(name).copied().ok_or_else(|| token.error("a number or a name defined by 'let'")),
+			_ => Err(token.error("a number")),
+		}
+	}
+
+	fn peek(&self) -> Option<Token<'a>> {
+		self.tokens.get(self.next).copied()
+	}
+
+	fn take(&mut self, expected: &str) -> Result<Token<'a>, ParseError> {
+		let token = self.peek().ok_or_else(|| self.error_at_end(expected))?;
+		self.next += 1;
+		Ok(token)
+	}
+
+	fn error_at_end(&self, expected: &str) -> ParseError {
+		let (line, column) = self.end;
+		ParseError::new(column, expected, "").on_line(line)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::submarine::{Submarine, Aim};
+
+	fn commands(script: &str) -> Vec<Command> {
+		Script::parse(script).unwrap().commands().collect()
+	}
+
+	fn error(script: &str) -> String {
+		Script::parse(script).unwrap_err().to_string()
+	}
+
+	#[test]
+	fn test_plain_course() {
+		let course = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+		let expected: Vec<Command> = course.lines().map(|line| Command::parse(line).unwrap()).collect();
+		assert_eq!(expected, commands(course));
+	}
+
+	#[test]
+	fn test_script() {
+		let script = "
+			# dive in steps
+			let leg = 7
+			repeat 2 { forward leg down 1 }
+			back 3   # and pull back a little
+			repeat 2 {
+				up 1
+				repeat 0 { forward 100 }
+				repeat 3 { }
+			}
+		";
+		assert_eq!(
+			vec![
+				Command::Forward(7), Command::Down(1), Command::Forward(7), Command::Down(1),
+				Command::Forward(-3), Command::Up(1), Command::Up(1),
+			],
+			commands(script)
+		);
+
+		let mut sub = Submarine::with_model(Aim);
+		for command in Script::parse(script).unwrap().commands() {
+			sub.execute(&command).unwrap();
+		}
+		assert_eq!(11, sub.horizontal_position());
+		assert_eq!(1, sub.depth());
+	}
+
+	#[test]
+	fn test_large_repeat() {
+		let script = Script::parse("repeat 1000000 { repeat 1000000 { down 1 } }").unwrap();
+		assert_eq!(vec![Command::Down(1); 3], script.commands().take(3).collect::<Vec<_>>());
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_eq!(
+			"Failed to parse input\n  line 2, column 1: expected 'forward', 'down', 'up', 'back', 'repeat' or 'let' but found 'sideways'",
+			error("forward 1\nsideways 5")
+		);
+		assert_eq!(
+			"Failed to parse input\n  line 1, column 9: expected a number or a name defined by 'let' but found 'leg'",
+			error("forward leg\nlet leg = 3")
+		);
+		assert_eq!(
+			"Failed to parse input\n  line 3, column 8: expected '}' but found end of line",
+			error("repeat 2 {\n  down 1\n  up 1 ")
+		);
+		assert_eq!(
+			"Failed to parse input\n  line 1, column 5: expected a name but found 'up'",
+			error("let up = 2")
+		);
+		assert_eq!(
+			"Failed to parse input\n  line 1, column 8: expected a repeat count of 0 or more but found '-1'",
+			error("repeat -1 { up 1 }")
+		);
+		assert_eq!(
+			"Failed to parse input\n  line 2, column 6: expected a command, a number, '{', '}' or '=' but found '!'",
+			error("up 1\nup 2 ! 3")
+		);
+	}
+}
@@ -1,14 +1,21 @@
-pub struct Submarine {
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+use aoc_common::{LineSolver, Answer, Error, ParseError, Cursor, Checked};
+
+/// Where the submarine is and how it is aimed, which is everything a command can change.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
 	horizontal_position: i32,
 	depth: i32,
+	aim: i32,
 }
 
-impl Submarine {
-
-	pub fn new() -> Submarine {
-		Submarine {
-			horizontal_position: 0,
-			depth: 0,
+impl State {
+	pub fn new(horizontal_position: i32, depth: i32, aim: i32) -> State {
+		State {
+			horizontal_position,
+			depth,
+			aim,
 		}
 	}
 
@@ -20,45 +27,403 @@ impl Submarine {
 		self.depth
 	}
 
-	fn forward(&mut self, value: i32) {
-		self.horizontal_position += value;
+	pub fn aim(&self) -> i32 {
+		self.aim
 	}
 
-	fn down(&mut self, value: i32) {
-		self.depth += value;
+	pub fn with_horizontal_position(self, horizontal_position: i32) -> State {
+		State {
+			horizontal_position,
+			..self
+		}
 	}
 
-	fn up(&mut self, value: i32) {
-		self.depth -= value;
+	pub fn with_depth(self, depth: i32) -> State {
+		State {
+			depth,
+			..self
+		}
 	}
 
-	pub fn execute_command(&mut self, command: &str) {
-		let command = Command::parse(&command);
-		match command {
-			Command::Forward(value) => self.forward(value),
-			Command::Down(value) => self.down(value),
-			Command::Up(value) => self.up(value),
+	pub fn with_aim(self, aim: i32) -> State {
+		State {
+			aim,
+			..self
 		}
 	}
 }
 
-#[derive(Debug, PartialEq)]
-enum Command {
+/// How a submarine moves for each command.
+///
+/// The commands are parsed the same way whatever the model, so a new model (like one with momentum,
+/// or one that won't dive past a floor) only has to say what each command does to the state. A model
+/// can keep its own state between commands too, as long as it can be cloned so that a recording
+/// submarine can take it back to an earlier step along with everything else.
+pub trait NavigationModel: ModelSnapshot {
+	/// The name the model is known by, as registered in a `ModelRegistry`.
+	fn name(&self) -> &str;
+
+	fn execute(&mut self, state: State, command: &Command) -> Result<State, Error>;
+
+	/// Whether the model steers with aim, in which case the aim is reported along with the position and depth.
+	fn has_aim(&self) -> bool {
+		false
+	}
+}
+
+/// Copies a model behind a `Box<dyn NavigationModel>`, which is implemented for every model that is `Clone`.
+pub trait ModelSnapshot {
+	fn snapshot(&self) -> Box<dyn NavigationModel>;
+}
+
+impl<T: NavigationModel + Clone + 'static> ModelSnapshot for T {
+	fn snapshot(&self) -> Box<dyn NavigationModel> {
+		Box::new(self.clone())
+	}
+}
+
+impl Clone for Box<dyn NavigationModel> {
+	fn clone(&self) -> Box<dyn NavigationModel> {
+		self.as_ref().snapshot()
+	}
+}
+
+/// The first puzzle's model: `forward` moves along, while `down` and `up` change the depth directly.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plain;
+
+impl NavigationModel for Plain {
+	fn name(&self) -> &str {
+		"plain"
+	}
+
+	fn execute(&mut self, state: State, command: &Command) -> Result<State, Error> {
+		Ok(match *command {
+			Command::Forward(value) => state.with_horizontal_position(state.horizontal_position.add_checked(value, "horizontal position + forward")?),
+			Command::Down(value) => state.with_depth(state.depth.add_checked(value, "depth + down")?),
+			Command::Up(value) => state.with_depth(state.depth.sub_checked(value, "depth - up")?),
+		})
+	}
+}
+
+/// The second puzzle's model: `down` and `up` change the aim, and `forward` dives by the aim for every unit moved.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Aim;
+
+impl NavigationModel for Aim {
+	fn name(&self) -> &str {
+		"aim"
+	}
+
+	fn execute(&mut self, state: State, command: &Command) -> Result<State, Error> {
+		Ok(match *command {
+			Command::Forward(value) => {
+				let dive = state.aim.mul_checked(value, "aim * forward")?;
+				state
+					.with_horizontal_position(state.horizontal_position.add_checked(value, "horizontal position + forward")?)
+					.with_depth(state.depth.add_checked(dive, "depth + aim * forward")?)
+			},
+			Command::Down(value) => state.with_aim(state.aim.add_checked(value, "aim + down")?),
+			Command::Up(value) => state.with_aim(state.aim.sub_checked(value, "aim - up")?),
+		})
+	}
+
+	fn has_aim(&self) -> bool {
+		true
+	}
+}
+
+impl NavigationModel for Box<dyn NavigationModel> {
+	fn name(&self) -> &str {
+		self.as_ref().name()
+	}
+
+	fn execute(&mut self, state: State, command: &Command) -> Result<State, Error> {
+		self.as_mut().execute(state, command)
+	}
+
+	fn has_aim(&self) -> bool {
+		self.as_ref().has_aim()
+	}
+}
+
+type ModelFactory = Box<dyn Fn() -> Box<dyn NavigationModel>>;
+
+/// The navigation models a submarine can be built with, looked up by name.
+///
+/// The plain and aim models are always there, and others can be registered alongside them.
+pub struct ModelRegistry {
+	factories: Vec<(String, ModelFactory)>,
+}
+
+impl ModelRegistry {
+	pub fn new() -> ModelRegistry {
+		let mut registry = ModelRegistry {
+			factories: vec![],
+		};
+		registry.register("plain", || Box::new(Plain));
+		registry.register("aim", || Box::new(Aim));
+		registry
+	}
+
+	/// Adds a model, replacing any model already registered under the same name.
+	pub fn register<F>(&mut self, name: &str, factory: F)
+	where
+		F: Fn() -> Box<dyn NavigationModel> + 'static,
+	{
+		self.factories.retain(|(registered, _)| registered != name);
+		self.factories.push((String::from(name), Box::new(factory)));
+	}
+
+	pub fn names(&self) -> Vec<&str> {
+		self.factories.iter().map(|(name, _)| name.as_str()).collect()
+	}
+
+	/// A new submarine using the model registered under the name.
+	pub fn submarine(&self, name: &str) -> Option<Submarine<Box<dyn NavigationModel>>> {
+		self.factories.iter()
+			.find(|(registered, _)| registered == name)
+			.map(|(_, factory)| Submarine::with_model(factory()))
+	}
+}
+
+impl Default for ModelRegistry {
+	fn default() -> ModelRegistry {
+		ModelRegistry::new()
+	}
+}
+
+/// A command the submarine followed and the state it left the submarine in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Step {
+	command: Command,
+	state: State,
+}
+
+impl Step {
+	pub fn command(&self) -> Command {
+		self.command
+	}
+
+	pub fn state(&self) -> State {
+		self.state
+	}
+}
+
+/// Every step of a recorded course from the state recording started in, along with how many of the
+/// steps are still followed after any undos. The model is kept as it was after each step too, so that
+/// going back to a step puts the model back the way it was then.
+struct CourseLog<M> {
+	start: (State, M),
+	steps: Vec<Step>,
+	models: Vec<M>,
+	followed: usize,
+}
+
+/// Follows commands from the surface, moving the way its navigation model says.
+///
+/// A recording submarine also keeps a log of every step, which can be undone, replayed, walked
+/// through or written out as CSV. Steps that were undone stay in the log to be replayed until the
+/// next command replaces them.
+pub struct Submarine<M = Plain> {
+	model: M,
+	state: State,
+	course: Option<CourseLog<M>>,
+}
+
+impl Submarine<Plain> {
+	pub fn new() -> Submarine<Plain> {
+		Submarine::with_model(Plain)
+	}
+}
+
+impl<M: NavigationModel + Clone> Submarine<M> {
+	pub fn with_model(model: M) -> Submarine<M> {
+		Submarine {
+			model,
+			state: State::default(),
+			course: None,
+		}
+	}
+
+	/// Keeps a log of every step from here on, which a submarine doesn't do by default so that
+	/// a course of any length can be streamed through it. The log starts from the current state,
+	/// which is where replaying back to step 0 returns to.
+	pub fn recording(self) -> Submarine<M> {
+		Submarine {
+			course: Some(CourseLog {
+				start: (self.state, self.model.clone()),
+				steps: vec![],
+				models: vec![],
+				followed: 0,
+			}),
+			..self
+		}
+	}
+
+	pub fn model(&self) -> &M {
+		&self.model
+	}
+
+	pub fn state(&self) -> State {
+		self.state
+	}
+
+	pub fn horizontal_position(&self) -> i32 {
+		self.state.horizontal_position
+	}
+
+	pub fn depth(&self) -> i32 {
+		self.state.depth
+	}
+
+	pub fn aim(&self) -> i32 {
+		self.state.aim
+	}
+
+	/// The position multiplied by the depth, along with the two of them (and the aim for models that use it).
+	pub fn answer(&self) -> Result<Answer, Error> {
+		let product = self.state.horizontal_position.mul_checked(self.state.depth, "horizontal position * depth")?;
+		let answer = Answer::from(product)
+			.with_detail("horizontal_position", self.state.horizontal_position)
+			.with_detail("depth", self.state.depth);
+		if self.model.has_aim() {
+			Ok(answer.with_detail("aim", self.state.aim))
+		} else {
+			Ok(answer)
+		}
+	}
+
+	pub fn execute_command(&mut self, command: &str) -> Result<(), Error> {
+		self.execute(&Command::parse(command)?)
+	}
+
+	pub fn execute(&mut self, command: &Command) -> Result<(), Error> {
+		self.state = self.model.execute(self.state, command)?;
+		if let Some(course) = &mut self.course {
+			// a new command takes the place of anything that had been undone
+			course.steps.truncate(course.followed);
+			course.models.truncate(course.followed);
+			course.steps.push(Step {
+				command: *command,
+				state: self.state,
+			});
+			course.models.push(self.model.clone());
+			course.followed += 1;
+		}
+		Ok(())
+	}
+
+	/// The number of recorded steps being followed, so 0 at the start of the course.
+	pub fn step(&self) -> usize {
+		self.course.as_ref().map_or(0, |course| course.followed)
+	}
+
+	/// The steps being followed, from the first command to the current one.
+	pub fn course(&self) -> &[Step] {
+		match &self.course {
+			Some(course) => &course.steps[..course.followed],
+			None => &[],
+		}
+	}
+
+	/// Goes back one step, giving the state the submarine is back in, or `None` when there is nothing to undo.
+	///
+	/// The model goes back too, so a model that keeps its own state between commands carries on from that step.
+	pub fn undo(&mut self) -> Option<State> {
+		let step = self.step();
+		if step == 0 {
+			None
+		} else {
+			self.replay_to(step - 1)
+		}
+	}
+
+	/// Moves to a recorded step, back to undo the steps after it or forward to redo undone ones.
+	/// Gives the state at that step, or `None` if the log doesn't reach it.
+	pub fn replay_to(&mut self, step: usize) -> Option<State> {
+		let course = self.course.as_mut()?;
+		if step > course.steps.len() {
+			return None;
+		}
+		course.followed = step;
+		(self.state, self.model) = match step {
+			0 => (course.start.0, course.start.1.clone()),
+			step => (course.steps[step - 1].state, course.models[step - 1].clone()),
+		};
+		Some(self.state)
+	}
+
+	/// Every state the submarine has been in along the course being followed, starting with the state
+	/// recording began in. Empty when the submarine isn't recording.
+	pub fn trajectory(&self) -> impl Iterator<Item = State> + '_ {
+		let start = self.course.as_ref().map(|course| course.start.0);
+		start.into_iter().chain(self.course().iter().map(|step| step.state))
+	}
+
+	/// Writes the course being followed as CSV, one row for every step along with the state it left the submarine in.
+	///
+	/// Step 0 is the state recording began in, and there are no rows at all when the submarine isn't recording.
+	pub fn write_csv<W: Write>(&self, out: &mut W) -> io::Result<()> {
+		writeln!(out, "step,command,horizontal_position,depth,aim")?;
+		if let Some(course) = &self.course {
+			let start = course.start.0;
+			writeln!(out, "0,,{},{},{}", start.horizontal_position, start.depth, start.aim)?;
+		}
+		for (i, step) in self.course().iter().enumerate() {
+			let state = step.state;
+			writeln!(out, "{},{},{},{},{}", i + 1, step.command, state.horizontal_position, state.depth, state.aim)?;
+		}
+		Ok(())
+	}
+}
+
+impl<M: NavigationModel + Clone + Default> Default for Submarine<M> {
+	fn default() -> Submarine<M> {
+		Submarine::with_model(M::default())
+	}
+}
+
+impl<M: NavigationModel + Clone + Default> LineSolver for Submarine<M> {
+	fn process_line(&mut self, line: &str) -> Result<(), Error> {
+		self.execute_command(line)
+	}
+
+	fn finish(self) -> Result<Answer, Error> {
+		self.answer()
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
 	Forward(i32),
 	Down(i32),
 	Up(i32),
 }
 
 impl Command {
-	fn parse(command: &str) -> Command {
-		let mut split = command.split_whitespace();
-		let name = split.next().unwrap();
-		let value = split.next().unwrap().trim().parse().expect("Command value should be an integer");
-		match name {
-			"forward" => Command::Forward(value),
-			"down" => Command::Down(value),
-			"up" => Command::Up(value),
-			_ => panic!("Unknown command '{}' provided - expected 'forward', 'down', or 'up'", name),
+	pub fn parse(command: &str) -> Result<Command, ParseError> {
+		const EXPECTED: &str = "'forward', 'down' or 'up'";
+		let mut cursor = Cursor::new(command);
+		let name = cursor.word(EXPECTED)?;
+		let command = match name {
+			"forward" => Command::Forward,
+			"down" => Command::Down,
+			"up" => Command::Up,
+			_ => return Err(ParseError::new(cursor.column() - name.len(), EXPECTED, name)),
+		};
+		let value = cursor.number()?;
+		cursor.end()?;
+		Ok(command(value))
+	}
+}
+
+impl Display for Command {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Command::Forward(value) => write!(f, "forward {}", value),
+			Command::Down(value) => write!(f, "down {}", value),
+			Command::Up(value) => write!(f, "up {}", value),
 		}
 	}
 }
@@ -67,20 +432,25 @@ impl Command {
 mod tests {
 	use super::*;
 
+	fn execute_all<M: NavigationModel + Clone>(sub: &mut Submarine<M>, commands: &[Command]) {
+		for command in commands.iter() {
+			sub.execute(command).unwrap();
+		}
+	}
+
 	#[test]
 	fn test_defaults() {
 		let sub = Submarine::new();
 
 		assert_eq!(0, sub.depth());
 		assert_eq!(0, sub.horizontal_position());
+		assert_eq!(0, sub.aim());
 	}
 
 	#[test]
 	fn test_forward() {
 		let mut sub = Submarine::new();
-		sub.forward(10);
-		sub.forward(5);
-		sub.forward(2);
+		execute_all(&mut sub, &[Command::Forward(10), Command::Forward(5), Command::Forward(2)]);
 
 		assert_eq!(17, sub.horizontal_position());
 		assert_eq!(0, sub.depth());
@@ -89,8 +459,7 @@ mod tests {
 	#[test]
 	fn test_down() {
 		let mut sub = Submarine::new();
-		sub.down(5);
-		sub.down(2);
+		execute_all(&mut sub, &[Command::Down(5), Command::Down(2)]);
 
 		assert_eq!(7, sub.depth());
 		assert_eq!(0, sub.horizontal_position());
@@ -99,28 +468,285 @@ mod tests {
 	#[test]
 	fn test_up() {
 		let mut sub = Submarine::new();
-		sub.up(20);
-		sub.up(1);
+		execute_all(&mut sub, &[Command::Up(20), Command::Up(1)]);
 
 		assert_eq!(-21, sub.depth());
 		assert_eq!(0, sub.horizontal_position());
 	}
 
+	#[test]
+	fn test_aim_forward() {
+		let mut sub = Submarine::with_model(Aim);
+		execute_all(&mut sub, &[Command::Forward(10), Command::Forward(5), Command::Forward(2)]);
+
+		assert_eq!(State::new(17, 0, 0), sub.state());
+	}
+
+	#[test]
+	fn test_aim_down() {
+		let mut sub = Submarine::with_model(Aim);
+		execute_all(&mut sub, &[Command::Down(5), Command::Down(2)]);
+
+		assert_eq!(State::new(0, 0, 7), sub.state());
+	}
+
+	#[test]
+	fn test_aim_up() {
+		let mut sub = Submarine::with_model(Aim);
+		execute_all(&mut sub, &[Command::Up(20), Command::Up(1)]);
+
+		assert_eq!(State::new(0, 0, -21), sub.state());
+	}
+
+	#[test]
+	fn test_triginometry() {
+		let mut sub = Submarine::with_model(Aim);
+		execute_all(&mut sub, &[Command::Down(10), Command::Forward(2), Command::Up(5), Command::Forward(5)]);
+
+		assert_eq!(45, sub.depth());
+		assert_eq!(7, sub.horizontal_position());
+		assert_eq!(5, sub.aim());
+	}
+
 	#[test]
 	fn test_parse_forward() {
 		let command = Command::parse("forward 1");
-		assert_eq!(Command::Forward(1), command);
+		assert_eq!(Ok(Command::Forward(1)), command);
 	}
 
 	#[test]
 	fn test_parse_down() {
 		let command = Command::parse("down 5");
-		assert_eq!(Command::Down(5), command);
+		assert_eq!(Ok(Command::Down(5)), command);
 	}
 
 	#[test]
 	fn test_parse_up() {
 		let command = Command::parse("up 13");
-		assert_eq!(Command::Up(13), command);
+		assert_eq!(Ok(Command::Up(13)), command);
+	}
+
+	#[test]
+	fn test_parse_errors() {
+		assert_eq!(Err(ParseError::new(1, "'forward', 'down' or 'up'", "backward")), Command::parse("backward 2"));
+		assert_eq!(Err(ParseError::new(6, "a number", "x")), Command::parse("down x"));
+		assert_eq!(Err(ParseError::new(3, "a number", "")), Command::parse("up"));
+		assert_eq!(Err(ParseError::new(11, "end of line", "3")), Command::parse("forward 2 3"));
+	}
+
+	#[test]
+	fn test_overflow() {
+		let mut sub = Submarine::new();
+		sub.execute(&Command::Forward(i32::MAX)).unwrap();
+		assert_eq!(Err(Error::Overflow(String::from("horizontal position + forward"))), sub.execute_command("forward 1"));
+		assert_eq!(Err(Error::Parse(vec![ParseError::new(1, "'forward', 'down' or 'up'", "sideways")])), sub.execute_command("sideways 1"));
+
+		let mut sub = Submarine::new();
+		execute_all(&mut sub, &[Command::Forward(100000), Command::Down(100000)]);
+		assert_eq!(Err(Error::Overflow(String::from("horizontal position * depth"))), sub.answer());
+
+		let mut sub = Submarine::with_model(Aim);
+		sub.execute(&Command::Down(100000)).unwrap();
+		assert_eq!(Err(Error::Overflow(String::from("aim * forward"))), sub.execute_command("forward 100000"));
+	}
+
+	/// Dives like the plain model but never goes deeper than the floor.
+	#[derive(Clone)]
+	struct DepthFloor {
+		floor: i32,
+	}
+
+	impl NavigationModel for DepthFloor {
+		fn name(&self) -> &str {
+			"depth floor"
+		}
+
+		fn execute(&mut self, state: State, command: &Command) -> Result<State, Error> {
+			let state = Plain.execute(state, command)?;
+			Ok(state.with_depth(state.depth().min(self.floor)))
+		}
+	}
+
+	#[test]
+	fn test_registry() {
+		const COURSE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+		let mut registry = ModelRegistry::new();
+		registry.register("floor", || Box::new(DepthFloor { floor: 6 }));
+		assert_eq!(vec!["plain", "aim", "floor"], registry.names());
+		assert!(registry.submarine("momentum").is_none());
+
+		// the same commands give a different course under each model
+		let mut results = vec![];
+		for name in registry.names() {
+			let mut sub = registry.submarine(name).unwrap();
+			for line in COURSE.lines() {
+				sub.execute_command(line).unwrap();
+			}
+			results.push((sub.model().name().to_string(), sub.answer().unwrap().to_string()));
+		}
+		assert_eq!(
+			vec![
+				(String::from("plain"), String::from("150")),
+				(String::from("aim"), String::from("900")),
+				(String::from("depth floor"), String::from("90")),
+			],
+			results
+		);
+
+		registry.register("aim", || Box::new(Plain));
+		assert_eq!(vec!["plain", "floor", "aim"], registry.names());
+	}
+
+	#[test]
+	fn test_answer_details() {
+		let mut plain = Submarine::new();
+		let mut aim = Submarine::<Aim>::default();
+		plain.execute_command("down 2").unwrap();
+		aim.execute_command("down 2").unwrap();
+
+		assert_eq!(2, plain.answer().unwrap().details().len());
+		assert_eq!(3, aim.answer().unwrap().details().len());
+	}
+
+	fn recorded_example() -> Submarine<Aim> {
+		let mut sub = Submarine::with_model(Aim).recording();
+		for line in ["forward 5", "down 5", "forward 8", "up 3", "down 8", "forward 2"] {
+			sub.execute_command(line).unwrap();
+		}
+		sub
+	}
+
+	#[test]
+	fn test_course_log() {
+		let mut sub = recorded_example();
+		assert_eq!(6, sub.step());
+		assert_eq!(Command::Forward(8), sub.course()[2].command());
+		assert_eq!(State::new(13, 40, 5), sub.course()[2].state());
+
+		let depths: Vec<i32> = sub.trajectory().map(|s| s.depth()).collect();
+		assert_eq!(vec![0, 0, 0, 40, 40, 40, 60], depths);
+
+		assert_eq!(Some(State::new(13, 40, 10)), sub.undo());
+		assert_eq!(Some(State::new(13, 40, 2)), sub.undo());
+		assert_eq!(5, sub.trajectory().count());
+
+		// undone steps can be replayed until a new command replaces them
+		assert_eq!(Some(State::new(15, 60, 10)), sub.replay_to(6));
+		assert_eq!(None, sub.replay_to(7));
+		assert_eq!(Some(State::default()), sub.replay_to(0));
+		assert_eq!(None, sub.undo());
+		assert_eq!(Some(State::new(5, 0, 0)), sub.replay_to(1));
+		sub.execute_command("down 1").unwrap();
+		assert_eq!(None, sub.replay_to(3));
+		assert_eq!(State::new(5, 0, 1), sub.state());
+	}
+
+	/// Moves like the plain model, except that each `forward` adds to the speed it keeps going at.
+	#[derive(Clone, Default)]
+	struct Momentum {
+		speed: i32,
 	}
-}
\ No newline at end of file
+
+	impl NavigationModel for Momentum {
+		fn name(&self) -> &str {
+			"momentum"
+		}
+
+		fn execute(&mut self, state: State, command: &Command) -> Result<State, Error> {
+			if let Command::Forward(value) = *command {
+				self.speed += value;
+				return Ok(state.with_horizontal_position(state.horizontal_position() + self.speed));
+			}
+			Plain.execute(state, command)
+		}
+	}
+
+	#[test]
+	fn test_undo_model_state() {
+		let mut sub = Submarine::with_model(Momentum::default()).recording();
+		sub.execute_command("forward 2").unwrap();
+		sub.execute_command("forward 3").unwrap();
+		assert_eq!(7, sub.horizontal_position());
+
+		// going back a step slows the model back down as well, so the step plays out the same again
+		sub.undo();
+		assert_eq!(2, sub.model().speed);
+		sub.execute_command("forward 3").unwrap();
+		assert_eq!(State::new(7, 0, 0), sub.state());
+
+		sub.replay_to(0);
+		assert_eq!(0, sub.model().speed);
+		sub.replay_to(2);
+		sub.execute_command("forward 1").unwrap();
+		assert_eq!(State::new(13, 0, 0), sub.state());
+
+		// a model from the registry can be recorded and rewound too
+		let mut registry = ModelRegistry::new();
+		registry.register("momentum", || Box::new(Momentum::default()));
+		let mut sub = registry.submarine("momentum").unwrap().recording();
+		sub.execute_command("forward 2").unwrap();
+		sub.execute_command("forward 3").unwrap();
+		sub.undo();
+		sub.execute_command("forward 3").unwrap();
+		assert_eq!(7, sub.horizontal_position());
+	}
+
+	#[test]
+	fn test_not_recording() {
+		let mut sub = Submarine::new();
+		sub.execute_command("forward 5").unwrap();
+		assert_eq!(0, sub.step());
+		assert_eq!(None, sub.undo());
+		assert_eq!(None, sub.replay_to(0));
+		assert_eq!(0, sub.trajectory().count());
+		assert_eq!(State::new(5, 0, 0), sub.state());
+
+		let mut csv = vec![];
+		sub.write_csv(&mut csv).unwrap();
+		assert_eq!("step,command,horizontal_position,depth,aim\n", String::from_utf8(csv).unwrap());
+	}
+
+	#[test]
+	fn test_recording_part_way() {
+		let mut sub = Submarine::with_model(Aim);
+		sub.execute_command("forward 5").unwrap();
+		sub.execute_command("down 5").unwrap();
+		let mut sub = sub.recording();
+		sub.execute_command("forward 8").unwrap();
+
+		let depths: Vec<i32> = sub.trajectory().map(|s| s.depth()).collect();
+		assert_eq!(vec![0, 40], depths);
+		assert_eq!(Some(State::new(5, 0, 5)), sub.undo());
+
+		sub.replay_to(1);
+		let mut csv = vec![];
+		sub.write_csv(&mut csv).unwrap();
+		assert_eq!(
+			"step,command,horizontal_position,depth,aim\n\
+			0,,5,0,5\n\
+			1,forward 8,13,40,5\n",
+			String::from_utf8(csv).unwrap()
+		);
+
+		assert_eq!(Some(State::new(5, 0, 5)), sub.replay_to(0));
+	}
+
+	#[test]
+	fn test_write_csv() {
+		let mut sub = recorded_example();
+		sub.undo();
+		let mut csv = vec![];
+		sub.write_csv(&mut csv).unwrap();
+		assert_eq!(
+			"step,command,horizontal_position,depth,aim\n\
+			0,,0,0,0\n\
+			1,forward 5,5,0,0\n\
+			2,down 5,5,0,5\n\
+			3,forward 8,13,40,5\n\
+			4,up 3,13,40,2\n\
+			5,down 8,13,40,10\n",
+			String::from_utf8(csv).unwrap()
+		);
+	}
+}
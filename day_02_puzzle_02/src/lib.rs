@@ -0,0 +1,24 @@
+pub mod planner;
+pub use day_02_puzzle_01::submarine;
+
+use aoc_common::{Puzzle, Answer, Error};
+use submarine::{Aim, Command, Submarine};
+
+pub struct Day02;
+
+impl Puzzle for Day02 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Vec<Command>, Error> {
+        aoc_common::parse_lines(input, Command::parse)
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<Answer, Error> {
+        let mut sub = Submarine::with_model(Aim);
+        for command in commands.iter() {
+            sub.execute(command)?;
+        }
+
+        sub.answer()
+    }
+}
@@ -0,0 +1,203 @@
+use aoc_common::Error;
+use crate::submarine::Command;
+
+/// What a planned course should keep as small as it can.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Objective {
+	/// The number of commands, which is never more than three.
+	FewestCommands,
+	/// The sum of every command's units. Going forward always adds up to the target's horizontal
+	/// position, so this keeps down how far the aim is turned.
+	ShortestDistance,
+}
+
+/// Works out a course that takes the submarine from the surface to a target under the aim model.
+///
+/// Courses only use the kind of commands found in the puzzle input: every value is positive and the
+/// submarine never points above the surface, so its depth never goes down along the way. That makes
+/// the target the deepest point of the course.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Planner {
+	objective: Objective,
+	max_depth: Option<i32>,
+}
+
+impl Planner {
+	pub fn new(objective: Objective) -> Planner {
+		Planner {
+			objective,
+			max_depth: None,
+		}
+	}
+
+	/// Refuses to plan a course that goes below `max_depth`.
+	pub fn with_max_depth(self, max_depth: i32) -> Planner {
+		Planner {
+			max_depth: Some(max_depth),
+			..self
+		}
+	}
+
+	pub fn objective(&self) -> Objective {
+		self.objective
+	}
+
+	pub fn max_depth(&self) -> Option<i32> {
+		self.max_depth
+	}
+
+	pub fn plan(&self, horizontal_position: i32, depth: i32) -> Result<Vec<Command>, Error> {
+		let unreachable = |reason: &str| Err(Error::NoSolution(format!(
+			"The submarine can't reach horizontal position {} and depth {} as {}",
+			horizontal_position, depth, reason
+		)));
+		if horizontal_position < 0 {
+			return unreachable("it only goes forward");
+		}
+		if depth < 0 {
+			return unreachable("it stays below the surface");
+		}
+		if let Some(max_depth) = self.max_depth {
+			if depth > max_depth {
+				return unreachable(&format!("that is deeper than {}", max_depth));
+			}
+		}
+		if depth == 0 {
+			return Ok(forward(horizontal_position));
+		}
+		if horizontal_position == 0 {
+			return unreachable("it only changes depth while going forward");
+		}
+
+		let commands = match self.objective {
+			Objective::FewestCommands if depth % horizontal_position == 0 => vec![
+				Command::Down(depth / horizontal_position),
+				Command::Forward(horizontal_position),
+			],
+			Objective::FewestCommands => {
+				// go most of the way at the surface, then the rest at an aim that dives exactly to the target
+				let last_leg = largest_divisor_below(depth, horizontal_position);
+				let mut commands = forward(horizontal_position - last_leg);
+				commands.extend([Command::Down(depth / last_leg), Command::Forward(last_leg)]);
+				commands
+			},
+			Objective::ShortestDistance => {
+				// the deepest aim can't be less than this to get down in time, and holding the aim
+				// one lower for the first part of the way lands exactly on the depth
+				let aim = (depth - 1) / horizontal_position + 1;
+				let last_leg = depth - (aim - 1) * horizontal_position;
+				if last_leg == horizontal_position {
+					vec![Command::Down(aim), Command::Forward(horizontal_position)]
+				} else {
+					let mut commands = vec![];
+					if aim > 1 {
+						commands.push(Command::Down(aim - 1));
+					}
+					commands.extend([
+						Command::Forward(horizontal_position - last_leg),
+						Command::Down(1),
+						Command::Forward(last_leg),
+					]);
+					commands
+				}
+			},
+		};
+		Ok(commands)
+	}
+}
+
+/// The total units of a course, the sum of the values of all its commands.
+pub fn distance(commands: &[Command]) -> i64 {
+	commands
+		.iter()
+		.map(|command| match command {
+			Command::Forward(value) | Command::Down(value) | Command::Up(value) => i64::from(*value).abs(),
+		})
+		.sum()
+}
+
+/// A single forward command, or none at all for no distance.
+fn forward(distance: i32) -> Vec<Command> {
+	if distance == 0 {
+		vec![]
+	} else {
+		vec![Command::Forward(distance)]
+	}
+}
+
+/// The largest divisor of `value` that is less than `limit`, which is at least 1.
+fn largest_divisor_below(value: i32, limit: i32) -> i32 {
+	let mut largest = 1;
+	let mut divisor = 1;
+	while divisor <= value / divisor {
+		if value % divisor == 0 {
+			for candidate in [divisor, value / divisor] {
+				if candidate < limit {
+					largest = largest.max(candidate);
+				}
+			}
+		}
+		divisor += 1;
+	}
+	largest
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::submarine::{Aim, Submarine};
+
+	/// Follows the planned course the way a course read from input is followed.
+	fn follow(commands: &[Command]) -> (i32, i32) {
+		let mut sub = Submarine::with_model(Aim);
+		for command in commands {
+			sub.execute_command(&command.to_string()).unwrap();
+			assert!(sub.aim() >= 0);
+		}
+		(sub.horizontal_position(), sub.depth())
+	}
+
+	#[test]
+	fn test_fewest_commands() {
+		let planner = Planner::new(Objective::FewestCommands);
+		assert_eq!(Ok(vec![]), planner.plan(0, 0));
+		assert_eq!(Ok(vec![Command::Forward(15)]), planner.plan(15, 0));
+		assert_eq!(Ok(vec![Command::Down(4), Command::Forward(15)]), planner.plan(15, 60));
+		assert_eq!(Ok(vec![Command::Forward(1), Command::Down(6), Command::Forward(14)]), planner.plan(15, 84));
+
+		for (horizontal_position, depth) in [(1, 1), (15, 1), (7, 97), (1840, 991), (1000, 999_999)] {
+			let commands = planner.plan(horizontal_position, depth).unwrap();
+			assert!(commands.len() <= 3);
+			assert_eq!((horizontal_position, depth), follow(&commands));
+		}
+	}
+
+	#[test]
+	fn test_shortest_distance() {
+		let planner = Planner::new(Objective::ShortestDistance);
+		assert_eq!(Ok(vec![Command::Down(4), Command::Forward(15)]), planner.plan(15, 60));
+		let commands = planner.plan(15, 84).unwrap();
+		assert_eq!(vec![Command::Down(5), Command::Forward(6), Command::Down(1), Command::Forward(9)], commands);
+		assert_eq!(21, distance(&commands));
+
+		let fewest = Planner::new(Objective::FewestCommands);
+		for (horizontal_position, depth) in [(1, 1), (15, 1), (7, 97), (1840, 991), (1000, 999_999)] {
+			let commands = planner.plan(horizontal_position, depth).unwrap();
+			assert_eq!((horizontal_position, depth), follow(&commands));
+			assert!(distance(&commands) <= distance(&fewest.plan(horizontal_position, depth).unwrap()));
+		}
+	}
+
+	#[test]
+	fn test_unreachable() {
+		let planner = Planner::new(Objective::ShortestDistance).with_max_depth(100);
+		assert_eq!(Ok((15, 100)), planner.plan(15, 100).map(|commands| follow(&commands)));
+		assert_eq!(
+			Err(Error::NoSolution(String::from("The submarine can't reach horizontal position 15 and depth 101 as that is deeper than 100"))),
+			planner.plan(15, 101)
+		);
+		assert!(planner.plan(0, 5).is_err());
+		assert!(planner.plan(-1, 0).is_err());
+		assert!(planner.plan(5, -1).is_err());
+	}
+}
@@ -0,0 +1,112 @@
+/// Decides which bit to keep in a column of the diagnostic from how many reports have each bit.
+///
+/// A 1 is the common bit when the share of reports with a 1 in the column is above the threshold, a 0
+/// when it is below, and otherwise it's a tie. The threshold is a half by default, which makes the
+/// common bit the one most reports have.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BitCriteria {
+	keep: Keep,
+	ties_to: u8,
+	threshold: (u32, u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Keep {
+	MostCommon,
+	LeastCommon,
+}
+
+impl BitCriteria {
+	pub fn new(keep: Keep, ties_to: u8) -> BitCriteria {
+		if ties_to > 1 {
+			panic!("Expected ties to go to 0 or 1 - found {}", ties_to);
+		}
+		BitCriteria {
+			keep,
+			ties_to,
+			threshold: (1, 2),
+		}
+	}
+
+	/// Keeps the most common bit with ties going to 1, as the oxygen generator rating does.
+	pub fn most_common() -> BitCriteria {
+		BitCriteria::new(Keep::MostCommon, 1)
+	}
+
+	/// Keeps the least common bit with ties going to 0, as the CO2 scrubber rating does.
+	pub fn least_common() -> BitCriteria {
+		BitCriteria::new(Keep::LeastCommon, 0)
+	}
+
+	pub fn with_ties_to(self, ties_to: u8) -> BitCriteria {
+		BitCriteria::new(self.keep, ties_to).with_threshold(self.threshold.0, self.threshold.1)
+	}
+
+	/// Makes 1 the common bit only when more than `numerator / denominator` of the reports have it.
+	pub fn with_threshold(self, numerator: u32, denominator: u32) -> BitCriteria {
+		if denominator == 0 || numerator > denominator {
+			panic!("Expected a threshold between 0 and 1 - found {}/{}", numerator, denominator);
+		}
+		BitCriteria {
+			threshold: (numerator, denominator),
+			..self
+		}
+	}
+
+	pub fn keep(&self) -> Keep {
+		self.keep
+	}
+
+	pub fn ties_to(&self) -> u8 {
+		self.ties_to
+	}
+
+	pub fn threshold(&self) -> (u32, u32) {
+		self.threshold
+	}
+
+	/// The bit to keep when `zeros` reports have a 0 in the column and `ones` have a 1.
+	pub fn bit(&self, zeros: usize, ones: usize) -> u8 {
+		let (numerator, denominator) = self.threshold;
+		// ones / (zeros + ones) against numerator / denominator, without any rounding
+		let share = ones as u128 * denominator as u128;
+		let threshold = (zeros as u128 + ones as u128) * numerator as u128;
+		let common = match share.cmp(&threshold) {
+			std::cmp::Ordering::Greater => 1,
+			std::cmp::Ordering::Less => 0,
+			std::cmp::Ordering::Equal => return self.ties_to,
+		};
+		match self.keep {
+			Keep::MostCommon => common,
+			Keep::LeastCommon => 1 - common,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_bit() {
+		assert_eq!(1, BitCriteria::most_common().bit(3, 5));
+		assert_eq!(1, BitCriteria::most_common().bit(4, 4));
+		assert_eq!(0, BitCriteria::least_common().bit(3, 5));
+		assert_eq!(0, BitCriteria::least_common().bit(4, 4));
+		assert_eq!(0, BitCriteria::most_common().with_ties_to(0).bit(4, 4));
+
+		// with a two thirds threshold 1 is only common when more than twice as many reports have it
+		let two_thirds = BitCriteria::most_common().with_threshold(2, 3);
+		assert_eq!(0, two_thirds.bit(3, 5));
+		assert_eq!(1, two_thirds.bit(2, 5));
+		assert_eq!(1, two_thirds.bit(4, 8));
+		assert_eq!(0, two_thirds.with_ties_to(0).bit(4, 8));
+		assert_eq!((2, 3), two_thirds.with_ties_to(0).threshold());
+	}
+
+	#[test]
+	#[should_panic(expected = "Expected a threshold between 0 and 1 - found 3/2")]
+	fn test_invalid_threshold() {
+		BitCriteria::most_common().with_threshold(3, 2);
+	}
+}
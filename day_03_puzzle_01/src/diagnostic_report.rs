@@ -0,0 +1,203 @@
+use aoc_common::{Error, ParseError, Checked};
+use crate::bit_criteria::BitCriteria;
+use crate::life_support::{LifeSupport, Rating};
+
+/// Every report from the diagnostic, loaded once for both the power consumption and the life support rating.
+///
+/// Reports are read from one line each and must all be the same width, up to 64 bits.
+pub struct DiagnosticReport {
+	one_counts: Vec<usize>,
+	life_support: LifeSupport,
+}
+
+impl DiagnosticReport {
+	/// Reads one report per non-blank line, reporting every line that isn't a report of the same width as the first.
+	///
+	/// The first line sets the width even when it isn't a valid report itself, so a bad first line doesn't
+	/// leave the second one to decide what every other line is checked against.
+	pub fn parse(input: &str) -> Result<DiagnosticReport, Error> {
+		let mut expected_width = None;
+		let mut one_counts: Vec<usize> = vec![];
+		let mut life_support = LifeSupport::new();
+		let mut errors = vec![];
+		for (number, line) in aoc_common::numbered_lines(input) {
+			let report = line.trim();
+			let first_column = line[..line.len() - line.trim_start().len()].chars().count() + 1;
+			let width = report.chars().count();
+			let expected_width = *expected_width.get_or_insert(width);
+			if width > 64 {
+				errors.push(ParseError::new(first_column, "a report of at most 64 bits", report).on_line(number));
+				continue;
+			}
+			if width != expected_width {
+				let expected = format!("a report of {} bits like the first", expected_width);
+				errors.push(ParseError::new(first_column, &expected, report).on_line(number));
+				continue;
+			}
+			match parse_report(report, first_column) {
+				Ok(packed) => {
+					if one_counts.is_empty() {
+						one_counts = vec![0; width];
+					}
+					for (column, count) in one_counts.iter_mut().enumerate() {
+						*count += ((packed >> (width - 1 - column)) & 1) as usize;
+					}
+					life_support.load_report(packed, width);
+				},
+				Err(error) => errors.push(error.on_line(number)),
+			}
+		}
+
+		if !errors.is_empty() {
+			Err(Error::Parse(errors))
+		} else if life_support.is_empty() {
+			Err(Error::NoSolution(String::from("The diagnostic has no reports")))
+		} else {
+			Ok(DiagnosticReport { one_counts, life_support })
+		}
+	}
+
+	/// The number of bits in each report.
+	pub fn width(&self) -> usize {
+		self.one_counts.len()
+	}
+
+	/// The number of reports, which is never 0.
+	pub fn report_count(&self) -> usize {
+		self.life_support.len()
+	}
+
+	/// How many reports have a 1 in each column, starting from the first character.
+	pub fn one_counts(&self) -> &[usize] {
+		&self.one_counts
+	}
+
+	pub fn life_support(&self) -> &LifeSupport {
+		&self.life_support
+	}
+
+	/// Each bit is the most common one in its column, with ties going to 0.
+	pub fn gamma_rate(&self) -> u64 {
+		self.rate(&BitCriteria::most_common().with_ties_to(0))
+	}
+
+	/// Each bit is the least common one in its column, with ties going to 1.
+	pub fn epsilon_rate(&self) -> u64 {
+		self.rate(&BitCriteria::least_common().with_ties_to(1))
+	}
+
+	/// Picks a bit for every column from all of the reports, the way the gamma and epsilon rates are worked out.
+	pub fn rate(&self, criteria: &BitCriteria) -> u64 {
+		let count = self.report_count();
+		self.one_counts.iter().fold(0, |rate, &ones| (rate << 1) | u64::from(criteria.bit(count - ones, ones)))
+	}
+
+	/// Filters the reports a column at a time, the way the oxygen and scrubber ratings are worked out.
+	pub fn rating(&self, criteria: &BitCriteria) -> Rating {
+		self.life_support.rating(criteria)
+	}
+
+	pub fn power_consumption(&self) -> Result<u64, Error> {
+		self.gamma_rate().mul_checked(self.epsilon_rate(), "gamma rate * epsilon rate")
+	}
+
+	pub fn oxygen_rating(&self) -> u64 {
+		self.life_support.oxygen_rating()
+	}
+
+	pub fn scrubber_rating(&self) -> u64 {
+		self.life_support.scrubber_rating()
+	}
+
+	pub fn life_support_rating(&self) -> Result<u64, Error> {
+		self.oxygen_rating().mul_checked(self.scrubber_rating(), "oxygen rating * scrubber rating")
+	}
+}
+
+fn parse_report(report: &str, first_column: usize) -> Result<u64, ParseError> {
+	report.chars().enumerate().try_fold(0, |packed, (i, c)| match c {
+		'0' => Ok(packed << 1),
+		'1' => Ok((packed << 1) | 1),
+		_ => Err(ParseError::new(first_column + i, "'0' or '1'", &c.to_string())),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
+
+	#[test]
+	fn test_example() {
+		let report = DiagnosticReport::parse(EXAMPLE).unwrap();
+		assert_eq!(12, report.report_count());
+		assert_eq!(&[7, 5, 8, 7, 5], report.one_counts());
+		assert_eq!(22, report.gamma_rate());
+		assert_eq!(9, report.epsilon_rate());
+		assert_eq!(Ok(198), report.power_consumption());
+		assert_eq!(23, report.oxygen_rating());
+		assert_eq!(10, report.scrubber_rating());
+		assert_eq!(Ok(230), report.life_support_rating());
+	}
+
+	#[test]
+	fn test_wide_reports() {
+		let input = format!("{}\n{}\n{}\n", "1".repeat(64), "1".repeat(64), "0".repeat(64));
+		let report = DiagnosticReport::parse(&input).unwrap();
+		assert_eq!(u64::MAX, report.gamma_rate());
+		assert_eq!(0, report.epsilon_rate());
+		assert_eq!(Ok(0), report.power_consumption());
+	}
+
+	#[test]
+	fn test_errors() {
+		let input = format!("10110\n101\n  10210\n\n{}\n", "1".repeat(65));
+		let errors = vec![
+			ParseError::new(1, "a report of 5 bits like the first", "101").on_line(2),
+			ParseError::new(5, "'0' or '1'", "2").on_line(3),
+			ParseError::new(1, "a report of at most 64 bits", &"1".repeat(65)).on_line(5),
+		];
+		assert_eq!(Err(Error::Parse(errors)), DiagnosticReport::parse(&input).map(|report| report.report_count()));
+		assert_eq!(Err(Error::NoSolution(String::from("The diagnostic has no reports"))), DiagnosticReport::parse("\n").map(|report| report.report_count()));
+
+		// a bad first line still sets the width the rest are checked against
+		let errors = vec![
+			ParseError::new(3, "'0' or '1'", "2").on_line(1),
+			ParseError::new(1, "a report of 5 bits like the first", "101").on_line(2),
+		];
+		assert_eq!(Err(Error::Parse(errors)), DiagnosticReport::parse("10210
+101
+10110
+").map(|report| report.report_count()));
+	}
+
+	#[test]
+	fn test_rating_trace() {
+		use crate::life_support::Elimination;
+
+		let report = DiagnosticReport::parse(EXAMPLE).unwrap();
+		let oxygen = report.rating(&BitCriteria::most_common());
+		assert_eq!(23, oxygen.value());
+		assert_eq!(
+			&[
+				Elimination::new(0, 1, 12, 7),
+				Elimination::new(1, 0, 7, 4),
+				Elimination::new(2, 1, 4, 3),
+				Elimination::new(3, 1, 3, 2),
+				Elimination::new(4, 1, 2, 1),
+			],
+			oxygen.trace()
+		);
+		let scrubber = report.rating(&BitCriteria::least_common());
+		assert_eq!(10, scrubber.value());
+		assert_eq!(vec![7, 3, 1], scrubber.trace().iter().map(Elimination::eliminated).collect::<Vec<_>>());
+
+		// three quarters of the reports having a 1 is a tie at a threshold of three quarters
+		let report = DiagnosticReport::parse("110\n101\n100\n011\n").unwrap();
+		let criteria = BitCriteria::most_common().with_threshold(3, 4).with_ties_to(0);
+		assert_eq!(0b011, report.rating(&criteria).value());
+		assert_eq!(&[Elimination::new(0, 0, 4, 1)], report.rating(&criteria).trace());
+		assert_eq!(0b000, report.rate(&criteria));
+	}
+}
@@ -0,0 +1,22 @@
+pub mod bit_criteria;
+pub mod diagnostic_report;
+pub mod life_support;
+
+use aoc_common::{Puzzle, Answer, Error};
+use diagnostic_report::DiagnosticReport;
+
+pub struct Day03;
+
+impl Puzzle for Day03 {
+    type Input = DiagnosticReport;
+
+    fn parse(input: &str) -> Result<DiagnosticReport, Error> {
+        DiagnosticReport::parse(input)
+    }
+
+    fn part1(report: &DiagnosticReport) -> Result<Answer, Error> {
+        Ok(Answer::from(report.power_consumption()?)
+            .with_detail("gamma_rate", report.gamma_rate())
+            .with_detail("epsilon_rate", report.epsilon_rate()))
+    }
+}
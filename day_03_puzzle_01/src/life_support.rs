@@ -0,0 +1,301 @@
+use crate::bit_criteria::BitCriteria;
+
+/// The diagnostic reports, each packed into the bits of a `u64` and kept in a binary trie.
+///
+/// Every node counts the reports below it, so each rating comes from a single walk down from the root,
+/// choosing a branch by its count at every bit. Copies of the same report share one leaf, and a report
+/// stops at a leaf as soon as no other report shares its bits so far. Reports with a long prefix in
+/// common still need a branch for each bit of it, so the trie can hold up to one node per bit of every
+/// different report rather than a couple of nodes per report.
+pub struct LifeSupport {
+	nodes: Vec<Node>,
+	width: usize,
+}
+
+enum Node {
+	/// One or more copies of the same report.
+	Leaf { report: u64, count: usize },
+	/// Reports that share every bit so far, split by their next bit.
+	Branch { count: usize, children: [Option<usize>; 2] },
+}
+
+/// A rating found by filtering the reports one column at a time, along with what happened at each column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rating {
+	value: u64,
+	trace: Vec<Elimination>,
+}
+
+impl Rating {
+	pub fn value(&self) -> u64 {
+		self.value
+	}
+
+	/// The columns the reports were filtered on, stopping once every report left is the same.
+	pub fn trace(&self) -> &[Elimination] {
+		&self.trace
+	}
+}
+
+/// The reports left before and after filtering on one column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Elimination {
+	column: usize,
+	bit: u8,
+	considered: usize,
+	kept: usize,
+}
+
+impl Elimination {
+	pub fn new(column: usize, bit: u8, considered: usize, kept: usize) -> Elimination {
+		Elimination { column, bit, considered, kept }
+	}
+
+	/// The column, counting from 0 at the first character of a report.
+	pub fn column(&self) -> usize {
+		self.column
+	}
+
+	/// The bit every report kept has in this column.
+	pub fn bit(&self) -> u8 {
+		self.bit
+	}
+
+	pub fn considered(&self) -> usize {
+		self.considered
+	}
+
+	pub fn kept(&self) -> usize {
+		self.kept
+	}
+
+	pub fn eliminated(&self) -> usize {
+		self.considered - self.kept
+	}
+}
+
+impl Node {
+	fn count(&self) -> usize {
+		match self {
+			Node::Leaf { count, .. } | Node::Branch { count, .. } => *count,
+		}
+	}
+}
+
+impl LifeSupport {
+
+	pub fn new() -> LifeSupport {
+		LifeSupport {
+			nodes: vec!(),
+			width: 0,
+		}
+	}
+
+	/// The number of bits in every report, 0 until the first report is loaded.
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// The number of reports loaded, counting every copy of a repeated report.
+	pub fn len(&self) -> usize {
+		self.nodes.first().map_or(0, Node::count)
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.nodes.is_empty()
+	}
+
+	pub fn load_diagnostic(&mut self, data: &str) {
+		self.load_report(pack(data), data.len());
+	}
+
+	/// Loads a report already packed into the low `width` bits of a number, the first bit being the highest.
+	pub fn load_report(&mut self, report: u64, width: usize) {
+		if self.nodes.is_empty() {
+			if width == 0 || width > 64 {
+				panic!("Expected a diagnostic report of 1 to 64 bits - found one of {} bits", width);
+			}
+			self.width = width;
+		} else if width != self.width {
+			panic!("All diagnistic data must have the same length - found a report of {} bits after ones of {} bits", width, self.width);
+		}
+		if width < 64 && report >> width != 0 {
+			panic!("Expected a report that fits in {} bits - found {}", width, report);
+		}
+		self.insert(report);
+	}
+
+	pub fn oxygen_rating(&self) -> u64 {
+		self.rating(&BitCriteria::most_common()).value()
+	}
+
+	pub fn scrubber_rating(&self) -> u64 {
+		self.rating(&BitCriteria::least_common()).value()
+	}
+
+	fn insert(&mut self, report: u64) {
+		if self.nodes.is_empty() {
+			self.nodes.push(Node::Leaf { report, count: 1 });
+			return;
+		}
+
+		let mut node = 0;
+		let mut bit = self.width;
+		loop {
+			let next_node = self.nodes.len();
+			match self.nodes[node] {
+				Node::Leaf { report: existing, ref mut count } if existing == report => {
+					*count += 1;
+					return;
+				},
+				Node::Leaf { report: existing, count } => {
+					// a different report got this far, so the leaf moves down a level below a new branch
+					// and the loop carries on to place the new report under that branch
+					self.nodes.push(Node::Leaf { report: existing, count });
+					let mut children = [None, None];
+					children[bit_at(existing, bit - 1)] = Some(next_node);
+					self.nodes[node] = Node::Branch { count, children };
+				},
+				Node::Branch { ref mut count, ref mut children } => {
+					*count += 1;
+					let side = bit_at(report, bit - 1);
+					match children[side] {
+						Some(child) => {
+							node = child;
+							bit -= 1;
+						},
+						None => {
+							children[side] = Some(next_node);
+							self.nodes.push(Node::Leaf { report, count: 1 });
+							return;
+						},
+					}
+				},
+			}
+		}
+	}
+
+	/// Filters the reports a column at a time, keeping the ones with the bit `criteria` picks, until
+	/// every report left is the same. When none of the reports left have the bit picked, they all have
+	/// the other one, and they're all kept.
+	///
+	/// Each column takes one step down the trie, so this never looks at a report more than once.
+	pub fn rating(&self, criteria: &BitCriteria) -> Rating {
+		if self.nodes.is_empty() {
+			panic!("Expected at least one diagnostic report to find a rating from");
+		}
+
+		let mut trace = vec![];
+		let mut node = 0;
+		loop {
+			match &self.nodes[node] {
+				Node::Leaf { report, .. } => return Rating { value: *report, trace },
+				Node::Branch { count, children } => {
+					let count_of = |child: Option<usize>| child.map_or(0, |child| self.nodes[child].count());
+					let mut bit = criteria.bit(count_of(children[0]), count_of(children[1]));
+					if children[bit as usize].is_none() {
+						bit = 1 - bit;
+					}
+					let next = children[bit as usize].expect("A branch always has at least one report below it");
+					trace.push(Elimination::new(trace.len(), bit, *count, self.nodes[next].count()));
+					node = next;
+				},
+			}
+		}
+	}
+}
+
+impl Default for LifeSupport {
+	fn default() -> LifeSupport {
+		LifeSupport::new()
+	}
+}
+
+/// Packs a report like `10110` into the low bits of a number, the first character being the highest bit.
+fn pack(diagnostic: &str) -> u64 {
+	diagnostic.bytes().enumerate().fold(0, |packed, (i, b)| match b {
+		b'0' => packed << 1,
+		b'1' => (packed << 1) | 1,
+		_ => panic!("Entry '{}' has an invalid character at column {} - only 0 and 1 are accepted input", diagnostic, i),
+	})
+}
+
+fn bit_at(report: u64, bit: usize) -> usize {
+	((report >> bit) & 1) as usize
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_example_oxygen() {
+		let mut life_support = LifeSupport::new();
+		life_support.load_diagnostic("00100");
+		life_support.load_diagnostic("11110");
+		life_support.load_diagnostic("10110");
+		life_support.load_diagnostic("10111");
+		life_support.load_diagnostic("10101");
+		life_support.load_diagnostic("01111");
+		life_support.load_diagnostic("00111");
+		life_support.load_diagnostic("11100");
+		life_support.load_diagnostic("10000");
+		life_support.load_diagnostic("11001");
+		life_support.load_diagnostic("00100");
+		life_support.load_diagnostic("01010");
+
+		let oxygen_rating = life_support.oxygen_rating();
+		assert_eq!(23, oxygen_rating);
+	}
+
+	#[test]
+	fn test_example_scrubber() {
+		let mut life_support = LifeSupport::new();
+		life_support.load_diagnostic("00100");
+		life_support.load_diagnostic("11110");
+		life_support.load_diagnostic("10110");
+		life_support.load_diagnostic("10111");
+		life_support.load_diagnostic("10101");
+		life_support.load_diagnostic("01111");
+		life_support.load_diagnostic("00111");
+		life_support.load_diagnostic("11100");
+		life_support.load_diagnostic("10000");
+		life_support.load_diagnostic("11001");
+		life_support.load_diagnostic("00100");
+		life_support.load_diagnostic("01010");
+
+		let scrubber_rating = life_support.scrubber_rating();
+		assert_eq!(10, scrubber_rating);
+	}
+
+	#[test]
+	fn test_repeated_and_shared_bits() {
+		let mut life_support = LifeSupport::new();
+		for report in ["10110", "10111", "10111", "00000", "00001"] {
+			life_support.load_diagnostic(report);
+		}
+		assert_eq!(5, life_support.len());
+		// where every report left has the same bit the walk carries straight on, rather than looking for a less common one
+		assert_eq!(0b10111, life_support.oxygen_rating());
+		assert_eq!(0, life_support.scrubber_rating());
+
+		let mut life_support = LifeSupport::new();
+		life_support.load_diagnostic("0110");
+		life_support.load_diagnostic("0110");
+		assert_eq!(6, life_support.oxygen_rating());
+		assert_eq!(6, life_support.scrubber_rating());
+	}
+
+	#[test]
+	fn test_wide_reports() {
+		let mut life_support = LifeSupport::new();
+		let high = format!("1{}", "0".repeat(63));
+		let low = format!("{}1", "0".repeat(63));
+		life_support.load_diagnostic(&high);
+		life_support.load_diagnostic(&high);
+		life_support.load_diagnostic(&low);
+		assert_eq!(64, life_support.width());
+		assert_eq!(1 << 63, life_support.oxygen_rating());
+		assert_eq!(1, life_support.scrubber_rating());
+	}
+}
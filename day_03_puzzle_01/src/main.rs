@@ -1,26 +1,25 @@
-mod power_consumption;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use power_consumption::PowerConsumption;
+use aoc_common::InputSource;
+use day_03_puzzle_01::diagnostic_report::DiagnosticReport;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_03_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
+    let input = InputSource::from_args("./day_03_puzzle_01/input.txt").read_to_string()?;
 
-    let mut power = PowerConsumption::new();
-    for line in lines {
-        if let Ok(entry) = line {
-            if entry.trim().len() == 0 {
-                // skip any rows with no content
-                continue;
-            }
-            power.analyze_entry(&entry.trim());
-        }
-    }
+    let report = match DiagnosticReport::parse(&input) {
+        Ok(report) => report,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
+    };
 
-    println!("The submarine's diagnostics give it a gamma rate of {} and an epsilon rate of {}", power.gamme_rate(), power.epsilon_rate());
-    println!("Multiplied together that gives {}", power.gamme_rate() * power.epsilon_rate());
+    println!("The submarine's diagnostics give it a gamma rate of {} and an epsilon rate of {}", report.gamma_rate(), report.epsilon_rate());
+    match report.power_consumption() {
+        Ok(product) => println!("Multiplied together that gives {}", product),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
+    }
 
     Ok(())
 }
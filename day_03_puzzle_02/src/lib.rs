@@ -0,0 +1,20 @@
+pub use day_03_puzzle_01::{bit_criteria, diagnostic_report, life_support};
+
+use aoc_common::{Puzzle, Answer, Error};
+use diagnostic_report::DiagnosticReport;
+
+pub struct Day03;
+
+impl Puzzle for Day03 {
+    type Input = DiagnosticReport;
+
+    fn parse(input: &str) -> Result<DiagnosticReport, Error> {
+        DiagnosticReport::parse(input)
+    }
+
+    fn part2(report: &DiagnosticReport) -> Result<Answer, Error> {
+        Ok(Answer::from(report.life_support_rating()?)
+            .with_detail("oxygen_rating", report.oxygen_rating())
+            .with_detail("scrubber_rating", report.scrubber_rating()))
+    }
+}
@@ -1,35 +1,28 @@
-mod life_support;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use crate::life_support::LifeSupport;
+use aoc_common::InputSource;
+use day_03_puzzle_02::diagnostic_report::DiagnosticReport;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_03_puzzle_01/input.txt")?;
-    let reader = BufReader::new(file);
+    let input = InputSource::from_args("./day_03_puzzle_01/input.txt").read_to_string()?;
 
-    let mut entries: Vec<String> = vec!();
-    for line in reader.lines() {
-        if let Ok(line) = line {
-            if line.trim().len() > 0 {
-                entries.push(String::from(line.trim()));
-            }
-        }
-    }
-    let mut life_support = LifeSupport::new();
-    for entry in entries.iter() {
-        if entry.trim().len() == 0 {
-            // skip any rows with no content
-            continue;
-        }
-        life_support.load_diagnostic(&entry.trim());
-    }
+    let report = match DiagnosticReport::parse(&input) {
+        Ok(report) => report,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
+    };
 
-    let oxygen = life_support.oxygen_rating();
-    let scrubber = life_support.scrubber_rating();
+    let oxygen = report.oxygen_rating();
+    let scrubber = report.scrubber_rating();
 
     println!("The submarine's diagnostics give it an oxygen rating of {} and a scrubber rating of {}", oxygen, scrubber);
-    println!("Multiplied together that gives {}", oxygen * scrubber);
+    match report.life_support_rating() {
+        Ok(product) => println!("Multiplied together that gives {}", product),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
+    }
 
     Ok(())
 }
@@ -1,13 +1,16 @@
+use aoc_common::Error;
+
+#[derive(Clone)]
 pub struct BingoBall {
     numbers: std::vec::IntoIter<u32>,
 }
 
 impl BingoBall {
-    pub fn new(seed_numbers: &str) -> BingoBall {
-        let numbers = parse_seed_numbers(seed_numbers).into_iter();
-        BingoBall {
+    pub fn new(seed_numbers: &str) -> Result<BingoBall, Error> {
+        let numbers = parse_seed_numbers(seed_numbers)?.into_iter();
+        Ok(BingoBall {
             numbers,
-        }
+        })
     }
 }
 
@@ -19,31 +22,35 @@ impl Iterator for BingoBall {
     }
 }
 
-fn parse_seed_numbers(seed_numbers: &str) -> Vec<u32> {
-    seed_numbers.split(",")
-    .map(|n| u32::from_str_radix(n, 10).unwrap())
-    .collect()
+fn parse_seed_numbers(seed_numbers: &str) -> Result<Vec<u32>, Error> {
+    aoc_common::parse_comma_separated(seed_numbers)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_common::ParseError;
     use super::*;
 
     #[test]
     fn test_parse_single_value() {
-        let numbers = parse_seed_numbers("13");
+        let numbers = parse_seed_numbers("13").unwrap();
         assert_eq!(vec![13], numbers);
     }
 
     #[test]
     fn test_parse_multiple_values() {
-        let numbers = parse_seed_numbers("1,17,44,98,27");
+        let numbers = parse_seed_numbers("1,17,44,98,27").unwrap();
         assert_eq!(vec![1,17,44,98,27], numbers);
     }
 
+    #[test]
+    fn test_parse_bad_value() {
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(4, "a number", "x")])), parse_seed_numbers("13,x"));
+    }
+
     #[test]
     fn test_bingo_ball() {
-        let mut ball = BingoBall::new("1,17,44,98,27");
+        let mut ball = BingoBall::new("1,17,44,98,27").unwrap();
         assert_eq!(Some(1), ball.next());
         assert_eq!(Some(17), ball.next());
         assert_eq!(Some(44), ball.next());
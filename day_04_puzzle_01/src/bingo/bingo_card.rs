@@ -1,107 +1,206 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+use aoc_common::{Checked, Error, ParseError};
+use crate::bingo::WinPattern;
 
+/// A bingo card of any number of rows and columns.
+///
+/// The card keeps the index of every number on it, along with a count of the marked squares in each
+/// set of squares its patterns can win with (every row and every column, in a standard game), so that
+/// calling a number only touches the counts of the square it marks.
+#[derive(Clone)]
 pub struct BingoCard {
-    size: usize,
+    rows: usize,
+    columns: usize,
     numbers: Vec<u32>,
-    matching_number_indicies: HashSet<usize>,
+    positions: HashMap<u32, usize>,
+    marked: Vec<bool>,
+    layout: Option<Arc<Layout>>,
+    hits: Vec<usize>,
+    winning_pattern: Option<WinPattern>,
+}
+
+/// The sets of squares a card of one size can win with, shared by every card of that size in a game.
+pub(crate) struct Layout {
+    rows: usize,
+    columns: usize,
+    patterns: Vec<WinPattern>,
+    /// The pattern each set of squares comes from and how many squares are in it.
+    masks: Vec<(usize, usize)>,
+    /// The sets of squares each square is in.
+    square_masks: Vec<Vec<usize>>,
+}
+
+impl Layout {
+    pub(crate) fn new(patterns: &[WinPattern], rows: usize, columns: usize) -> Layout {
+        let mut masks = vec![];
+        let mut square_masks = vec![vec![]; rows * columns];
+        for (pattern_index, pattern) in patterns.iter().enumerate() {
+            for mask in pattern.masks(rows, columns) {
+                for &square in mask.iter() {
+                    square_masks[square].push(masks.len());
+                }
+                masks.push((pattern_index, mask.len()));
+            }
+        }
+        Layout {
+            rows,
+            columns,
+            patterns: patterns.to_vec(),
+            masks,
+            square_masks,
+        }
+    }
+
+    pub(crate) fn size(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
 }
 
 impl BingoCard {
+    /// A square card, with as many rows as its first row has numbers.
     pub fn new() -> BingoCard {
+        BingoCard::with_size(0, 0)
+    }
+
+    pub fn with_size(rows: usize, columns: usize) -> BingoCard {
         BingoCard {
-            size: 0,
+            rows,
+            columns,
             numbers: vec!(),
-            matching_number_indicies: HashSet::new(),
+            positions: HashMap::new(),
+            marked: vec!(),
+            layout: None,
+            hits: vec!(),
+            winning_pattern: None,
         }
     }
 
-    pub fn load_row(&mut self, row_numbers: &str) {
-        let row = &mut parse_row_numbers(row_numbers);
-        if self.size == 0 {
-            self.size = row.len();
-        } else if self.size != row.len() {
-            panic!("Bingo card of size {} cannot take a row of size {}", self.size, row.len());
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Adds the next row of the card, which has to be as long as the first.
+    pub fn load_row(&mut self, row_numbers: &str) -> Result<(), Error> {
+        let row = parse_row_numbers(row_numbers)?;
+        if self.columns == 0 {
+            self.rows = row.len();
+            self.columns = row.len();
+            // any layout was for a card with no squares, so its patterns are laid out again for the card's size
+            if let Some(layout) = self.layout.take() {
+                self.set_layout(Arc::new(Layout::new(&layout.patterns, self.rows, self.columns)));
+            }
+        } else if self.columns != row.len() {
+            return Err(row_error(row_numbers, &format!("a row of {} numbers", self.columns)));
         } else if self.is_full() {
-            panic!("Bingo card is full ({} rows of length {}) and cannot take any more rows", self.size, self.size);
+            return Err(row_error(row_numbers, &format!("no more than {} rows", self.rows)));
+        }
+        for number in row {
+            self.positions.entry(number).or_insert(self.numbers.len());
+            self.numbers.push(number);
         }
-        self.numbers.append(row);
+        Ok(())
     }
 
     pub fn is_full(&self) -> bool {
-        if self.size == 0 {
+        if self.columns == 0 {
             false
         } else {
-            self.numbers.len() >= self.size * self.size
+            self.numbers.len() >= self.rows * self.columns
         }
     }
 
-    fn dabbed_square(&self, number: u32) -> Option<usize> {
-        for (i, n) in self.numbers.iter().enumerate() {
-            if *n == number {
-                return Some(i);
+    /// Wins with any of `patterns` rather than just rows and columns, checked in order.
+    pub fn with_patterns(mut self, patterns: &[WinPattern]) -> BingoCard {
+        self.set_layout(Arc::new(Layout::new(patterns, self.rows, self.columns)));
+        self
+    }
+
+    /// Uses the layout of a card of the same size, counting up any squares that are already marked.
+    pub(crate) fn set_layout(&mut self, layout: Arc<Layout>) {
+        self.marked.resize(self.rows * self.columns, false);
+        self.hits = vec![0; layout.masks.len()];
+        for (square, _) in self.marked.iter().enumerate().filter(|(_, &marked)| marked) {
+            for &mask in layout.square_masks[square].iter() {
+                self.hits[mask] += 1;
             }
         }
-        None
+        self.winning_pattern = (0..layout.masks.len())
+            .filter(|&mask| self.hits[mask] == layout.masks[mask].1)
+            .map(|mask| layout.masks[mask].0)
+            .min()
+            .map(|pattern| layout.patterns[pattern].clone());
+        self.layout = Some(layout);
     }
 
-    fn bingo(&self) -> bool {
-        if self.matching_number_indicies.len() < self.size {
-            false
-        } else {
-            // check for horizontals
-            'row: for row in 0..self.size {
-                for column in 0..self.size {
-                    let index = row * self.size + column;
-                    if !self.matching_number_indicies.contains(&index) {
-                        continue 'row;
-                    }
-                }
-                return true;
-            }
+    fn dabbed_square(&self, number: u32) -> Option<usize> {
+        self.positions.get(&number).copied()
+    }
 
-            // check for verticals
-            'column: for column in 0..self.size {
-                for row in 0..self.size {
-                    let index = row * self.size + column;
-                    if !self.matching_number_indicies.contains(&index) {
-                        continue 'column;
-                    }
-                }
-                return true;
-            }
+    /// Whether the card has won, with any pattern.
+    pub fn bingo(&self) -> bool {
+        self.winning_pattern.is_some()
+    }
 
-            false
-        }
+    /// The first pattern the card won with.
+    pub fn winning_pattern(&self) -> Option<&WinPattern> {
+        self.winning_pattern.as_ref()
     }
 
-    pub fn number_called(&mut self, number: u32) -> Option<u32> {
+    /// Marks the number, giving the card's score if it has won.
+    pub fn number_called(&mut self, number: u32) -> Result<Option<u32>, Error> {
+        if self.layout.is_none() {
+            self.set_layout(Arc::new(Layout::new(&WinPattern::standard(), self.rows, self.columns)));
+        }
         let dabbed_square = self.dabbed_square(number);
         if let Some(dabbed_square) = dabbed_square {
-            self.matching_number_indicies.insert(dabbed_square);
+            if !self.marked[dabbed_square] {
+                self.mark(dabbed_square);
+            }
 
             if self.bingo() {
-                let mut undabbed_numbers_sum = 0;
-                for i in 0..(self.size * self.size) {
-                    if !self.matching_number_indicies.contains(&i) {
-                        undabbed_numbers_sum += self.numbers[i];
-                    }
+                let mut undabbed_numbers_sum: u32 = 0;
+                for (n, _) in self.numbers.iter().zip(self.marked.iter()).filter(|(_, &marked)| !marked) {
+                    undabbed_numbers_sum = undabbed_numbers_sum.add_checked(*n, "sum of unmarked numbers")?;
                 }
-                Some(undabbed_numbers_sum * number)
+                Ok(Some(undabbed_numbers_sum.mul_checked(number, "sum of unmarked numbers * winning number")?))
             } else {
-                None
+                Ok(None)
             }
         } else {
             // cannot win if number wasn't dabbed (assuming player was paying attenting to the previous numbers)
-            None
+            Ok(None)
         }
     }
+
+    fn mark(&mut self, square: usize) {
+        self.marked[square] = true;
+        let layout = self.layout.as_ref().expect("A card has a layout before any squares are marked");
+        let mut completed = None;
+        for &mask in layout.square_masks[square].iter() {
+            self.hits[mask] += 1;
+            let (pattern, size) = layout.masks[mask];
+            if self.hits[mask] == size {
+                completed = Some(completed.map_or(pattern, |first: usize| first.min(pattern)));
+            }
+        }
+        if let (None, Some(pattern)) = (&self.winning_pattern, completed) {
+            self.winning_pattern = Some(layout.patterns[pattern].clone());
+        }
+    }
+}
+
+fn parse_row_numbers(row_numbers: &str) -> Result<Vec<u32>, Error> {
+    aoc_common::parse_whitespace_separated(row_numbers)
 }
 
-fn parse_row_numbers(row_numbers: &str) -> Vec<u32> {
-    row_numbers.split(" ")
-        .filter(|&s| !s.is_empty())
-        .map(|n| u32::from_str_radix(n, 10).unwrap())
-        .collect()
+fn row_error(row_numbers: &str, expected: &str) -> Error {
+    let column = row_numbers.chars().take_while(|c| c.is_whitespace()).count() + 1;
+    ParseError::new(column, expected, row_numbers.trim()).into()
 }
 
 #[cfg(test)]
@@ -110,58 +209,56 @@ mod tests {
 
     #[test]
     fn test_parse_row() {
-        let row = parse_row_numbers(" 2 13 14  8 74");
+        let row = parse_row_numbers(" 2 13 14  8 74").unwrap();
         assert_eq!(vec![2, 13, 14, 8, 74], row);
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(4, "a number", "x")])), parse_row_numbers(" 2 x"));
     }
 
     #[test]
     fn test_build_card() {
         let mut card = BingoCard::new();
         assert_eq!(false, card.is_full());
-        card.load_row("22 13 17 11  0");
+        card.load_row("22 13 17 11  0").unwrap();
         assert_eq!(false, card.is_full());
-        card.load_row(" 8  2 23  4 24");
+        card.load_row(" 8  2 23  4 24").unwrap();
         assert_eq!(false, card.is_full());
-        card.load_row("21  9 14 16  7");
+        card.load_row("21  9 14 16  7").unwrap();
         assert_eq!(false, card.is_full());
-        card.load_row(" 6 10  3 18  5");
+        card.load_row(" 6 10  3 18  5").unwrap();
         assert_eq!(false, card.is_full());
-        card.load_row(" 1 12 20 15 19");
+        card.load_row(" 1 12 20 15 19").unwrap();
         assert_eq!(true, card.is_full());
     }
 
     #[test]
-    #[should_panic(expected = "Bingo card of size 5 cannot take a row of size 3")]
     fn test_too_small() {
         let mut card = BingoCard::new();
-        card.load_row(" 1 16 31 46 61");
-        card.load_row(" 2 17 32");
+        card.load_row(" 1 16 31 46 61").unwrap();
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(2, "a row of 5 numbers", "2 17 32")])), card.load_row(" 2 17 32"));
     }
 
     #[test]
-    #[should_panic(expected = "Bingo card of size 3 cannot take a row of size 5")]
     fn test_too_big() {
         let mut card = BingoCard::new();
-        card.load_row(" 2 17 32");
-        card.load_row(" 1 16 31 46 61");
+        card.load_row(" 2 17 32").unwrap();
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(2, "a row of 3 numbers", "1 16 31 46 61")])), card.load_row(" 1 16 31 46 61"));
     }
 
     #[test]
-    #[should_panic(expected = "Bingo card is full (3 rows of length 3) and cannot take any more rows")]
     fn test_card_already_full() {
         let mut card = BingoCard::new();
-        card.load_row(" 1 16 31");
-        card.load_row(" 7 22 37");
-        card.load_row("15 30 45");
-        card.load_row("16 21 46");
+        card.load_row(" 1 16 31").unwrap();
+        card.load_row(" 7 22 37").unwrap();
+        card.load_row("15 30 45").unwrap();
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(1, "no more than 3 rows", "16 21 46")])), card.load_row("16 21 46"));
     }
 
     #[test]
     fn test_dabbing_squares() {
         let mut card = BingoCard::new();
-        card.load_row(" 1 16 31");
-        card.load_row(" 7 22 37");
-        card.load_row("15 30 45");
+        card.load_row(" 1 16 31").unwrap();
+        card.load_row(" 7 22 37").unwrap();
+        card.load_row("15 30 45").unwrap();
 
         assert_eq!(None, card.dabbed_square(2));
         assert_eq!(None, card.dabbed_square(5));
@@ -172,18 +269,94 @@ mod tests {
     #[test]
     fn test_bingo() {
         let mut card = BingoCard::new();
-        card.load_row(" 1 16 31");
-        card.load_row(" 7 22 37");
-        card.load_row("15 30 45");
-
-        assert_eq!(None, card.number_called(5));
-        assert_eq!(None, card.number_called(10));
-        assert_eq!(None, card.number_called(15));
-        assert_eq!(None, card.number_called(20));
-        assert_eq!(None, card.number_called(25));
-        assert_eq!(None, card.number_called(30));
-        assert_eq!(None, card.number_called(35));
-        assert_eq!(None, card.number_called(40));
-        assert_eq!(Some(5130), card.number_called(45));
-    }
-}
\ No newline at end of file
+        card.load_row(" 1 16 31").unwrap();
+        card.load_row(" 7 22 37").unwrap();
+        card.load_row("15 30 45").unwrap();
+
+        assert_eq!(Ok(None), card.number_called(5));
+        assert_eq!(Ok(None), card.number_called(10));
+        assert_eq!(Ok(None), card.number_called(15));
+        assert_eq!(Ok(None), card.number_called(20));
+        assert_eq!(Ok(None), card.number_called(25));
+        assert_eq!(Ok(None), card.number_called(30));
+        assert_eq!(Ok(None), card.number_called(35));
+        assert_eq!(Ok(None), card.number_called(40));
+        assert_eq!(Ok(Some(5130)), card.number_called(45));
+        assert_eq!(Some(&WinPattern::Row), card.winning_pattern());
+    }
+
+    #[test]
+    fn test_patterns() {
+        let mut card = BingoCard::new();
+        card.load_row(" 1 16 31").unwrap();
+        card.load_row(" 7 22 37").unwrap();
+        card.load_row("15 30 45").unwrap();
+        let mut card = card.with_patterns(&[WinPattern::Diagonal, WinPattern::FourCorners]);
+
+        // a full row isn't a win with these patterns
+        assert_eq!(Ok(None), card.number_called(7));
+        assert_eq!(Ok(None), card.number_called(22));
+        assert_eq!(Ok(None), card.number_called(37));
+        assert!(!card.bingo());
+        assert_eq!(Ok(None), card.number_called(1));
+        assert_eq!(Ok(None), card.number_called(31));
+        assert_eq!(Ok(Some(91 * 15)), card.number_called(15));
+        assert_eq!(Some(&WinPattern::Diagonal), card.winning_pattern());
+    }
+
+    #[test]
+    fn test_patterns_before_rows() {
+        // the card's size isn't known until its first row, but the patterns are kept for it
+        let mut card = BingoCard::new().with_patterns(&[WinPattern::Diagonal]);
+        card.load_row(" 1 16 31").unwrap();
+        card.load_row(" 7 22 37").unwrap();
+        card.load_row("15 30 45").unwrap();
+
+        assert_eq!(Ok(None), card.number_called(7));
+        assert_eq!(Ok(None), card.number_called(22));
+        assert_eq!(Ok(None), card.number_called(37));
+        assert!(!card.bingo());
+        assert_eq!(Ok(None), card.number_called(1));
+        assert_eq!(Ok(Some(92 * 45)), card.number_called(45));
+        assert_eq!(Some(&WinPattern::Diagonal), card.winning_pattern());
+    }
+
+    #[test]
+    fn test_score_overflow() {
+        let mut card = BingoCard::new();
+        card.load_row("4000000000 1").unwrap();
+        card.load_row("4000000000 2").unwrap();
+
+        assert_eq!(Ok(None), card.number_called(1));
+        assert_eq!(Err(Error::Overflow(String::from("sum of unmarked numbers"))), card.number_called(2));
+    }
+
+    #[test]
+    fn test_rectangular_card() {
+        let mut card = BingoCard::with_size(2, 4);
+        card.load_row(" 1  2  3  4").unwrap();
+        assert!(!card.is_full());
+        card.load_row(" 5  6  7  8").unwrap();
+        assert!(card.is_full());
+
+        assert_eq!(Ok(None), card.number_called(3));
+        assert_eq!(Ok(None), card.number_called(3));
+        assert_eq!(Some(2), card.dabbed_square(3));
+        assert_eq!(Ok(Some(26 * 7)), card.number_called(7));
+        assert_eq!(Some(&WinPattern::Column), card.winning_pattern());
+    }
+
+    #[test]
+    fn test_patterns_after_marking() {
+        let mut card = BingoCard::new();
+        card.load_row("1 2").unwrap();
+        card.load_row("3 4").unwrap();
+        assert_eq!(Ok(None), card.number_called(1));
+        assert_eq!(Ok(None), card.number_called(4));
+
+        // the squares already marked count towards the new patterns
+        let mut card = card.with_patterns(&[WinPattern::Diagonal]);
+        assert!(card.bingo());
+        assert_eq!(Ok(Some(3 * 2)), card.number_called(2));
+    }
+}
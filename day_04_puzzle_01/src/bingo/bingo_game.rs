@@ -1,9 +1,15 @@
-use crate::bingo::bingo_ball::BingoBall;
-use crate::bingo::bingo_card::BingoCard;
+use std::sync::Arc;
+use aoc_common::{Json, Error};
+use crate::bingo::{BingoBall, WinPattern};
+use crate::bingo::bingo_card::{BingoCard, Layout};
 
+#[derive(Clone)]
 pub struct BingoGame {
     ball: Option<BingoBall>,
     cards: Vec<BingoCard>,
+    patterns: Vec<WinPattern>,
+    // one for each size of card in the game
+    layouts: Vec<Arc<Layout>>,
 }
 
 impl BingoGame {
@@ -11,35 +17,155 @@ impl BingoGame {
         BingoGame {
             ball: None,
             cards: vec!(),
+            patterns: WinPattern::standard(),
+            layouts: vec!(),
         }
     }
 
+    /// Plays with cards winning on any of `patterns` rather than just rows and columns, checked in order.
+    pub fn with_patterns(self, patterns: Vec<WinPattern>) -> BingoGame {
+        let mut game = BingoGame {
+            patterns,
+            layouts: vec!(),
+            ..self
+        };
+        let mut cards = std::mem::take(&mut game.cards);
+        for card in cards.iter_mut() {
+            game.set_layout(card);
+        }
+        game.cards = cards;
+        game
+    }
+
+    pub fn patterns(&self) -> &[WinPattern] {
+        &self.patterns
+    }
+
+    pub fn cards(&self) -> &[BingoCard] {
+        &self.cards
+    }
+
     pub fn setup_ball(&mut self, ball: BingoBall) {
         self.ball = Some(ball);
     }
 
-    pub fn add_card(&mut self, card: BingoCard) {
+    pub fn add_card(&mut self, mut card: BingoCard) {
+        self.set_layout(&mut card);
         self.cards.push(card);
     }
 
-    pub fn play_game(&mut self) -> Option<Vec<(u32, usize, u32)>> {
+    fn set_layout(&mut self, card: &mut BingoCard) {
+        let size = (card.rows(), card.columns());
+        let layout = match self.layouts.iter().find(|layout| layout.size() == size) {
+            Some(layout) => layout.clone(),
+            None => {
+                let layout = Arc::new(Layout::new(&self.patterns, size.0, size.1));
+                self.layouts.push(layout.clone());
+                layout
+            },
+        };
+        card.set_layout(layout);
+    }
+
+    /// Calls numbers until at least one card has a bingo, giving every card that won on that number.
+    pub fn play_game(&mut self) -> Result<Option<Vec<Winner>>, Error> {
         if let Some(ball) = &mut self.ball {
             for number in ball {
                 let mut winners = vec!();
 
                 for (i, card) in self.cards.iter_mut().enumerate() {
-                    if let Some(score) = card.number_called(number) {
-                        winners.push((number, i, score));
+                    if let Some(score) = card.number_called(number)? {
+                        let pattern = card.winning_pattern().cloned().expect("A card with a score has won with a pattern");
+                        winners.push(Winner::new(number, i, score, pattern));
                     }
                 }
 
                 if winners.len() > 0 {
-                    return Some(winners);
+                    return Ok(Some(winners));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Plays until the ball runs out, giving every card's win in the order they came. Every card has
+    /// to win, as there is no last winner otherwise.
+    pub fn everyones_a_winner(&mut self) -> Result<Option<Vec<Winner>>, Error> {
+        if let Some(ball) = &mut self.ball {
+            let mut winners = vec!();
+
+            for number in ball {
+                for (i, card) in self.cards.iter_mut().enumerate() {
+                    if card.bingo() {
+                        // we don't allow multiple winners
+                        continue;
+                    }
+                    if let Some(score) = card.number_called(number)? {
+                        let pattern = card.winning_pattern().cloned().expect("A card with a score has won with a pattern");
+                        winners.push(Winner::new(number, i, score, pattern));
+                    }
                 }
             }
+
+            if winners.len() < self.cards.len() {
+                return Err(Error::NoSolution(format!(
+                    "Not everyone wins! After running through all the numbers in the ball {} of the {} cards still don't have a bingo.",
+                    self.cards.len() - winners.len(),
+                    self.cards.len()
+                )));
+            }
+
+            if winners.len() > 0 {
+                return Ok(Some(winners));
+            }
         }
 
-        None
+        Ok(None)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Winner {
+    winning_number: u32,
+    board_index: usize,
+    score: u32,
+    pattern: WinPattern,
+}
+
+impl Winner {
+    pub fn new(winning_number: u32, board_index: usize, score: u32, pattern: WinPattern) -> Winner {
+        Winner {
+            winning_number,
+            board_index,
+            score,
+            pattern,
+        }
+    }
+
+    pub fn winning_number(&self) -> u32 {
+        self.winning_number
+    }
+
+    pub fn board_index(&self) -> usize {
+        self.board_index
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    /// The pattern the card won with.
+    pub fn pattern(&self) -> &WinPattern {
+        &self.pattern
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::object()
+            .with("winning_number", self.winning_number)
+            .with("board_index", self.board_index)
+            .with("score", self.score)
+            .with("pattern", self.pattern.name())
     }
 }
 
@@ -47,47 +173,85 @@ impl BingoGame {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_game() {
+    fn example_game() -> BingoGame {
         let mut game = BingoGame::new();
 
-        let ball = BingoBall::new("7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1");
+        let ball = BingoBall::new("7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1").unwrap();
 
         game.setup_ball(ball);
 
         let mut card = BingoCard::new();
-        card.load_row("22 13 17 11  0");
-        card.load_row(" 8  2 23  4 24");
-        card.load_row("21  9 14 16  7");
-        card.load_row(" 6 10  3 18  5");
-        card.load_row(" 1 12 20 15 19");
+        card.load_row("22 13 17 11  0").unwrap();
+        card.load_row(" 8  2 23  4 24").unwrap();
+        card.load_row("21  9 14 16  7").unwrap();
+        card.load_row(" 6 10  3 18  5").unwrap();
+        card.load_row(" 1 12 20 15 19").unwrap();
         assert_eq!(true, card.is_full());
 
         game.add_card(card);
 
         let mut card = BingoCard::new();
-        card.load_row(" 3 15  0  2 22");
-        card.load_row(" 9 18 13 17  5");
-        card.load_row("19  8  7 25 23");
-        card.load_row("20 11 10 24  4");
-        card.load_row("14 21 16 12  6");
+        card.load_row(" 3 15  0  2 22").unwrap();
+        card.load_row(" 9 18 13 17  5").unwrap();
+        card.load_row("19  8  7 25 23").unwrap();
+        card.load_row("20 11 10 24  4").unwrap();
+        card.load_row("14 21 16 12  6").unwrap();
         assert_eq!(true, card.is_full());
 
         game.add_card(card);
 
         let mut card = BingoCard::new();
-        card.load_row("14 21 17 24  4");
-        card.load_row("10 16 15  9 19");
-        card.load_row("18  8 23 26 20");
-        card.load_row("22 11 13  6  5");
-        card.load_row(" 2  0 12  3  7");
+        card.load_row("14 21 17 24  4").unwrap();
+        card.load_row("10 16 15  9 19").unwrap();
+        card.load_row("18  8 23 26 20").unwrap();
+        card.load_row("22 11 13  6  5").unwrap();
+        card.load_row(" 2  0 12  3  7").unwrap();
         assert_eq!(true, card.is_full());
 
         game.add_card(card);
 
-        let winners = game.play_game();
+        game
+    }
 
-        assert_eq!(Some(vec![(24, 2, 4512)]), winners);
+    #[test]
+    fn test_play_game() {
+        let winners = example_game().play_game();
 
+        assert_eq!(Ok(Some(vec![Winner::new(24, 2, 4512, WinPattern::Row)])), winners);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_game() {
+        let winners = example_game().everyones_a_winner();
+
+        assert_eq!(
+            Ok(Some(vec![
+                Winner::new(24, 2, 4512, WinPattern::Row),
+                Winner::new(16, 0, 2192, WinPattern::Row),
+                Winner::new(13, 1, 1924, WinPattern::Column),
+            ])),
+            winners
+        );
+
+    }
+
+    #[test]
+    fn test_not_everyone_wins() {
+        // the ball runs out just after the first card gets its bingo
+        let mut game = example_game();
+        game.setup_ball(BingoBall::new("7,4,9,5,11,17,23,2,0,14,21,24").unwrap());
+
+        assert_eq!(
+            Err(Error::NoSolution(String::from("Not everyone wins! After running through all the numbers in the ball 2 of the 3 cards still don't have a bingo."))),
+            game.everyones_a_winner()
+        );
+    }
+
+    #[test]
+    fn test_patterns() {
+        let mut game = example_game().with_patterns(vec![WinPattern::FourCorners, WinPattern::Diagonal, WinPattern::X]);
+        let winners = game.everyones_a_winner().unwrap().unwrap();
+        let patterns: Vec<(u32, usize, &str)> = winners.iter().map(|w| (w.winning_number(), w.board_index(), w.pattern().name())).collect();
+        assert_eq!(vec![(2, 2, "diagonal"), (22, 1, "diagonal"), (19, 0, "diagonal")], patterns);
+    }
+}
@@ -1,7 +1,9 @@
-pub use self::bingo_game::BingoGame;
+pub use self::bingo_game::{BingoGame, Winner};
 pub use self::bingo_card::BingoCard;
 pub use self::bingo_ball::BingoBall;
+pub use self::win_pattern::WinPattern;
 
 mod bingo_ball;
 mod bingo_card;
-mod bingo_game;
\ No newline at end of file
+mod bingo_game;
+mod win_pattern;
\ No newline at end of file
@@ -0,0 +1,127 @@
+use std::fmt::{self, Display};
+
+/// A way of marking off squares that wins a bingo card.
+///
+/// Every pattern gives the sets of squares a card can win with, as the indexes of the squares with
+/// the rows laid end to end. A card wins once every square in any one of those sets is marked.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WinPattern {
+    /// Every square in any one row.
+    Row,
+    /// Every square in any one column.
+    Column,
+    /// Every square along either diagonal, which only square cards have.
+    Diagonal,
+    /// The square in each corner.
+    FourCorners,
+    /// Every square along both diagonals at once, which only square cards have.
+    X,
+    /// Every square on the card.
+    Blackout,
+    /// The given squares as (row, column), which only wins on cards that have all of them.
+    Custom { name: String, squares: Vec<(usize, usize)> },
+}
+
+impl WinPattern {
+    /// The rows and columns that win a standard game of bingo.
+    pub fn standard() -> Vec<WinPattern> {
+        vec![WinPattern::Row, WinPattern::Column]
+    }
+
+    pub fn custom(name: &str, squares: &[(usize, usize)]) -> WinPattern {
+        if squares.is_empty() {
+            panic!("Expected custom pattern '{}' to have at least one square", name);
+        }
+        WinPattern::Custom {
+            name: String::from(name),
+            squares: squares.to_vec(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            WinPattern::Row => "row",
+            WinPattern::Column => "column",
+            WinPattern::Diagonal => "diagonal",
+            WinPattern::FourCorners => "four corners",
+            WinPattern::X => "x",
+            WinPattern::Blackout => "blackout",
+            WinPattern::Custom { name, .. } => name,
+        }
+    }
+
+    /// The sets of squares that win with this pattern on a card of `rows` by `columns`, which is
+    /// none at all when the card doesn't have the squares the pattern needs.
+    pub fn masks(&self, rows: usize, columns: usize) -> Vec<Vec<usize>> {
+        let index = |row: usize, column: usize| row * columns + column;
+        let square = rows == columns;
+        let diagonals = || {
+            let down: Vec<usize> = (0..rows).map(|i| index(i, i)).collect();
+            let up: Vec<usize> = (0..rows).map(|i| index(rows - 1 - i, i)).collect();
+            (down, up)
+        };
+        match self {
+            _ if rows == 0 || columns == 0 => vec![],
+            WinPattern::Row => (0..rows).map(|row| (0..columns).map(|column| index(row, column)).collect()).collect(),
+            WinPattern::Column => (0..columns).map(|column| (0..rows).map(|row| index(row, column)).collect()).collect(),
+            WinPattern::Diagonal if square => {
+                let (down, up) = diagonals();
+                vec![down, up]
+            },
+            WinPattern::X if square => {
+                let (mut both, up) = diagonals();
+                both.extend(up);
+                // the middle square of an odd sized card is on both diagonals
+                both.sort_unstable();
+                both.dedup();
+                vec![both]
+            },
+            WinPattern::Diagonal | WinPattern::X => vec![],
+            WinPattern::FourCorners => {
+                let mut corners = vec![index(0, 0), index(0, columns - 1), index(rows - 1, 0), index(rows - 1, columns - 1)];
+                corners.sort_unstable();
+                corners.dedup();
+                vec![corners]
+            },
+            WinPattern::Blackout => vec![(0..rows * columns).collect()],
+            WinPattern::Custom { squares, .. } => {
+                if squares.iter().all(|&(row, column)| row < rows && column < columns) {
+                    let mut mask: Vec<usize> = squares.iter().map(|&(row, column)| index(row, column)).collect();
+                    mask.sort_unstable();
+                    mask.dedup();
+                    vec![mask]
+                } else {
+                    vec![]
+                }
+            },
+        }
+    }
+}
+
+impl Display for WinPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masks() {
+        assert_eq!(vec![vec![0, 1, 2], vec![3, 4, 5]], WinPattern::Row.masks(2, 3));
+        assert_eq!(vec![vec![0, 3], vec![1, 4], vec![2, 5]], WinPattern::Column.masks(2, 3));
+        assert_eq!(vec![vec![0, 4, 8], vec![6, 4, 2]], WinPattern::Diagonal.masks(3, 3));
+        assert_eq!(vec![vec![0, 2, 4, 6, 8]], WinPattern::X.masks(3, 3));
+        assert_eq!(vec![vec![0, 3, 5, 6, 9, 10, 12, 15]], WinPattern::X.masks(4, 4));
+        assert_eq!(Vec::<Vec<usize>>::new(), WinPattern::Diagonal.masks(2, 3));
+        assert_eq!(vec![vec![0, 2, 3, 5]], WinPattern::FourCorners.masks(2, 3));
+        assert_eq!(vec![vec![0, 1, 2, 3]], WinPattern::Blackout.masks(2, 2));
+
+        let plus = WinPattern::custom("plus", &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]);
+        assert_eq!(vec![vec![1, 3, 4, 5, 7]], plus.masks(3, 3));
+        assert_eq!(Vec::<Vec<usize>>::new(), plus.masks(2, 3));
+        assert_eq!("plus", plus.to_string());
+    }
+}
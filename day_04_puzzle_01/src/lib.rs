@@ -0,0 +1,60 @@
+pub mod bingo;
+
+use aoc_common::{Puzzle, Answer, Error, Json};
+use bingo::{BingoGame, BingoBall, BingoCard};
+
+/// Reads the numbers to call from the first line, then square cards from the rest, with a new card
+/// starting once the last one is full. Every bad line is reported, not just the first.
+pub fn load_game(input: &str) -> Result<BingoGame, Error> {
+    let mut game = BingoGame::new();
+
+    let mut current_card = None;
+    let mut errors = vec![];
+
+    for (number, line) in aoc_common::numbered_lines(input) {
+        let loaded = if number == 1 {
+            BingoBall::new(line.trim()).map(|ball| game.setup_ball(ball))
+        } else {
+            let card = current_card.get_or_insert_with(BingoCard::new);
+            let loaded = card.load_row(line.trim());
+            if card.is_full() {
+                game.add_card(current_card.take().unwrap());
+            }
+            loaded
+        };
+        match loaded.map_err(|e| e.on_line(number)) {
+            Ok(()) => {},
+            Err(Error::Parse(line_errors)) => errors.extend(line_errors),
+            Err(error) => return Err(error),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(game)
+    } else {
+        Err(Error::Parse(errors))
+    }
+}
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    type Input = BingoGame;
+
+    fn parse(input: &str) -> Result<BingoGame, Error> {
+        load_game(input)
+    }
+
+    fn part1(game: &BingoGame) -> Result<Answer, Error> {
+        match game.clone().play_game()? {
+            Some(winners) => {
+                let details: Vec<Json> = winners
+                    .iter()
+                    .map(|w| Json::object().with("winning_number", w.winning_number()).with("board_index", w.board_index()).with("score", w.score()))
+                    .collect();
+                Ok(Answer::from(winners[0].score()).with_detail("winners", details))
+            },
+            None => Err(Error::NoSolution(String::from("We ran out of numbers before anyone got a Bingo!"))),
+        }
+    }
+}
@@ -1,47 +1,26 @@
-mod bingo;
+use aoc_common::InputSource;
 
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-
-use crate::bingo::{BingoGame, BingoBall, BingoCard};
+use day_04_puzzle_01::load_game;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_04_puzzle_01/input.txt")?;
-    let reader = BufReader::new(file);
-
-    let mut game = BingoGame::new();
+    let input = InputSource::from_args("./day_04_puzzle_01/input.txt").read_to_string()?;
 
-    let mut current_card = None;
+    let mut game = load_game(&input).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    });
 
-    for (i, line) in reader.lines().enumerate() {
-        if let Ok(line) = line {
-            if line.trim().len() == 0 {
-                // skip empty lines
-                continue;
-            }
-            if i == 0 {
-                let ball = BingoBall::new(line.trim());
-                game.setup_ball(ball);
-            } else {
-                if current_card.is_none() {
-                    current_card = Some(BingoCard::new());
-                }
-                if let Some(c) = &mut current_card {
-                    c.load_row(line.trim());
-                    if c.is_full() {
-                        game.add_card(current_card.take().unwrap());
-                    }
-                }
+    match game.play_game() {
+        Ok(Some(winners)) => {
+            for winner in winners.iter() {
+                println!("Calling number {} gave board {} a Bingo with a score of {}", winner.winning_number(), winner.board_index(), winner.score());
             }
-        }
-    }
-
-    if let Some(winners) = game.play_game() {
-        for winner in winners.iter() {
-            println!("Calling number {} gave board {} a Bingo with a score of {}", winner.0, winner.1, winner.2);
-        }
-    } else {
-        println!("We ran out of numbers before anyone got a Bingo!");
+        },
+        Ok(None) => println!("We ran out of numbers before anyone got a Bingo!"),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
     Ok(())
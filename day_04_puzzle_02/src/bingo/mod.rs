@@ -1,7 +0,0 @@
-pub use self::bingo_game::BingoGame;
-pub use self::bingo_card::BingoCard;
-pub use self::bingo_ball::BingoBall;
-
-mod bingo_ball;
-mod bingo_card;
-mod bingo_game;
\ No newline at end of file
@@ -0,0 +1,100 @@
+pub use day_04_puzzle_01::bingo;
+
+use aoc_common::{Puzzle, Answer, Error, Json, ParseError};
+use bingo::{BingoGame, BingoBall, BingoCard, Winner};
+
+/// Reads the numbers to call from the first line, then a card from each run of lines up to a blank
+/// line. Cards can have any number of rows, and as many columns as their first row has numbers.
+/// Every bad line is reported, not just the first.
+///
+/// As a card can be any height, only the blank lines tell the cards apart - so the numbers have to be
+/// followed by one too, or input with no blank lines at all would be read as one enormous card.
+pub fn load_game(input: &str) -> Result<BingoGame, Error> {
+    let mut game = BingoGame::new();
+    let mut errors = vec!();
+
+    let mut lines = input.lines().map(str::trim).enumerate().map(|(i, line)| (i + 1, line)).peekable();
+    if let Some((number, ball)) = lines.next().filter(|(_, line)| !line.is_empty()) {
+        let loaded = BingoBall::new(ball).map(|ball| game.setup_ball(ball));
+        keep_parse_errors(&mut errors, loaded, number)?;
+    }
+    if let Some(&(number, line)) = lines.peek().filter(|(_, line)| !line.is_empty()) {
+        errors.push(ParseError::new(1, "a blank line before the first card", line).on_line(number));
+    }
+
+    let mut rows = vec!();
+    // a blank line on the end finishes the last card
+    for (number, line) in lines.chain(std::iter::once((0, ""))) {
+        if !line.is_empty() {
+            rows.push((number, line));
+        } else if !rows.is_empty() {
+            let mut card = BingoCard::with_size(rows.len(), rows[0].1.split_whitespace().count());
+            for (number, row) in rows.drain(..) {
+                keep_parse_errors(&mut errors, card.load_row(row), number)?;
+            }
+            game.add_card(card);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(game)
+    } else {
+        Err(Error::Parse(errors))
+    }
+}
+
+/// Moves any parse errors onto the line and keeps them to report together, stopping at any other error.
+fn keep_parse_errors(errors: &mut Vec<ParseError>, result: Result<(), Error>, line: usize) -> Result<(), Error> {
+    match result.map_err(|e| e.on_line(line)) {
+        Err(Error::Parse(line_errors)) => {
+            errors.extend(line_errors);
+            Ok(())
+        },
+        result => result,
+    }
+}
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    type Input = BingoGame;
+
+    fn parse(input: &str) -> Result<BingoGame, Error> {
+        load_game(input)
+    }
+
+    fn part2(game: &BingoGame) -> Result<Answer, Error> {
+        match game.clone().everyones_a_winner()? {
+            Some(winners) => {
+                let details: Vec<Json> = winners.iter().map(Winner::to_json).collect();
+                Ok(Answer::from(winners.last().unwrap().score()).with_detail("winners", details))
+            },
+            None => Err(Error::NoSolution(String::from("We ran out of numbers before anyone got a Bingo!"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_game() {
+        let game = load_game("7,4,9\n\n 1 2\n 3 4\n\n5 6 7\n8 9 10\n").unwrap();
+        assert_eq!(2, game.cards().len());
+        assert_eq!(3, game.cards()[1].columns());
+    }
+
+    #[test]
+    fn test_load_game_errors() {
+        // without blank lines the cards can't be told apart
+        let errors = vec![ParseError::new(1, "a blank line before the first card", "1 2").on_line(2)];
+        assert_eq!(Err(Error::Parse(errors)), load_game("7,4,9\n1 2\n3 4\n5 6\n7 8\n").map(|game| game.cards().len()));
+
+        let errors = vec![
+            ParseError::new(1, "a row of 2 numbers", "3 4 5").on_line(4),
+            ParseError::new(3, "a number", "x").on_line(7),
+        ];
+        assert_eq!(Err(Error::Parse(errors)), load_game("7,4,9\n\n1 2\n3 4 5\n\n5 6\n7 x\n").map(|game| game.cards().len()));
+    }
+}
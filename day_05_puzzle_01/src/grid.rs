@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use aoc_common::Pool;
 use crate::line::{self, Line, Point};
 
 pub struct Grid {
@@ -17,30 +18,21 @@ impl Grid {
     }
 
     pub fn overlaps(&self) -> HashSet<Point> {
-        let mut overlaps = HashSet::new();
-
         let lines = self.lines.iter().collect::<Vec<&Line>>();
 
-        let mut compare_counter = 0;
-        for outer in 0..(lines.len()-1) {
+        // every line is compared with the lines after it, and each of those rounds can run on its own thread
+        let outers = (0..lines.len().saturating_sub(1)).collect::<Vec<usize>>();
+        let rounds = Pool::configured().map(&outers, |&outer| {
+            let mut points = vec![];
             for inner in (outer+1)..lines.len() {
                 let a = lines[outer];
                 let b = lines[inner];
-                compare_counter += 1;
-                let points = line::intersections_optimized(a, b);
-                if points.len() > 0 {
-                    for p in points.into_iter() {
-                        overlaps.insert(p);
-                    }
-                }
+                points.extend(line::intersections_specialized(a, b));
             }
-        }
-
-        let expected_comparisons = lines.len() * (lines.len() - 1) / 2;
-
-        println!("Compared {} pairs of lines together (expected {}) from a total of {}", compare_counter, expected_comparisons, lines.len());
+            points
+        });
 
-        overlaps
+        rounds.into_iter().flatten().collect()
     }
 }
 
@@ -106,7 +98,7 @@ mod tests {
         let lines = input.split("\n").filter(|l| !l.trim().is_empty()).collect::<Vec<&str>>();
 
         for line in lines.iter() {
-            let l = Line::from(line);
+            let l = Line::from(line).unwrap();
             if l.horizontal() || l.vertical() {
                 grid.add_line(l);
             }
@@ -121,6 +113,44 @@ mod tests {
         assert_eq!(true, overlaps.contains(&Point::new(2, 9)));
     }
 
+    #[test]
+    fn test_demo_diagonals() {
+        let mut grid = Grid::new();
+        let input = r"
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+        ";
+        let lines = input.split("\n").filter(|l| !l.trim().is_empty()).collect::<Vec<&str>>();
+
+        for line in lines.iter() {
+            let l = Line::from(line).unwrap();
+            grid.add_line(l);
+        }
+
+        let overlaps = grid.overlaps();
+        assert_eq!(12, overlaps.len());
+        assert_eq!(true, overlaps.contains(&Point::new(7, 1)));
+        assert_eq!(true, overlaps.contains(&Point::new(2, 2)));
+        assert_eq!(true, overlaps.contains(&Point::new(5, 3)));
+        assert_eq!(true, overlaps.contains(&Point::new(7, 3)));
+        assert_eq!(true, overlaps.contains(&Point::new(3, 4)));
+        assert_eq!(true, overlaps.contains(&Point::new(4, 4)));
+        assert_eq!(true, overlaps.contains(&Point::new(6, 4)));
+        assert_eq!(true, overlaps.contains(&Point::new(7, 4)));
+        assert_eq!(true, overlaps.contains(&Point::new(5, 5)));
+        assert_eq!(true, overlaps.contains(&Point::new(0, 9)));
+        assert_eq!(true, overlaps.contains(&Point::new(2, 9)));
+        assert_eq!(true, overlaps.contains(&Point::new(2, 9)));
+    }
+
     #[test]
     fn test_sample() {
         let file = File::open("./input.txt").unwrap();
@@ -133,7 +163,7 @@ mod tests {
                     // skip any rows with no content
                     continue;
                 }
-                let l = Line::from(entry.trim());
+                let l = Line::from(&entry).unwrap();
                 if l.horizontal() || l.vertical() {
                     grid.add_line(l);
                 }
@@ -0,0 +1,34 @@
+pub mod line;
+pub mod grid;
+pub mod geometry;
+
+use aoc_common::{Puzzle, Answer, Error, Picture, Raster};
+use grid::Grid;
+use line::Line;
+
+pub struct Day05;
+
+impl Puzzle for Day05 {
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Vec<Line>, Error> {
+        aoc_common::parse_lines(input, Line::from)
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<Answer, Error> {
+        let mut grid = Grid::new();
+        for l in lines.iter().filter(|l| l.horizontal() || l.vertical()) {
+            grid.add_line(l.clone());
+        }
+
+        Ok(grid.overlaps().len().into())
+    }
+}
+
+/// The horizontal and vertical vent lines as a heat map, brighter where more of them overlap.
+pub fn export(input: &str) -> Result<Picture, Error> {
+    let lines = Day05::parse(input)?;
+    let points: Vec<_> = lines.iter().filter(|l| l.horizontal() || l.vertical()).flat_map(|l| l.points()).collect();
+
+    Ok(Picture::Raster(Raster::heat_map(&points)))
+}
@@ -1,36 +1,16 @@
-#[macro_use]
-extern crate lazy_static;
-
-mod line;
-mod grid;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use crate::grid::Grid;
-use crate::line::Line;
+use aoc_common::InputSource;
+use day_05_puzzle_01::Day05;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_05_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
-
-    let mut grid = Grid::new();
-    for line in lines {
-        if let Ok(entry) = line {
-            if entry.trim().len() == 0 {
-                // skip any rows with no content
-                continue;
-            }
-            let l = Line::from(entry.trim());
-            if l.horizontal() || l.vertical() {
-                grid.add_line(l);
-            }
-        }
+    let input = InputSource::from_args("./day_05_puzzle_01/input.txt").read_to_string()?;
+
+    match aoc_common::solve_part1::<Day05>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
-    let overlaps = grid.overlaps();
-
-    println!("With all of the lines there are {} points where they overlap", overlaps.len());
-
     Ok(())
-}
-
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+pub use day_05_puzzle_01::{geometry, grid, line};
+
+use aoc_common::{Puzzle, Answer, Error, Picture, Raster};
+use grid::Grid;
+use line::Line;
+
+pub struct Day05;
+
+impl Puzzle for Day05 {
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Vec<Line>, Error> {
+        aoc_common::parse_lines(input, Line::from)
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<Answer, Error> {
+        let mut grid = Grid::new();
+        for l in lines.iter() {
+            grid.add_line(l.clone());
+        }
+
+        Ok(grid.overlaps().len().into())
+    }
+}
+
+/// The vent lines as a heat map, brighter where more of them overlap.
+pub fn export(input: &str) -> Result<Picture, Error> {
+    let lines = Day05::parse(input)?;
+    let points: Vec<_> = lines.iter().flat_map(|l| l.points()).collect();
+
+    Ok(Picture::Raster(Raster::heat_map(&points)))
+}
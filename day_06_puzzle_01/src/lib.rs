@@ -0,0 +1,30 @@
+pub mod school_of_fish;
+
+use aoc_common::{Puzzle, Answer, Error};
+use school_of_fish::SchoolOfFish;
+
+fn school_size_after(school: &SchoolOfFish, days: u32) -> u64 {
+    let mut school = school.clone();
+    for _ in 0..days {
+        school.next_day();
+    }
+    school.len()
+}
+
+pub struct Day06;
+
+impl Puzzle for Day06 {
+    type Input = SchoolOfFish;
+
+    fn parse(input: &str) -> Result<SchoolOfFish, Error> {
+        SchoolOfFish::from(input.trim())
+    }
+
+    fn part1(school: &SchoolOfFish) -> Result<Answer, Error> {
+        Ok(school_size_after(school, 80).into())
+    }
+
+    fn part2(school: &SchoolOfFish) -> Result<Answer, Error> {
+        Ok(school_size_after(school, 256).into())
+    }
+}
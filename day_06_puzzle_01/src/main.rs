@@ -1,19 +1,16 @@
-mod school_of_fish;
-
-use std::fs;
-use crate::school_of_fish::SchoolOfFish;
+use aoc_common::InputSource;
+use day_06_puzzle_01::Day06;
 
 fn main() -> std::io::Result<()> {
-    let data = fs::read_to_string("./day_06_puzzle_01/input.txt").unwrap();
-
-    let mut school = SchoolOfFish::from(data.trim());
+    let input = InputSource::from_args("./day_06_puzzle_01/input.txt").read_to_string()?;
 
-    // for _ in 0..80 {
-    for _ in 0..256 {
-        school.next_day();
+    match aoc_common::solve_part1::<Day06>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
-    println!("After 80 days there are {} lanternfish", school.len());
-
     Ok(())
-}
+}
\ No newline at end of file
@@ -1,13 +1,15 @@
 use std::collections::HashMap;
+use aoc_common::Error;
 
+#[derive(Clone)]
 pub struct SchoolOfFish {
     all_generations: HashMap<u32,HashMap<u32, u64>>,
 }
 
 impl SchoolOfFish {
-    pub fn from(input: &str) -> SchoolOfFish {
+    pub fn from(input: &str) -> Result<SchoolOfFish, Error> {
         let mut initial_generation = HashMap::new();
-        for n in parse_input(input).iter() {
+        for n in parse_input(input)?.iter() {
             if let Some(count) = initial_generation.get_mut(n) {
                 *count += 1;
             } else {
@@ -16,9 +18,9 @@ impl SchoolOfFish {
         }
         let mut all_generations = HashMap::new();
         all_generations.insert(6, initial_generation);
-        SchoolOfFish {
+        Ok(SchoolOfFish {
             all_generations
-        }
+        })
     }
 
     pub fn len(&self) -> u64 {
@@ -59,12 +61,8 @@ impl SchoolOfFish {
     }
 }
 
-fn parse_input(input: &str) -> Vec<u32> {
-    input
-        .split(",")
-        .filter(|v| !v.is_empty())
-        .map(|v| u32::from_str_radix(v, 10).unwrap())
-        .collect::<Vec<u32>>()
+fn parse_input(input: &str) -> Result<Vec<u32>, Error> {
+    aoc_common::parse_comma_separated(input)
 }
 
 #[cfg(test)]
@@ -73,13 +71,13 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let numbers = parse_input("3,4,3,1,2");
+        let numbers = parse_input("3,4,3,1,2").unwrap();
         assert_eq!(vec![3, 4, 3, 1, 2], numbers);
     }
 
     #[test]
     fn test_school_from() {
-        let school = SchoolOfFish::from("3,4,3,1,2");
+        let school = SchoolOfFish::from("3,4,3,1,2").unwrap();
         // starts with a single generation
         assert_eq!(1, school.all_generations.len());
         // there are only 4 entries because there are two 3s
@@ -90,7 +88,7 @@ mod tests {
 
     #[test]
     fn test_school_progeny() {
-        let mut school = SchoolOfFish::from("3,4,3,1,2");
+        let mut school = SchoolOfFish::from("3,4,3,1,2").unwrap();
 
         // 1
         school.next_day();
@@ -1,32 +1,40 @@
-pub fn parse_numbers(input: &str) -> Vec<i32> {
-    let mut numbers = input
-        .split(",")
-        .filter(|v| !v.trim().is_empty())
-        .map(|v| i32::from_str_radix(v.trim(), 10).unwrap())
-        .collect::<Vec<i32>>();
-    
+use aoc_common::{Checked, Error};
+
+pub fn parse_numbers(input: &str) -> Result<Vec<i32>, Error> {
+    let mut numbers: Vec<i32> = aoc_common::parse_comma_separated(input)?;
+    if numbers.is_empty() {
+        return Err(Error::NoSolution(String::from("There are no crab positions to align")));
+    }
+
     numbers.sort();
 
-    numbers
+    Ok(numbers)
 }
 
-fn offset_total(numbers: &Vec<i32>, target: i32) -> i64 {
-    let mut offset_total = 0;
+/// The fuel used moving every crab to `target`, given what each distance moved costs.
+pub fn offset_total(numbers: &[i32], target: i32, fuel_cost: fn(i32) -> Result<i32, Error>) -> Result<i64, Error> {
+    let mut offset_total: i64 = 0;
 
     for n in numbers.iter() {
-        let offset = (target - n).abs();
-        offset_total += offset as i64;
+        let offset = target.sub_checked(*n, "target - crab position")?.abs();
+        offset_total = offset_total.add_checked(fuel_cost(offset)? as i64, "total fuel + fuel")?;
     }
 
-    offset_total
+    Ok(offset_total)
 }
 
-pub fn min_offset_total_target_brute_force(numbers: &Vec<i32>) -> (i32, i64) {
+/// Each point of offset costs one fuel.
+pub fn min_offset_total_target_brute_force(numbers: &[i32]) -> Result<(i32, i64), Error> {
+    min_cost_target_brute_force(numbers, Ok)
+}
+
+/// The position that takes the least fuel to align the crabs on, along with that fuel.
+pub fn min_cost_target_brute_force(numbers: &[i32], fuel_cost: fn(i32) -> Result<i32, Error>) -> Result<(i32, i64), Error> {
     let mut min_offset = i32::MAX;
     let mut min_offset_total = i64::MAX;
 
     for n in 0..numbers[numbers.len()-1] {
-        let offset_total = offset_total(&numbers, n);
+        let offset_total = offset_total(numbers, n, fuel_cost)?;
         if offset_total < min_offset_total {
             min_offset_total = offset_total;
             min_offset = n;
@@ -34,33 +42,37 @@ pub fn min_offset_total_target_brute_force(numbers: &Vec<i32>) -> (i32, i64) {
             break;
         }
     }
-    (min_offset, min_offset_total)
+    Ok((min_offset, min_offset_total))
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_common::ParseError;
     use super::*;
 
     #[test]
     fn test_parse() {
-        let numbers = parse_numbers("12,6,87,4,2,99,37,4,185");
+        let numbers = parse_numbers("12,6,87,4,2,99,37,4,185").unwrap();
         assert_eq!(vec![2,4,4,6,12,37,87,99,185], numbers);
+
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(1, "a number", "1 2 x")])), parse_numbers("1 2 x"));
+        assert_eq!(Err(Error::NoSolution(String::from("There are no crab positions to align"))), parse_numbers(""));
     }
 
     #[test]
     fn test_offset_total() {
         let numbers = vec![0,1,1,2,2,2,4,7,14,16];
 
-        assert_eq!(49, offset_total(&numbers, 0));
-        assert_eq!(41, offset_total(&numbers, 1));
-        assert_eq!(37, offset_total(&numbers, 2));
-        assert_eq!(71, offset_total(&numbers, 10));
+        assert_eq!(Ok(49), offset_total(&numbers, 0, Ok));
+        assert_eq!(Ok(41), offset_total(&numbers, 1, Ok));
+        assert_eq!(Ok(37), offset_total(&numbers, 2, Ok));
+        assert_eq!(Ok(71), offset_total(&numbers, 10, Ok));
     }
 
     #[test]
     fn test_min_offset() {
         let numbers = vec![0,1,1,2,2,2,4,7,14,16];
 
-        assert_eq!((2,37), min_offset_total_target_brute_force(&numbers));
+        assert_eq!(Ok((2,37)), min_offset_total_target_brute_force(&numbers));
     }
 }
\ No newline at end of file
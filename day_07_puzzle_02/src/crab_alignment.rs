@@ -1,46 +1,15 @@
-pub fn parse_numbers(input: &str) -> Vec<i32> {
-    let mut numbers = input
-        .split(",")
-        .filter(|v| !v.trim().is_empty())
-        .map(|v| i32::from_str_radix(v.trim(), 10).unwrap())
-        .collect::<Vec<i32>>();
-    
-    numbers.sort();
+use aoc_common::{Checked, Error};
+use day_07_puzzle_01::crab_alignment;
+pub use day_07_puzzle_01::crab_alignment::parse_numbers;
 
-    numbers
-}
-
-fn fuel_cost(offset: i32) -> i32 {
+fn fuel_cost(offset: i32) -> Result<i32, Error> {
     // each point of offset costs its value in fuel
     // eg. offset 3 > 1 + 2 + 3 = 6 fuel
-    offset * (offset + 1) / 2
-}
-
-fn offset_total(numbers: &Vec<i32>, target: i32) -> i64 {
-    let mut offset_total = 0;
-
-    for n in numbers.iter() {
-        let offset = (target - n).abs();
-        offset_total += fuel_cost(offset) as i64;
-    }
-
-    offset_total
+    Ok(offset.mul_checked(offset.add_checked(1, "offset + 1")?, "offset * (offset + 1)")? / 2)
 }
 
-pub fn min_offset_total_target_brute_force(numbers: &Vec<i32>) -> (i32, i64) {
-    let mut min_offset = i32::MAX;
-    let mut min_offset_total = i64::MAX;
-
-    for n in 0..numbers[numbers.len()-1] {
-        let offset_total = offset_total(&numbers, n);
-        if offset_total < min_offset_total {
-            min_offset_total = offset_total;
-            min_offset = n;
-        } else {
-            break;
-        }
-    }
-    (min_offset, min_offset_total)
+pub fn min_offset_total_target_brute_force(numbers: &[i32]) -> Result<(i32, i64), Error> {
+    crab_alignment::min_cost_target_brute_force(numbers, fuel_cost)
 }
 
 #[cfg(test)]
@@ -49,7 +18,7 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let numbers = parse_numbers("12,6,87,4,2,99,37,4,185");
+        let numbers = parse_numbers("12,6,87,4,2,99,37,4,185").unwrap();
         assert_eq!(vec![2,4,4,6,12,37,87,99,185], numbers);
     }
 
@@ -57,14 +26,20 @@ mod tests {
     fn test_offset_total() {
         let numbers = vec![0,1,1,2,2,2,4,7,14,16];
 
-        assert_eq!(206, offset_total(&numbers, 2));
-        assert_eq!(168, offset_total(&numbers, 5));
+        assert_eq!(Ok(206), crab_alignment::offset_total(&numbers, 2, fuel_cost));
+        assert_eq!(Ok(168), crab_alignment::offset_total(&numbers, 5, fuel_cost));
+    }
+
+    #[test]
+    fn test_fuel_overflow() {
+        assert_eq!(Ok(1_073_720_970), fuel_cost(46_340));
+        assert_eq!(Err(Error::Overflow(String::from("offset * (offset + 1)"))), fuel_cost(65_536));
     }
 
     #[test]
     fn test_min_offset() {
         let numbers = vec![0,1,1,2,2,2,4,7,14,16];
 
-        assert_eq!((5,168), min_offset_total_target_brute_force(&numbers));
+        assert_eq!(Ok((5,168)), min_offset_total_target_brute_force(&numbers));
     }
 }
\ No newline at end of file
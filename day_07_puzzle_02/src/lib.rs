@@ -0,0 +1,19 @@
+pub mod crab_alignment;
+
+use aoc_common::{Puzzle, Answer, Error};
+
+pub struct Day07;
+
+impl Puzzle for Day07 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, Error> {
+        crab_alignment::parse_numbers(input.trim())
+    }
+
+    fn part2(numbers: &Vec<i32>) -> Result<Answer, Error> {
+        let min_offset = crab_alignment::min_offset_total_target_brute_force(numbers)?;
+
+        Ok(Answer::from(min_offset.1).with_detail("position", min_offset.0))
+    }
+}
@@ -1,12 +1,17 @@
-mod crab_alignment;
-
-use std::fs;
+use aoc_common::InputSource;
+use day_07_puzzle_02::crab_alignment;
 
 fn main() -> std::io::Result<()> {
-    let data = fs::read_to_string("./day_07_puzzle_01/input.txt").unwrap();
+    let data = InputSource::from_args("./day_07_puzzle_01/input.txt").read_to_string()?;
 
-    let numbers = crab_alignment::parse_numbers(&data);
-    let min_offset = crab_alignment::min_offset_total_target_brute_force(&numbers);
+    let numbers = crab_alignment::parse_numbers(&data).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    });
+    let min_offset = crab_alignment::min_offset_total_target_brute_force(&numbers).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    });
 
     println!("The minimum amount of fuel to align all of the crabs (at position {}) is {}", min_offset.0, min_offset.1);
 
@@ -1,15 +1,16 @@
-pub fn extract_output_digits(input: &str) -> &str {
-	let mut split = input.split("|");
+use aoc_common::{Cursor, ParseError};
+
+/// Checks the entry is signal patterns and output patterns either side of a single '|', handing back the output.
+pub fn extract_output_digits(input: &str) -> Result<&str, ParseError> {
+	let mut cursor = Cursor::new(input);
 	// signals, ignore for now
-	split.next();
-	if let Some(output) = split.next() {
-		if let Some(v) = split.next() {
-			panic!("Unexpected third entry after spliting '{}' on | - '{}'", input, v);
-		}
-		output.trim()
-	} else {
-		panic!("Failed to parse '{}'", input);
+	while !cursor.accept("|") {
+		cursor.word("a signal pattern or '|'")?;
+	}
+	while !cursor.is_done() {
+		cursor.word("an output pattern")?;
 	}
+	Ok(input.split('|').nth(1).unwrap_or("").trim())
 }
 
 pub fn count_known_digits(encoded_digits: &str) -> u32 {
@@ -59,25 +60,32 @@ mod tests {
 
 	#[test]
 	fn test_extract_digits() {
-		assert_eq!("fdgacbe cefdb cefbgd gcbe", extract_output_digits("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe"));
+		assert_eq!(Ok("fdgacbe cefdb cefbgd gcbe"), extract_output_digits("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe"));
 
-		assert_eq!("fcgedb cgb dgebacf gc", extract_output_digits("edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc"));
+		assert_eq!(Ok("fcgedb cgb dgebacf gc"), extract_output_digits("edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc"));
 		
-		assert_eq!("cg cg fdcagb cbg", extract_output_digits("fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg"));
+		assert_eq!(Ok("cg cg fdcagb cbg"), extract_output_digits("fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg"));
 
-		assert_eq!("efabcd cedba gadfec cb", extract_output_digits("fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb"));
+		assert_eq!(Ok("efabcd cedba gadfec cb"), extract_output_digits("fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb"));
 
-		assert_eq!("gecf egdcabf bgf bfgea", extract_output_digits("aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea"));
+		assert_eq!(Ok("gecf egdcabf bgf bfgea"), extract_output_digits("aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea"));
 
-		assert_eq!("gebdcfa ecba ca fadegcb", extract_output_digits("fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb"));
+		assert_eq!(Ok("gebdcfa ecba ca fadegcb"), extract_output_digits("fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb"));
 
-		assert_eq!("cefg dcbef fcge gbcadfe", extract_output_digits("dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe"));
+		assert_eq!(Ok("cefg dcbef fcge gbcadfe"), extract_output_digits("dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe"));
 
-		assert_eq!("ed bcgafe cdgba cbgef", extract_output_digits("bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef"));
+		assert_eq!(Ok("ed bcgafe cdgba cbgef"), extract_output_digits("bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef"));
 
-		assert_eq!("gbdfcae bgc cg cgb", extract_output_digits("egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb"));
+		assert_eq!(Ok("gbdfcae bgc cg cgb"), extract_output_digits("egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb"));
 
-		assert_eq!("fgae cfgab fg bagce", extract_output_digits("gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"));
+		assert_eq!(Ok("fgae cfgab fg bagce"), extract_output_digits("gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"));
+	}
+
+	#[test]
+	fn test_extract_digits_errors() {
+		assert_eq!(Err(ParseError::new(12, "a signal pattern or '|'", "")), extract_output_digits("be cfbegad "));
+		assert_eq!(Err(ParseError::new(4, "a signal pattern or '|'", "12")), extract_output_digits("be 12 | cfbegad"));
+		assert_eq!(Err(ParseError::new(14, "an output pattern", "|")), extract_output_digits("be | cfbegad | edb"));
 	}
 
 	#[test]
@@ -0,0 +1,23 @@
+pub mod digital_display;
+
+use aoc_common::{Puzzle, Answer, Error, parse_lines};
+
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error> {
+        // only the output patterns are needed to count the known digits
+        parse_lines(input, |line| digital_display::extract_output_digits(line).map(String::from))
+    }
+
+    fn part1(outputs: &Vec<String>) -> Result<Answer, Error> {
+        let mut known_digit_totals = 0;
+        for output_digits in outputs.iter() {
+            known_digit_totals += digital_display::count_known_digits(output_digits);
+        }
+
+        Ok(known_digit_totals.into())
+    }
+}
@@ -1,25 +1,16 @@
-mod digital_display;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
+use aoc_common::InputSource;
+use day_08_puzzle_01::Day08;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_08_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
-
-    let mut known_digit_totals = 0;
-    for line in lines {
-        if let Ok(entry) = line {
-            if entry.trim().is_empty() {
-                continue;
-            }
-            let output_digits = digital_display::extract_output_digits(&entry.trim());
+    let input = InputSource::from_args("./day_08_puzzle_01/input.txt").read_to_string()?;
 
-            known_digit_totals += digital_display::count_known_digits(output_digits);
-        }
+    match aoc_common::solve_part1::<Day08>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
-    println!("{}", known_digit_totals);
-
     Ok(())
-}
+}
\ No newline at end of file
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use aoc_common::{Cursor, ParseError};
 
 pub struct FrequencyAnalysis<'input> {
 	signal_patterns: Vec<&'input str>,
@@ -14,22 +15,20 @@ lazy_static! {
 }
 
 impl<'input> FrequencyAnalysis<'input> {
-	pub fn from(input: &'input str) -> FrequencyAnalysis<'input> {
-		let mut split = input.split("|");
-
-		let signal_patterns = if let Some(signals) = split.next() {
-			signals.split(" ").filter(|&s| !s.is_empty()).collect::<Vec<&str>>()
-		} else {
-			panic!("Failed to parse '{}'", input);
-		};
-
-		let digital_output = if let Some(output) = split.next() {
-			output.split(" ").filter(|&s| !s.is_empty()).collect::<Vec<&str>>()
-		} else {
-			panic!("Failed to parse '{}'", input);
-		};
-		
-		FrequencyAnalysis::new(signal_patterns, digital_output)
+	pub fn from(input: &'input str) -> Result<FrequencyAnalysis<'input>, ParseError> {
+		let mut cursor = Cursor::new(input);
+
+		let mut signal_patterns = vec![];
+		while !cursor.accept("|") {
+			signal_patterns.push(segments(&mut cursor, "a signal pattern or '|'")?);
+		}
+
+		let mut digital_output = vec![];
+		while !cursor.is_done() {
+			digital_output.push(segments(&mut cursor, "an output pattern")?);
+		}
+
+		Ok(FrequencyAnalysis::new(signal_patterns, digital_output))
 	}
 
 	pub fn new(signal_patterns: Vec<&'input str>, digital_output: Vec<&'input str>) -> FrequencyAnalysis<'input> {
@@ -194,6 +193,16 @@ impl<'input> FrequencyAnalysis<'input> {
 	}
 }
 
+/// Reads a pattern of lit segments, which are only ever named 'a' to 'g'.
+fn segments<'input>(cursor: &mut Cursor<'input>, expected: &str) -> Result<&'input str, ParseError> {
+	let pattern = cursor.word(expected)?;
+	if pattern.chars().all(|c| ALL_CHARACTERS.contains(&c)) {
+		Ok(pattern)
+	} else {
+		Err(ParseError::new(cursor.column() - pattern.len(), expected, pattern))
+	}
+}
+
 pub struct Digit {
 	value: u32,
 	positions: Vec<u32>,
@@ -279,6 +288,17 @@ fn find_digit(positions: Vec<u32>) -> Option<&'static Digit> {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn test_from() {
+		let analysis = FrequencyAnalysis::from("be cfbegad | fdgacbe cefdb").unwrap();
+		assert_eq!(vec!["be", "cfbegad"], analysis.signal_patterns);
+		assert_eq!(vec!["fdgacbe", "cefdb"], analysis.digital_output);
+
+		assert_eq!(Err(ParseError::new(12, "a signal pattern or '|'", "")), FrequencyAnalysis::from("be cfbegad ").map(|_| ()));
+		assert_eq!(Err(ParseError::new(6, "an output pattern", "xyz")), FrequencyAnalysis::from("be | xyz").map(|_| ()));
+		assert_eq!(Err(ParseError::new(14, "an output pattern", "|")), FrequencyAnalysis::from("be | cfbegad | edb").map(|_| ()));
+	}
+
 	#[test]
 	fn test_decode_1() {
 		let signal_patterns = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb".split(" ").collect::<Vec<&str>>();
@@ -0,0 +1,28 @@
+#[macro_use]
+extern crate lazy_static;
+
+pub mod frequency_analysis;
+
+use aoc_common::{Puzzle, Answer, Error, parse_lines};
+use frequency_analysis::FrequencyAnalysis;
+
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error> {
+        parse_lines(input, |line| FrequencyAnalysis::from(line).map(|_| String::from(line)))
+    }
+
+    fn part2(entries: &Vec<String>) -> Result<Answer, Error> {
+        let mut display_output_total = 0;
+        for entry in entries.iter() {
+            let mut analysis = FrequencyAnalysis::from(entry)?;
+            analysis.analyze();
+            display_output_total += analysis.decode_display_output();
+        }
+
+        Ok(display_output_total.into())
+    }
+}
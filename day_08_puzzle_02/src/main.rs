@@ -1,29 +1,16 @@
-#[macro_use]
-extern crate lazy_static;
-
-mod frequency_analysis;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use crate::frequency_analysis::FrequencyAnalysis;
+use aoc_common::InputSource;
+use day_08_puzzle_02::Day08;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_08_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
+    let input = InputSource::from_args("./day_08_puzzle_01/input.txt").read_to_string()?;
 
-    let mut display_output_total = 0;
-    for line in lines {
-        if let Ok(entry) = line {
-            if entry.trim().is_empty() {
-                continue;
-            }
-            let mut analysis = FrequencyAnalysis::from(&entry);
-            analysis.analyze();
-            display_output_total += analysis.decode_display_output();
-        }
+    match aoc_common::solve_part2::<Day08>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
-    println!("{}", display_output_total);
-
     Ok(())
-}
+}
\ No newline at end of file
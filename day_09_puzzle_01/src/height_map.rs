@@ -1,6 +1,4 @@
-pub struct LowPoint {
-	risk_level: u32,
-}
+use aoc_common::{LineSolver, Answer, Error, ParseError};
 
 pub struct HeightMap {
 	size: Option<usize>,
@@ -9,7 +7,9 @@ pub struct HeightMap {
 	prev_row: Option<Vec<u32>>,
 	current_row: Option<Vec<u32>>,
 	next_row: Option<Vec<u32>>,
-	low_points: Vec<LowPoint>,
+	// only the totals are kept so memory stays at three rows however tall the map is
+	low_point_count: usize,
+	risk_level_total: u32,
 }
 
 impl HeightMap {
@@ -21,7 +21,8 @@ impl HeightMap {
 			prev_row: None,
 			current_row: None,
 			next_row: None,
-			low_points: vec![],
+			low_point_count: 0,
+			risk_level_total: 0,
 		}
 	}
 
@@ -79,7 +80,8 @@ impl HeightMap {
 						continue;
 					}
 				}
-				self.low_points.push(LowPoint { risk_level: value + 1 });
+				self.low_point_count += 1;
+				self.risk_level_total += value + 1;
 			}
 
 			if self.next_row.is_none() {
@@ -88,8 +90,36 @@ impl HeightMap {
 		}
 	}
 
+	pub fn low_point_count(&self) -> usize {
+		self.low_point_count
+	}
+
 	pub fn risk_level_total(&self) -> u32 {
-		self.low_points.iter().map(|lp| lp.risk_level).sum()
+		self.risk_level_total
+	}
+}
+
+impl Default for HeightMap {
+	fn default() -> HeightMap {
+		HeightMap::new()
+	}
+}
+
+impl LineSolver for HeightMap {
+	fn process_line(&mut self, line: &str) -> Result<(), Error> {
+		let row = aoc_common::parse_digit_row(line)?;
+		if let Some(size) = self.size {
+			if size != row.len() {
+				return Err(ParseError::new(1, &format!("a row of {} digits", size), line.trim()).into());
+			}
+		}
+		self.process_row(Some(row));
+		Ok(())
+	}
+
+	fn finish(mut self) -> Result<Answer, Error> {
+		self.process_row(None);
+		Ok(self.risk_level_total().into())
 	}
 }
 
@@ -108,7 +138,17 @@ mod tests {
 		map.process_row(Some(vec![9, 8, 9, 9, 9, 6, 5, 6, 7, 8]));
 		map.process_row(None);
 
-		let lowest_point_values = map.low_points.iter().map(|lp| lp.risk_level).collect::<Vec<u32>>();
-		assert_eq!(vec![2, 1, 6, 6], lowest_point_values);
+		// the low points have risk levels of 2, 1, 6 and 6
+		assert_eq!(4, map.low_point_count());
+		assert_eq!(15, map.risk_level_total());
+	}
+
+	#[test]
+	fn test_stream() {
+		let input = "2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n";
+		assert_eq!(Ok(Answer::from(15)), aoc_common::solve_lines::<HeightMap>(&mut input.as_bytes()));
+
+		let errors = vec![ParseError::new(1, "a row of 10 digits", "398789492").on_line(2)];
+		assert_eq!(Err(Error::Parse(errors)), aoc_common::solve_lines::<HeightMap>(&mut "2199943210\n398789492".as_bytes()));
 	}
 }
\ No newline at end of file
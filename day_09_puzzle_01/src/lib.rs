@@ -0,0 +1,24 @@
+pub mod height_map;
+
+use aoc_common::{Puzzle, Answer, Error, Grid};
+use height_map::HeightMap;
+
+pub struct Day09;
+
+impl Puzzle for Day09 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Grid<u32>, Error> {
+        aoc_common::parse_digit_grid(input)
+    }
+
+    fn part1(heights: &Grid<u32>) -> Result<Answer, Error> {
+        let mut map = HeightMap::new();
+        for row in heights.rows() {
+            map.process_row(Some(row.to_vec()));
+        }
+        map.process_row(None);
+
+        Ok(map.risk_level_total().into())
+    }
+}
@@ -1,17 +1,4 @@
-type Position = (usize, usize);
-
-trait AreAdjacent<T> {
-	fn are_adjacent(&self, other: &T) -> bool;
-}
-
-impl AreAdjacent<Position> for Position {
-	fn are_adjacent(&self, other: &Position) -> bool {
-		let x_delta = (self.0 as isize - other.0 as isize).abs();
-		let y_delta = (self.1 as isize - other.1 as isize).abs();
-		// diagonals are not adjacent
-		x_delta == 1 && y_delta == 0 || x_delta == 0 && y_delta == 1
-	}
-}
+use aoc_common::{Position, Error, Checked};
 
 pub struct Basin {
 	positions: Vec<Position>,
@@ -47,7 +34,8 @@ impl Basin {
 
 	fn is_within(&self, position: &Position) -> bool {
 		for p in self.positions.iter() {
-			if p.are_adjacent(position) {
+			// diagonals are not adjacent
+			if p.manhattan_distance(position) == 1 {
 				return true;
 			}
 		}
@@ -57,6 +45,10 @@ impl Basin {
 	fn add(&mut self, position: Position) {
 		self.positions.push(position);
 	}
+
+	pub fn positions(&self) -> &[Position] {
+		&self.positions
+	}
 }
 
 pub struct MapScanner {
@@ -96,7 +88,7 @@ impl MapScanner {
 			if *value > 9 {
 				panic!("Expected highest value is 9 - found {}", value);
 			}
-			let position = (column, self.current_row_index.unwrap());
+			let position = Position::new(column, self.current_row_index.unwrap());
 			for basin in self.basins.iter_mut() {
 				if basin.is_within(&position) {
 					basin.add(position);
@@ -137,16 +129,24 @@ impl MapScanner {
 		self.basins = merged_basins;
 	}
 
-	pub fn largest_basins_score(&mut self, count: usize) -> usize {
+	pub fn basins(&self) -> &[Basin] {
+		&self.basins
+	}
+
+	/// The sizes of the `count` largest basins multiplied together.
+	pub fn largest_basins_score(&mut self, count: usize) -> Result<usize, Error> {
+		if self.basins.len() < count {
+			return Err(Error::NoSolution(format!("Found {} basins but the score needs the {} largest", self.basins.len(), count)));
+		}
+
 		self.basins.sort_by(|a, b| a.positions.len().partial_cmp(&b.positions.len()).unwrap());
 		self.basins.reverse();
 
-		let mut score = 1;
-		for i in 0..count {
-			let basin = &self.basins[i];
-			score *= basin.positions.len();
+		let mut score: usize = 1;
+		for basin in self.basins.iter().take(count) {
+			score = score.mul_checked(basin.positions.len(), "product of basin sizes")?;
 		}
-		score
+		Ok(score)
 	}
 }
 
@@ -184,6 +184,17 @@ mod tests {
 		scanner.merge_basins();
 		scanner.merge_basins();
 
-		assert_eq!(1134, scanner.largest_basins_score(3));
+		assert_eq!(Ok(1134), scanner.largest_basins_score(3));
+	}
+
+	#[test]
+	fn test_too_few_basins() {
+		let mut scanner = MapScanner::new();
+
+		scanner.scan_row(vec![1, 9, 1]);
+		scanner.merge_basins();
+
+		assert_eq!(Err(Error::NoSolution(String::from("Found 2 basins but the score needs the 3 largest"))), scanner.largest_basins_score(3));
+		assert_eq!(Err(Error::NoSolution(String::from("Found 0 basins but the score needs the 3 largest"))), MapScanner::new().largest_basins_score(3));
 	}
 }
\ No newline at end of file
@@ -0,0 +1,113 @@
+pub mod basin;
+
+use std::collections::HashMap;
+use aoc_common::{Puzzle, Answer, Error, Grid, Frame, Cell, Colour, Picture, Raster, Rgb};
+use basin::MapScanner;
+
+fn scan(heights: &Grid<u32>) -> MapScanner {
+    let mut scanner = MapScanner::new();
+    for row in heights.rows() {
+        scanner.scan_row(row.to_vec());
+    }
+    scanner.merge_basins();
+    scanner
+}
+
+pub struct Day09;
+
+impl Puzzle for Day09 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Grid<u32>, Error> {
+        aoc_common::parse_digit_grid(input)
+    }
+
+    fn part2(heights: &Grid<u32>) -> Result<Answer, Error> {
+        let mut scanner = scan(heights);
+
+        Ok(scanner.largest_basins_score(3)?.into())
+    }
+}
+
+/// The height map, then each basin filled in from smallest to largest, ending with the three largest picked out.
+pub fn visualize(input: &str) -> Result<Vec<Frame>, Error> {
+    let heights = Day09::parse(input)?;
+    let mut basins = scan(&heights).basins().iter().map(|b| b.positions().to_vec()).collect::<Vec<_>>();
+    basins.sort_by_key(|b| b.len());
+
+    let height_map = Frame::from_grid("Height map", &heights, |_, &height| {
+        let symbol = char::from_digit(height, 10).unwrap();
+        if height == 9 { Cell::coloured(symbol, Colour::Dim) } else { Cell::new(symbol) }
+    });
+    let mut cells = height_map.cells().clone();
+
+    let mut frames = vec![height_map];
+    for (i, basin) in basins.iter().enumerate() {
+        let colour = Colour::PALETTE[i % Colour::PALETTE.len()];
+        for &position in basin.iter() {
+            cells[position] = Cell::coloured(cells[position].symbol(), colour);
+        }
+        frames.push(Frame::new(&format!("Basin {} of {} - size {}", i + 1, basins.len(), basin.len()), cells.clone()));
+    }
+
+    for basin in basins.iter().rev().take(3) {
+        for &position in basin.iter() {
+            cells[position] = Cell::coloured(cells[position].symbol(), Colour::Bright);
+        }
+    }
+    frames.push(Frame::new("Three largest basins", cells));
+
+    Ok(frames)
+}
+
+/// The basins with the three largest picked out in colour, the smaller ones in grey and the ridges of 9s in black.
+pub fn export(input: &str) -> Result<Picture, Error> {
+    let heights = Day09::parse(input)?;
+    let mut basins = scan(&heights).basins().iter().map(|b| b.positions().to_vec()).collect::<Vec<_>>();
+    basins.sort_by_key(|b| std::cmp::Reverse(b.len()));
+
+    let mut colours = HashMap::new();
+    for (i, basin) in basins.iter().enumerate() {
+        let colour = if i < 3 { Rgb::PALETTE[i] } else { Rgb::grey(120) };
+        colours.extend(basin.iter().map(|&p| (p, colour)));
+    }
+
+    let raster = Raster::from_grid(&heights, |position, _| *colours.get(&position).unwrap_or(&Rgb::BLACK));
+    Ok(Picture::Raster(raster.scaled(4)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Position;
+
+    #[test]
+    fn test_visualize() {
+        let frames = visualize("2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n").unwrap();
+
+        // the height map, one frame per basin and the final three
+        assert_eq!(6, frames.len());
+        assert_eq!("Height map\n2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n", frames[0].to_string());
+        assert_eq!("Basin 1 of 4 - size 3", frames[1].title());
+        assert_eq!(Colour::Red, frames[1].cells()[Position::new(0, 0)].colour());
+        assert_eq!(Colour::Plain, frames[1].cells()[Position::new(9, 0)].colour());
+
+        let last = &frames[5];
+        assert_eq!(Colour::Dim, last.cells()[Position::new(2, 0)].colour());
+        assert_eq!(Colour::Red, last.cells()[Position::new(0, 0)].colour());
+        assert_eq!(Colour::Bright, last.cells()[Position::new(9, 0)].colour());
+        assert_eq!(9 + 14 + 9, last.cells().values().filter(|c| c.colour() == Colour::Bright).count());
+    }
+
+    #[test]
+    fn test_export() {
+        let picture = export("2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n").unwrap();
+        let Picture::Raster(raster) = picture else { panic!("Expected the basins to be a raster") };
+
+        let pixels = raster.pixels();
+        assert_eq!(Rgb::grey(120), pixels[Position::new(0, 0)]);
+        assert_eq!(Rgb::BLACK, pixels[Position::new(2, 0)]);
+        assert_eq!(Rgb::PALETTE[0], pixels[Position::new(2, 2)]);
+        assert_eq!(9 + 14 + 9, pixels.values().filter(|&&p| p != Rgb::BLACK && p != Rgb::grey(120)).count());
+    }
+}
@@ -1,29 +1,16 @@
-mod basin;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use crate::basin::MapScanner;
+use aoc_common::InputSource;
+use day_09_puzzle_02::Day09;
 
 fn main() -> std::io::Result<()> {
-
-    let file = File::open("./day_09_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
-
-    let mut scanner = MapScanner::new();
-
-    for line in lines {
-        if let Ok(entry) = line {
-            if entry.trim().is_empty() {
-                continue;
-            }
-            let row = entry.trim().chars().map(|c| c.to_digit(10).unwrap()).collect::<Vec<u32>>();
-            scanner.scan_row(row);
-        }
+    let input = InputSource::from_args("./day_09_puzzle_01/input.txt").read_to_string()?;
+
+    match aoc_common::solve_part2::<Day09>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
-    scanner.merge_basins();
-
-    println!("{}", scanner.largest_basins_score(3));
-
     Ok(())
-}
+}
\ No newline at end of file
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use aoc_common::{LineSolver, Answer, Error};
 
 lazy_static! {
 	static ref OPEN_CLOSE_PAIRS: HashMap<char, char> = {
@@ -13,27 +14,20 @@ lazy_static! {
 }
 
 pub struct ChunkChecker {
-	invalid_characters: Vec<char>,
+	// scored as each line is parsed so nothing from earlier lines needs to be kept
+	syntax_error_score: u32,
 }
 
 impl ChunkChecker {
 	pub fn new() -> ChunkChecker {
 		ChunkChecker {
-			invalid_characters: vec![],
+			syntax_error_score: 0,
 		}
 	}
 
 	pub fn parse_line(&mut self, line: &str) {
 		if let Some(c) = first_illegal_character(&line) {
-			self.invalid_characters.push(c);
-		}
-	}
-
-	pub fn syntax_error_score(&self) -> u32 {
-		let mut score = 0;
-
-		for c in self.invalid_characters.iter() {
-			score +=  match *c {
+			self.syntax_error_score += match c {
 				')' => 3,
 				']' => 57,
 				'}' => 1197,
@@ -41,32 +35,71 @@ impl ChunkChecker {
 				_ => panic!("Expected invalid character to be ')', ']', '}}', or '>' - found '{}'", c),
 			}
 		}
+	}
+
+	pub fn syntax_error_score(&self) -> u32 {
+		self.syntax_error_score
+	}
+}
+
+impl Default for ChunkChecker {
+	fn default() -> ChunkChecker {
+		ChunkChecker::new()
+	}
+}
+
+impl LineSolver for ChunkChecker {
+	fn process_line(&mut self, line: &str) -> Result<(), Error> {
+		self.parse_line(line.trim());
+		Ok(())
+	}
 
-		score
+	fn finish(self) -> Result<Answer, Error> {
+		Ok(self.syntax_error_score().into())
 	}
 }
 
 pub fn first_illegal_character(line: &str) -> Option<char> {
+	match_chunks(line).err()
+}
+
+/// The closing characters that would complete the line, or `None` when it is complete or corrupted.
+pub fn find_incomplete_opens(line: &str) -> Option<String> {
+	let opens = match_chunks(line).ok()?;
+	if !opens.is_empty() {
+		let mut missing_closes = vec![];
+		for open in opens.iter().rev() {
+			let close = OPEN_CLOSE_PAIRS.get(open).unwrap();
+			missing_closes.push(*close);
+		}
+		Some(String::from_iter(missing_closes))
+	} else {
+		None
+	}
+}
+
+/// The chunks still open at the end of the line, or the first illegal character.
+fn match_chunks(line: &str) -> Result<Vec<char>, char> {
 	let mut opens = vec![];
-	for c in line.chars() {
+	'chars: for c in line.chars() {
 		for (open, close) in OPEN_CLOSE_PAIRS.iter() {
 			if c == *open {
 				opens.push(c);
-				continue;
+				continue 'chars;
 			} else if c == *close {
-				let last_index = opens.len() - 1;
-				let last_open = opens[last_index];
-				let expected_close = OPEN_CLOSE_PAIRS.get(&last_open).unwrap();
-				if expected_close == close {
-					opens.remove(last_index);
-				} else {
-					return Some(*close);
+				// a close with nothing left open is as illegal as one that doesn't match
+				match opens.last() {
+					Some(last_open) if OPEN_CLOSE_PAIRS.get(last_open) == Some(close) => {
+						opens.pop();
+						continue 'chars;
+					},
+					_ => return Err(*close),
 				}
 			}
 		}
 	}
 
-	None
+	Ok(opens)
 }
 
 #[cfg(test)]
@@ -99,6 +132,43 @@ mod tests {
 		assert_eq!(Some('>'), first_illegal_character("<{([([[(<>()){}]>(<<{{"));
 	}
 
+	#[test]
+	fn test_first_illegal_characters_unopened() {
+		assert_eq!(Some(')'), first_illegal_character("()))"));
+		assert_eq!(None, find_incomplete_opens("]"));
+	}
+
+	#[test]
+	fn test_find_incomplete_opens_none() {
+		assert_eq!(None, find_incomplete_opens("[<>({}){}[([])<>]]"));
+	}
+
+	#[test]
+	fn test_first_incomplete_opens_1() {
+		assert_eq!("}}]])})]", find_incomplete_opens("[({(<(())[]>[[{[]{<()<>>").unwrap());
+	}
+
+	#[test]
+	fn test_first_incomplete_opens_2() {
+		assert_eq!(")}>]})", find_incomplete_opens("[(()[<>])]({[<{<<[]>>(").unwrap());
+	}
+
+
+	#[test]
+	fn test_first_incomplete_opens_3() {
+		assert_eq!("}}>}>))))", find_incomplete_opens("(((({<>}<{<{<>}{[]{[]{}").unwrap());
+	}
+
+	#[test]
+	fn test_first_incomplete_opens_4() {
+		assert_eq!("]]}}]}]}>", find_incomplete_opens("{<[[]]>}<{[{[{[]{()[[[]").unwrap());
+	}
+
+	#[test]
+	fn test_first_incomplete_opens_5() {
+		assert_eq!("])}>", find_incomplete_opens("<{([{{}}[<[[[<>{}]]]>[]]").unwrap());
+	}
+
 	#[test]
 	fn test_demo() {
 		let mut checker = ChunkChecker::new();
@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate lazy_static;
+
+pub mod chunk;
+
+use aoc_common::{Puzzle, Answer, Error};
+use chunk::ChunkChecker;
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error> {
+        Ok(input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()).map(String::from).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, Error> {
+        let mut checker = ChunkChecker::new();
+        for line in lines.iter() {
+            checker.parse_line(line);
+        }
+
+        Ok(checker.syntax_error_score().into())
+    }
+}
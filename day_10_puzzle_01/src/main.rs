@@ -1,29 +1,16 @@
-#[macro_use]
-extern crate lazy_static;
-
-mod chunk;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use crate::chunk::ChunkChecker;
+use aoc_common::InputSource;
+use day_10_puzzle_01::chunk::ChunkChecker;
 
 fn main() -> std::io::Result<()> {
-
-    let file = File::open("./day_10_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
-
-    let mut checker = ChunkChecker::new();
-
-    for line in lines {
-        if let Ok(entry) = line {
-            if entry.trim().is_empty() {
-                continue;
-            }
-            checker.parse_line(entry.trim());
-        }
+    let mut input = InputSource::from_args("./day_10_puzzle_01/input.txt").open()?;
+
+    match aoc_common::solve_lines::<ChunkChecker>(&mut input) {
+        Ok(score) => println!("{}", score),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
-    println!("{}", checker.syntax_error_score());
-
     Ok(())
-}
\ No newline at end of file
+}
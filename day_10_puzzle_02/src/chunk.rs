@@ -1,92 +1,64 @@
-use std::collections::HashMap;
-
-lazy_static! {
-	static ref OPEN_CLOSE_PAIRS: HashMap<char, char> = {
-		let mut map = HashMap::new();
-		map.insert('(', ')');
-		map.insert('[', ']');
-		map.insert('{', '}');
-		map.insert('<', '>');
-
-		map
-	};
-}
+use aoc_common::{LineSolver, Answer, Error, Checked};
+use day_10_puzzle_01::chunk::find_incomplete_opens;
 
 pub struct ChunkChecker {
-	incomplete_opens: Vec<String>,
+	// the middle score needs every score but not the lines they came from
+	incomplete_scores: Vec<u64>,
 }
 
 impl ChunkChecker {
 	pub fn new() -> ChunkChecker {
 		ChunkChecker {
-			incomplete_opens: vec![],
+			incomplete_scores: vec![],
 		}
 	}
 
-	pub fn parse_line(&mut self, line: &str) {
+	pub fn parse_line(&mut self, line: &str) -> Result<(), Error> {
 		if let Some(opens) = find_incomplete_opens(&line) {
-			self.incomplete_opens.push(opens);
-		}
-	}
-
-	pub fn middle_incomplete_score(&self) -> u64 {
-		let mut scores = vec![];
-
-		for opens in self.incomplete_opens.iter() {
-			let mut score = 0;
+			let mut score: u64 = 0;
 			for c in opens.chars() {
-				score *= 5;
-				score += match c {
+				score = score.mul_checked(5, "completion score * 5")?;
+				score = score.add_checked(match c {
 					')' => 1,
 					']' => 2,
 					'}' => 3,
 					'>' => 4,
 					_ => panic!("Expected invalid character to be ')', ']', '}}', or '>' - found '{}'", c),
-				}
+				}, "completion score + character score")?;
 			}
 
-			scores.push(score);
+			self.incomplete_scores.push(score);
 		}
 
+		Ok(())
+	}
+
+	/// The middle of the incomplete lines' scores, which needs an odd number of them to exist.
+	pub fn middle_incomplete_score(&self) -> Result<u64, Error> {
+		let mut scores = self.incomplete_scores.clone();
 		scores.sort();
 
-		// assumption that there is always an odd number of incomplete lines
-		assert_eq!(1, scores.len() % 2);
+		if scores.len() % 2 != 1 {
+			return Err(Error::NoSolution(format!("Found {} incomplete lines but the middle score needs an odd number of them", scores.len())));
+		}
 
-		scores[scores.len() / 2]
+		Ok(scores[scores.len() / 2])
 	}
 }
 
-pub fn find_incomplete_opens(line: &str) -> Option<String> {
-	let mut opens = vec![];
-	'chars: for c in line.chars() {
-		for (open, close) in OPEN_CLOSE_PAIRS.iter() {
-			if c == *open {
-				opens.push(c);
-				continue 'chars;
-			} else if c == *close {
-				let last_index = opens.len() - 1;
-				let last_open = opens[last_index];
-				let expected_close = OPEN_CLOSE_PAIRS.get(&last_open).unwrap();
-				if expected_close == close {
-					opens.remove(last_index);
-				} else {
-					// illegal character - skip this line
-					return None;
-				}
-			}
-		}
+impl Default for ChunkChecker {
+	fn default() -> ChunkChecker {
+		ChunkChecker::new()
 	}
+}
 
-	if opens.len() > 0 {
-		let mut missing_closes = vec![];
-		for open in opens.iter().rev() {
-			let close = OPEN_CLOSE_PAIRS.get(&open).unwrap();
-			missing_closes.push(*close);
-		}
-		Some(String::from_iter(missing_closes))
-	} else {
-		None
+impl LineSolver for ChunkChecker {
+	fn process_line(&mut self, line: &str) -> Result<(), Error> {
+		self.parse_line(line.trim())
+	}
+
+	fn finish(self) -> Result<Answer, Error> {
+		Ok(self.middle_incomplete_score()?.into())
 	}
 }
 
@@ -96,50 +68,29 @@ mod tests {
 	use super::*;
 
 	#[test]
-	fn test_find_incomplete_opens_none() {
-		assert_eq!(None, find_incomplete_opens("[<>({}){}[([])<>]]"));
-	}
-
-	#[test]
-	fn test_first_incomplete_opens_1() {
-		assert_eq!("}}]])})]", find_incomplete_opens("[({(<(())[]>[[{[]{<()<>>").unwrap());
-	}
-
-	#[test]
-	fn test_first_incomplete_opens_2() {
-		assert_eq!(")}>]})", find_incomplete_opens("[(()[<>])]({[<{<<[]>>(").unwrap());
-	}
-
-
-	#[test]
-	fn test_first_incomplete_opens_3() {
-		assert_eq!("}}>}>))))", find_incomplete_opens("(((({<>}<{<{<>}{[]{[]{}").unwrap());
-	}
-
-	#[test]
-	fn test_first_incomplete_opens_4() {
-		assert_eq!("]]}}]}]}>", find_incomplete_opens("{<[[]]>}<{[{[{[]{()[[[]").unwrap());
-	}
-
-	#[test]
-	fn test_first_incomplete_opens_5() {
-		assert_eq!("])}>", find_incomplete_opens("<{([{{}}[<[[[<>{}]]]>[]]").unwrap());
+	fn test_demo() {
+		let mut checker = ChunkChecker::new();
+		checker.parse_line("[({(<(())[]>[[{[]{<()<>>").unwrap();
+		checker.parse_line("[(()[<>])]({[<{<<[]>>(").unwrap();
+		checker.parse_line("{([(<{}[<>[]}>{[]{[(<()>").unwrap();
+		checker.parse_line("(((({<>}<{<{<>}{[]{[]{}").unwrap();
+		checker.parse_line("[[<[([]))<([[{}[[()]]]").unwrap();
+		checker.parse_line("[{[{({}]{}}([{[{{{}}([]").unwrap();
+		checker.parse_line("{<[[]]>}<{[{[{[]{()[[[]").unwrap();
+		checker.parse_line("[<(<(<(<{}))><([]([]()").unwrap();
+		checker.parse_line("<{([([[(<>()){}]>(<<{{").unwrap();
+		checker.parse_line("<{([{{}}[<[[[<>{}]]]>[]]").unwrap();
+
+		assert_eq!(Ok(288957), checker.middle_incomplete_score());
 	}
 
 	#[test]
-	fn test_demo() {
+	fn test_no_middle_score() {
 		let mut checker = ChunkChecker::new();
-		checker.parse_line("[({(<(())[]>[[{[]{<()<>>");
-		checker.parse_line("[(()[<>])]({[<{<<[]>>(");
-		checker.parse_line("{([(<{}[<>[]}>{[]{[(<()>");
-		checker.parse_line("(((({<>}<{<{<>}{[]{[]{}");
-		checker.parse_line("[[<[([]))<([[{}[[()]]]");
-		checker.parse_line("[{[{({}]{}}([{[{{{}}([]");
-		checker.parse_line("{<[[]]>}<{[{[{[]{()[[[]");
-		checker.parse_line("[<(<(<(<{}))><([]([]()");
-		checker.parse_line("<{([([[(<>()){}]>(<<{{");
-		checker.parse_line("<{([{{}}[<[[[<>{}]]]>[]]");
-
-		assert_eq!(288957, checker.middle_incomplete_score());
+		assert_eq!(Err(Error::NoSolution(String::from("Found 0 incomplete lines but the middle score needs an odd number of them"))), checker.middle_incomplete_score());
+
+		checker.parse_line("[(").unwrap();
+		checker.parse_line("<").unwrap();
+		assert_eq!(Err(Error::NoSolution(String::from("Found 2 incomplete lines but the middle score needs an odd number of them"))), checker.middle_incomplete_score());
 	}
 }
\ No newline at end of file
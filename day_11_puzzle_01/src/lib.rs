@@ -0,0 +1,18 @@
+pub mod octopus_grid;
+
+use aoc_common::{Puzzle, Answer, Error};
+use octopus_grid::OctopusGrid;
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    type Input = OctopusGrid;
+
+    fn parse(input: &str) -> Result<OctopusGrid, Error> {
+        Ok(OctopusGrid::from(aoc_common::parse_digit_grid(input)?))
+    }
+
+    fn part1(grid: &OctopusGrid) -> Result<Answer, Error> {
+        Ok(grid.compute_flashes(100).into())
+    }
+}
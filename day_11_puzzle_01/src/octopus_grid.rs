@@ -1,100 +1,122 @@
+use aoc_common::{Grid, Position, Frame, Cell, Colour};
+
 pub struct OctopusGrid {
-    energy_levels: Vec<u32>,
-    size: Option<usize>,
+    energy_levels: Grid<u32>,
 }
 
 impl OctopusGrid {
     pub fn new() -> OctopusGrid {
         OctopusGrid {
-            energy_levels: vec![],
-            size: None,
+            energy_levels: Grid::new(),
         }
     }
 
-    pub fn add_row(&mut self, mut levels: Vec<u32>) {
-        if let Some(size) = self.size {
-            if levels.len() != size {
-                panic!("Expect row to be of size {} - found {}", size, levels.len());
-            }
-        } else {
-            self.size = Some(levels.len());
+    pub fn from(energy_levels: Grid<u32>) -> OctopusGrid {
+        OctopusGrid {
+            energy_levels,
         }
+    }
+
+    pub fn add_row(&mut self, levels: Vec<u32>) {
+        self.energy_levels.push_row(levels);
+    }
 
-        self.energy_levels.append(&mut levels);
+    /// Advances the octopi by one step, returning how many of them flashed.
+    pub fn step(&mut self) -> usize {
+        step(&mut self.energy_levels)
+    }
+
+    /// The energy levels with the octopi that just flashed lit up, and the rest coloured by how close they are to flashing.
+    pub fn to_frame(&self, title: &str) -> Frame {
+        Frame::from_grid(title, &self.energy_levels, |_, &energy| {
+            let colour = match energy {
+                0 => Colour::Bright,
+                1..=3 => Colour::Dim,
+                4..=6 => Colour::Blue,
+                _ => Colour::Cyan,
+            };
+            Cell::coloured(char::from_digit(energy.min(9), 10).unwrap(), colour)
+        })
     }
 
     pub fn compute_flashes(&self, step_count: u32) -> u32 {
-        let mut energy_levels_buffer = self.energy_levels.to_vec();
+        let mut energy_levels_buffer = self.energy_levels.clone();
 
         let mut flash_count = 0;
 
         for _ in 0..step_count {
-            let mut has_flashed_octopi = vec![];
-            let mut just_flash_octopi = vec![];
-            for (octopus, energy) in energy_levels_buffer.iter_mut().enumerate() {
-                *energy += 1;
-                if *energy > 9 {
-                    has_flashed_octopi.push(octopus);
-                    just_flash_octopi.push(octopus);
-                    flash_count += 1;
-                }
-            }
-            while just_flash_octopi.len() > 0 {
-                let mut newly_flashed_octopi = vec![];
-
-                for flashed_octopus in just_flash_octopi.iter() {
-                    for adjacent_octopus in self.adjacent_indicies(*flashed_octopus).iter() {
-                        let energy = energy_levels_buffer.get_mut(*adjacent_octopus).unwrap();
-                        *energy += 1;
-                        if *energy > 9 && !has_flashed_octopi.contains(adjacent_octopus) {
-                            has_flashed_octopi.push(*adjacent_octopus);
-                            newly_flashed_octopi.push(*adjacent_octopus);
-                            flash_count += 1;
-                        }
-                    }
-                }
-
-                just_flash_octopi = newly_flashed_octopi;
-            }
-
-            for has_flashed in has_flashed_octopi.iter() {
-                let energy = energy_levels_buffer.get_mut(*has_flashed).unwrap();
-                *energy = 0;
-            }
+            flash_count += step(&mut energy_levels_buffer) as u32;
         }
 
         flash_count
     }
 
-    fn adjacent_indicies(&self, center: usize) -> Vec<usize> {
-        let mut indicies = vec![];
-
-        let size = self.size.unwrap();
+    pub fn compute_synchronized_flash_step(&self) -> Option<u32> {
+        let mut energy_levels_buffer = self.energy_levels.clone();
+        let octopus_count = energy_levels_buffer.len();
+
+        // each step only depends on the one before, so coming back to an earlier state means the octopi are going
+        // round a loop that never synchronizes - comparing against a snapshot taken at every power of two finds it
+        let mut snapshot = energy_levels_buffer.clone();
+        let mut next_snapshot = 1;
+        let mut step_number = 0;
+        loop {
+            step_number += 1;
+            if step(&mut energy_levels_buffer) == octopus_count {
+                return Some(step_number);
+            }
+            if energy_levels_buffer == snapshot {
+                return None;
+            }
+            if step_number == next_snapshot {
+                snapshot = energy_levels_buffer.clone();
+                next_snapshot *= 2;
+            }
+        }
+    }
+}
 
-        let row = (center / size) as isize;
-        let column = (center % size) as isize;
+// advances every octopus by one step and returns how many of them flashed
+fn step(energy_levels: &mut Grid<u32>) -> usize {
+    let mut has_flashed_octopi = vec![];
+    let mut just_flash_octopi = vec![];
+    for (octopus, energy) in energy_levels.positions().zip(energy_levels.values_mut()) {
+        *energy += 1;
+        if *energy > 9 {
+            has_flashed_octopi.push(octopus);
+            just_flash_octopi.push(octopus);
+        }
+    }
+    while !just_flash_octopi.is_empty() {
+        let mut newly_flashed_octopi = vec![];
 
-        for x_delta in -1..=1 {
-            for y_delta in -1..=1 {
-                let x = column + x_delta;
-                let y = row + y_delta;
-                if x >= 0 && (x as usize) < size && y >= 0 && (y as usize) < size {
-                    let index = (x as usize) + (y as usize) * size;
-                    indicies.push(index);
+        for flashed_octopus in just_flash_octopi.iter() {
+            let adjacent_octopi = energy_levels.neighbours_8(*flashed_octopus).collect::<Vec<Position>>();
+            for adjacent_octopus in adjacent_octopi.into_iter() {
+                let energy = &mut energy_levels[adjacent_octopus];
+                *energy += 1;
+                if *energy > 9 && !has_flashed_octopi.contains(&adjacent_octopus) {
+                    has_flashed_octopi.push(adjacent_octopus);
+                    newly_flashed_octopi.push(adjacent_octopus);
                 }
             }
         }
 
-        indicies
+        just_flash_octopi = newly_flashed_octopi;
+    }
+
+    for has_flashed in has_flashed_octopi.iter() {
+        energy_levels[*has_flashed] = 0;
     }
+
+    has_flashed_octopi.len()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_demo() {
+    fn demo() -> OctopusGrid {
         let mut grid = OctopusGrid::new();
         let rows =
 "5483143223
@@ -117,8 +139,42 @@ mod tests {
             grid.add_row(row);
         }
 
+        grid
+    }
+
+    #[test]
+    fn test_demo() {
+        let grid = demo();
+
         assert_eq!(0, grid.compute_flashes(1));
         assert_eq!(204, grid.compute_flashes(10));
         assert_eq!(1656, grid.compute_flashes(100));
     }
+
+    #[test]
+    fn test_demo_synchronized() {
+        let grid = demo();
+
+        assert_eq!(Some(195), grid.compute_synchronized_flash_step());
+    }
+
+    #[test]
+    fn test_frame() {
+        let mut grid = demo();
+        grid.step();
+        grid.step();
+
+        let frame = grid.to_frame("Step 2");
+        assert_eq!("Step 2\n8807476555\n5089087054\n8597889608\n8485769600\n8700908800\n6600088989\n6800005943\n0000007456\n9000000876\n8700006848\n", frame.to_string());
+        assert_eq!(Colour::Bright, frame.cells()[Position::new(2, 0)].colour());
+    }
+
+    #[test]
+    fn test_never_synchronized() {
+        // the second octopus always flashes five steps after the first without ever pulling it along
+        let mut grid = OctopusGrid::new();
+        grid.add_row(vec![0, 5]);
+
+        assert_eq!(None, grid.compute_synchronized_flash_step());
+    }
 }
\ No newline at end of file
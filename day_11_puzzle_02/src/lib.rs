@@ -0,0 +1,35 @@
+pub use day_11_puzzle_01::octopus_grid;
+
+use aoc_common::{Puzzle, Answer, Error, Frame};
+use octopus_grid::OctopusGrid;
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    type Input = OctopusGrid;
+
+    fn parse(input: &str) -> Result<OctopusGrid, Error> {
+        Ok(OctopusGrid::from(aoc_common::parse_digit_grid(input)?))
+    }
+
+    fn part2(grid: &OctopusGrid) -> Result<Answer, Error> {
+        match grid.compute_synchronized_flash_step() {
+            Some(step) => Ok(step.into()),
+            None => Err(Error::NoSolution(String::from("The octopi never synchronize their flashes"))),
+        }
+    }
+}
+
+/// Every step up to the first one where all of the octopi flash together, or the first 100 when they never do.
+pub fn visualize(input: &str) -> Result<Vec<Frame>, Error> {
+    let mut grid = Day11::parse(input)?;
+    let last_step = grid.compute_synchronized_flash_step().unwrap_or(100);
+
+    let mut frames = vec![grid.to_frame("Step 0")];
+    for step in 1..=last_step {
+        let flashes = grid.step();
+        frames.push(grid.to_frame(&format!("Step {} - {} flashed", step, flashes)));
+    }
+
+    Ok(frames)
+}
@@ -1,33 +1,16 @@
-mod octopus_grid;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use crate::octopus_grid::OctopusGrid;
+use aoc_common::InputSource;
+use day_11_puzzle_02::Day11;
 
 fn main() -> std::io::Result<()> {
-
-    let file = File::open("./day_11_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
-
-    let mut grid = OctopusGrid::new();
-
-    for line in lines {
-        if let Ok(entry) = line {
-            if entry.trim().is_empty() {
-                continue;
-            }
-            grid.add_row(entry.trim().chars()
-            .map(|c| u32::from_str_radix(&c.to_string(), 10).unwrap())
-            .collect::<Vec<u32>>());
-        }
+    let input = InputSource::from_args("./day_11_puzzle_01/input.txt").read_to_string()?;
+
+    match aoc_common::solve_part2::<Day11>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
-    if let Some(step) = grid.compute_synchronized_flash_step() {
-        println!("All octopi synchronize their flashes on step {}", step);
-    } else {
-        println!("The octopi never synchronize their flashes");
-    }
-
-
     Ok(())
 }
\ No newline at end of file
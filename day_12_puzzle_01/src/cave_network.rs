@@ -1,6 +1,7 @@
 use std::rc::Rc;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use aoc_common::{Cursor, Error, ParseError};
 
 #[derive(PartialEq)]
 pub enum CaveSize {
@@ -20,7 +21,7 @@ impl CaveSize {
     }
 }
 
-type CaveRef<'input> = Rc<RefCell<Cave<'input>>>;
+pub type CaveRef<'input> = Rc<RefCell<Cave<'input>>>;
 
 pub struct Cave<'input> {
     name: &'input str,
@@ -37,6 +38,18 @@ impl<'input> Cave<'input> {
         }))
     }
 
+    pub fn name(&self) -> &'input str {
+        self.name
+    }
+
+    pub fn size(&self) -> &CaveSize {
+        &self.size
+    }
+
+    pub fn connections(&self) -> &[CaveRef<'input>] {
+        &self.connections
+    }
+
     pub fn add_connection(&mut self, other_cave: CaveRef<'input>) {
         self.connections.push(other_cave);
     }
@@ -68,17 +81,21 @@ pub fn find_paths<'input>(start: CaveRef<'input>, end: CaveRef<'input>) -> Vec<V
     start_to_end_paths
 }
 
-pub fn create_cave_network<'input>(connection_inputs: Vec<&'input str>) -> HashMap<&str, CaveRef<'input>> {
-    let mut all_caves = HashMap::new();
+/// Reads a connection like `start-A` into the names of the two caves it joins.
+pub fn parse_connection(input: &str) -> Result<(&str, &str), ParseError> {
+    let mut cursor = Cursor::new(input);
+    let start = cursor.word("a cave name")?;
+    cursor.expect("-")?;
+    let end = cursor.word("a cave name")?;
+    cursor.end()?;
 
-    for connection_input in connection_inputs.iter() {
-        let mut split = connection_input.split("-");
-        let start = split.next().unwrap();
-        let end = split.next().unwrap();
-        if let Some(_) = split.next() {
-            panic!("connection inputs should only have two components (start to end) - found extra component(s) in '{}'", connection_input);
-        }
+    Ok((start, end))
+}
+
+pub fn create_cave_network<'input>(connections: Vec<(&'input str, &'input str)>) -> HashMap<&'input str, CaveRef<'input>> {
+    let mut all_caves = HashMap::new();
 
+    for (start, end) in connections {
         all_caves.entry(start).or_insert(Cave::new(start));
         all_caves.entry(end).or_insert(Cave::new(end));
 
@@ -92,6 +109,13 @@ pub fn create_cave_network<'input>(connection_inputs: Vec<&'input str>) -> HashM
     all_caves
 }
 
+/// The `start` and `end` caves, which every path runs between.
+pub fn start_and_end<'input>(network: &HashMap<&'input str, CaveRef<'input>>) -> Result<(CaveRef<'input>, CaveRef<'input>), Error> {
+    let find = |name: &str| network.get(name).cloned().ok_or_else(|| Error::NoSolution(format!("There is no cave called '{}'", name)));
+
+    Ok((find("start")?, find("end")?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,13 +202,13 @@ mod tests {
     #[test]
     fn test_create_demo_1() {
         let network = create_cave_network(vec![
-            "start-A",
-            "start-b",
-            "A-c",
-            "A-b",
-            "b-d",
-            "A-end",
-            "b-end"
+            ("start", "A"),
+            ("start", "b"),
+            ("A", "c"),
+            ("A", "b"),
+            ("b", "d"),
+            ("A", "end"),
+            ("b", "end")
         ]);
 
         let start_cave = network["start"].clone();
@@ -211,16 +235,16 @@ mod tests {
     #[test]
     fn test_create_demo_2() {
         let network = create_cave_network(vec![
-            "dc-end",
-            "HN-start",
-            "start-kj",
-            "dc-start",
-            "dc-HN",
-            "LN-dc",
-            "HN-end",
-            "kj-sa",
-            "kj-HN",
-            "kj-dc"
+            ("dc", "end"),
+            ("HN", "start"),
+            ("start", "kj"),
+            ("dc", "start"),
+            ("dc", "HN"),
+            ("LN", "dc"),
+            ("HN", "end"),
+            ("kj", "sa"),
+            ("kj", "HN"),
+            ("kj", "dc")
         ]);
 
         let start_cave = network["start"].clone();
@@ -256,24 +280,24 @@ mod tests {
     #[test]
     fn test_create_demo_3() {
         let network = create_cave_network(vec![
-            "fs-end",
-            "he-DX",
-            "fs-he",
-            "start-DX",
-            "pj-DX",
-            "end-zg",
-            "zg-sl",
-            "zg-pj",
-            "pj-he",
-            "RW-he",
-            "fs-DX",
-            "pj-RW",
-            "zg-RW",
-            "start-pj",
-            "he-WI",
-            "zg-he",
-            "pj-fs",
-            "start-RW"
+            ("fs", "end"),
+            ("he", "DX"),
+            ("fs", "he"),
+            ("start", "DX"),
+            ("pj", "DX"),
+            ("end", "zg"),
+            ("zg", "sl"),
+            ("zg", "pj"),
+            ("pj", "he"),
+            ("RW", "he"),
+            ("fs", "DX"),
+            ("pj", "RW"),
+            ("zg", "RW"),
+            ("start", "pj"),
+            ("he", "WI"),
+            ("zg", "he"),
+            ("pj", "fs"),
+            ("start", "RW")
         ]);
 
         let start_cave = network["start"].clone();
@@ -282,4 +306,18 @@ mod tests {
         let paths = find_paths(start_cave.clone(), end_cave.clone());
         assert_eq!(226, paths.len());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_connection() {
+        assert_eq!(Ok(("start", "A")), parse_connection("start-A"));
+        assert_eq!(Err(ParseError::new(7, "'-'", "A")), parse_connection("start A"));
+        assert_eq!(Err(ParseError::new(4, "end of line", "-d")), parse_connection("b-c-d"));
+        assert_eq!(Err(ParseError::new(1, "a cave name", "-end")), parse_connection("-end"));
+    }
+
+    #[test]
+    fn test_start_and_end() {
+        let network = create_cave_network(vec![("start", "A"), ("A", "b")]);
+        assert_eq!(Some(Error::NoSolution(String::from("There is no cave called 'end'"))), start_and_end(&network).err());
+    }
+}
@@ -0,0 +1,22 @@
+pub mod cave_network;
+
+use aoc_common::{Puzzle, Answer, Error};
+
+pub struct Day12;
+
+impl Puzzle for Day12 {
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Result<Vec<(String, String)>, Error> {
+        aoc_common::parse_lines(input, |line| {
+            cave_network::parse_connection(line).map(|(start, end)| (String::from(start), String::from(end)))
+        })
+    }
+
+    fn part1(connections: &Vec<(String, String)>) -> Result<Answer, Error> {
+        let network = cave_network::create_cave_network(connections.iter().map(|(s, e)| (s.as_str(), e.as_str())).collect());
+        let (start, end) = cave_network::start_and_end(&network)?;
+
+        Ok(cave_network::find_paths(start, end).len().into())
+    }
+}
@@ -1,107 +1,56 @@
-use std::rc::Rc;
-use std::cell::RefCell;
-use std::collections::HashMap;
-
-#[derive(PartialEq)]
-pub enum CaveSize {
-    Small,
-    Big,
-}
-
-impl CaveSize {
-    pub fn from(name: &str) -> CaveSize {
-        if name.chars().filter(|c| c.is_ascii_uppercase()).count() == name.len() {
-            // all uppercase name means LARGE
-            CaveSize::Big
-        } else {
-            // otherwise means small
-            CaveSize::Small
-        }
-    }
-}
-
-type CaveRef<'input> = Rc<RefCell<Cave<'input>>>;
-
-pub struct Cave<'input> {
-    name: &'input str,
-    size: CaveSize,
-    connections: Vec<CaveRef<'input>>,
-}
-
-impl<'input> Cave<'input> {
-    pub fn new(name: &str) -> Rc<RefCell<Cave>> {
-        Rc::new(RefCell::new(Cave {
-            name,
-            size: CaveSize::from(name),
-            connections: vec![],
-        }))
-    }
-
-    pub fn add_connection(&mut self, other_cave: CaveRef<'input>) {
-        self.connections.push(other_cave);
-    }
-}
+use std::collections::{HashMap, HashSet};
+use aoc_common::Pool;
+pub use day_12_puzzle_01::cave_network::{Cave, CaveRef, CaveSize, create_cave_network, parse_connection, start_and_end};
 
 pub fn find_paths<'input>(network: &HashMap<&str, CaveRef<'input>>, start: CaveRef<'input>, end: CaveRef<'input>) -> Vec<Vec<CaveRef<'input>>> {
-    let mut start_to_end_paths: HashMap<String,Vec<CaveRef<'input>>> = HashMap::new();
-
-    for duplicate_allowed in network.values().filter(|c| c.borrow().name != "start" && c.borrow().name != "end" && c.borrow().size == CaveSize::Small) {
-        let mut cave_paths: Vec<Vec<CaveRef<'input>>> = vec![];
-
-        cave_paths.push(vec![start.clone()]);
-
-        while cave_paths.len() > 0 {
-            let current_path = cave_paths.pop().unwrap();
-            for next_cave in current_path.last().unwrap().borrow().connections.iter() {
-                let allowed_duplicate_count = if next_cave.borrow().name == duplicate_allowed.borrow().name {
-                    1
-                } else {
-                    0
-                };
-                if next_cave.borrow().size == CaveSize::Small && current_path.iter().filter(|c| c.borrow().name == next_cave.borrow().name).count() > allowed_duplicate_count  {
-                    continue;
-                }
-                let mut next_path = current_path.clone();
-                next_path.push(next_cave.clone());
-                if next_cave.borrow().name == end.borrow().name {
-                    // because we are looping for each small cave (excluding start and end) we need to exclude duplicates
-                    start_to_end_paths.entry(write_path(&next_path)).or_insert(next_path);
-                } else {
-                    cave_paths.push(next_path);
-                }
-            }
-        }
-    }
-
-    start_to_end_paths.into_iter().map(|(_, p)| p.clone()).collect::<Vec<Vec<CaveRef<'input>>>>()
-}
-
-fn write_path<'input>(path: &Vec<CaveRef<'input>>) -> String {
-    path.iter().map(|c| c.borrow().name).collect::<Vec<&str>>().join(",")
+    // the caves can't be shared between threads, so the search for each small cave runs over their indices
+    let caves: Vec<CaveRef<'input>> = network.values().cloned().collect();
+    let names: Vec<&str> = caves.iter().map(|c| c.borrow().name()).collect();
+    let index_of = |name: &str| names.iter().position(|&n| n == name).unwrap();
+    let connections: Vec<Vec<usize>> = caves.iter()
+        .map(|c| c.borrow().connections().iter().map(|other| index_of(other.borrow().name())).collect())
+        .collect();
+    let small: Vec<bool> = caves.iter().map(|c| *c.borrow().size() == CaveSize::Small).collect();
+    let start = index_of(start.borrow().name());
+    let end = index_of(end.borrow().name());
+
+    let duplicates_allowed: Vec<usize> = (0..caves.len()).filter(|&i| names[i] != "start" && names[i] != "end" && small[i]).collect();
+    let found = Pool::configured().map(&duplicates_allowed, |&duplicate_allowed| {
+        paths_with_duplicate(&connections, &small, start, end, duplicate_allowed)
+    });
+
+    // because we are looping for each small cave (excluding start and end) we need to exclude duplicates
+    let start_to_end_paths: HashSet<Vec<usize>> = found.into_iter().flatten().collect();
+
+    start_to_end_paths.into_iter().map(|p| p.into_iter().map(|i| caves[i].clone()).collect()).collect::<Vec<Vec<CaveRef<'input>>>>()
 }
 
-pub fn create_cave_network<'input>(connection_inputs: Vec<&'input str>) -> HashMap<&str, CaveRef<'input>> {
-    let mut all_caves = HashMap::new();
-
-    for connection_input in connection_inputs.iter() {
-        let mut split = connection_input.split("-");
-        let start = split.next().unwrap();
-        let end = split.next().unwrap();
-        if let Some(_) = split.next() {
-            panic!("connection inputs should only have two components (start to end) - found extra component(s) in '{}'", connection_input);
+/// Every path from start to end that visits small caves at most once, apart from `duplicate_allowed` which can be visited twice.
+fn paths_with_duplicate(connections: &[Vec<usize>], small: &[bool], start: usize, end: usize, duplicate_allowed: usize) -> Vec<Vec<usize>> {
+    let mut start_to_end_paths = vec![];
+    let mut cave_paths: Vec<Vec<usize>> = vec![vec![start]];
+
+    while let Some(current_path) = cave_paths.pop() {
+        for &next_cave in connections[*current_path.last().unwrap()].iter() {
+            let allowed_duplicate_count = if next_cave == duplicate_allowed {
+                1
+            } else {
+                0
+            };
+            if small[next_cave] && current_path.iter().filter(|&&c| c == next_cave).count() > allowed_duplicate_count {
+                continue;
+            }
+            let mut next_path = current_path.clone();
+            next_path.push(next_cave);
+            if next_cave == end {
+                start_to_end_paths.push(next_path);
+            } else {
+                cave_paths.push(next_path);
+            }
         }
-
-        all_caves.entry(start).or_insert(Cave::new(start));
-        all_caves.entry(end).or_insert(Cave::new(end));
-
-        let start_cave = all_caves[start].clone();
-        let end_cave = all_caves[end].clone();
-
-        start_cave.borrow_mut().add_connection(end_cave.clone());
-        end_cave.borrow_mut().add_connection(start_cave.clone());
     }
 
-    all_caves
+    start_to_end_paths
 }
 
 #[cfg(test)]
@@ -111,13 +60,13 @@ mod tests {
     #[test]
     fn test_create_demo_1() {
         let network = create_cave_network(vec![
-            "start-A",
-            "start-b",
-            "A-c",
-            "A-b",
-            "b-d",
-            "A-end",
-            "b-end"
+            ("start", "A"),
+            ("start", "b"),
+            ("A", "c"),
+            ("A", "b"),
+            ("b", "d"),
+            ("A", "end"),
+            ("b", "end")
         ]);
 
         let start_cave = network["start"].clone();
@@ -130,16 +79,16 @@ mod tests {
     #[test]
     fn test_create_demo_2() {
         let network = create_cave_network(vec![
-            "dc-end",
-            "HN-start",
-            "start-kj",
-            "dc-start",
-            "dc-HN",
-            "LN-dc",
-            "HN-end",
-            "kj-sa",
-            "kj-HN",
-            "kj-dc"
+            ("dc", "end"),
+            ("HN", "start"),
+            ("start", "kj"),
+            ("dc", "start"),
+            ("dc", "HN"),
+            ("LN", "dc"),
+            ("HN", "end"),
+            ("kj", "sa"),
+            ("kj", "HN"),
+            ("kj", "dc")
         ]);
 
         let start_cave = network["start"].clone();
@@ -152,24 +101,24 @@ mod tests {
     #[test]
     fn test_create_demo_3() {
         let network = create_cave_network(vec![
-            "fs-end",
-            "he-DX",
-            "fs-he",
-            "start-DX",
-            "pj-DX",
-            "end-zg",
-            "zg-sl",
-            "zg-pj",
-            "pj-he",
-            "RW-he",
-            "fs-DX",
-            "pj-RW",
-            "zg-RW",
-            "start-pj",
-            "he-WI",
-            "zg-he",
-            "pj-fs",
-            "start-RW"
+            ("fs", "end"),
+            ("he", "DX"),
+            ("fs", "he"),
+            ("start", "DX"),
+            ("pj", "DX"),
+            ("end", "zg"),
+            ("zg", "sl"),
+            ("zg", "pj"),
+            ("pj", "he"),
+            ("RW", "he"),
+            ("fs", "DX"),
+            ("pj", "RW"),
+            ("zg", "RW"),
+            ("start", "pj"),
+            ("he", "WI"),
+            ("zg", "he"),
+            ("pj", "fs"),
+            ("start", "RW")
         ]);
 
         let start_cave = network["start"].clone();
@@ -0,0 +1,132 @@
+pub mod paper;
+
+use std::fmt::{self, Display};
+use aoc_common::{Puzzle, Answer, Error, ParseError, Cursor};
+use paper::{Paper, PaperBuilder};
+
+pub enum Fold {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl Fold {
+    pub fn apply(&self, paper: Paper) -> Result<Paper, Error> {
+        match *self {
+            Fold::Vertical(column) => paper.fold_vertical(column),
+            Fold::Horizontal(row) => paper.fold_horizontal(row),
+        }
+    }
+}
+
+impl Display for Fold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fold::Vertical(column) => write!(f, "fold along x={}", column),
+            Fold::Horizontal(row) => write!(f, "fold along y={}", row),
+        }
+    }
+}
+
+/// Reads a fold like `fold along x=5`.
+fn parse_fold(line: &str) -> Result<Fold, ParseError> {
+    let mut cursor = Cursor::new(line);
+    cursor.expect("fold along")?;
+    let axis = cursor.word("an axis (x or y)")?;
+    let axis_column = cursor.column() - axis.len();
+    cursor.expect("=")?;
+    let offset = cursor.number()?;
+    cursor.end()?;
+
+    match axis {
+        "x" => Ok(Fold::Vertical(offset)),
+        "y" => Ok(Fold::Horizontal(offset)),
+        _ => Err(ParseError::new(axis_column, "an axis (x or y)", axis)),
+    }
+}
+
+/// Reads a dot like `6,10`.
+fn parse_dot(line: &str) -> Result<(usize, usize), ParseError> {
+    let mut cursor = Cursor::new(line);
+    let x = cursor.number()?;
+    cursor.expect(",")?;
+    let y = cursor.number()?;
+    cursor.end()?;
+
+    Ok((x, y))
+}
+
+/// Reads the dots, one `x,y` per line, and then the folds to make. Every bad line is reported, not just the first.
+pub fn load_paper(input: &str) -> Result<(Paper, Vec<Fold>), Error> {
+    let mut builder = PaperBuilder::new();
+    let mut folds = vec![];
+    let mut errors = vec![];
+
+    for (number, line) in aoc_common::numbered_lines(input) {
+        if line.trim_start().starts_with("fold") {
+            match parse_fold(line) {
+                Ok(fold) => folds.push(fold),
+                Err(error) => errors.push(error.on_line(number)),
+            }
+        } else {
+            match parse_dot(line) {
+                Ok((x, y)) => builder.add_dot(x, y),
+                Err(error) => errors.push(error.on_line(number)),
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::Parse(errors));
+    }
+
+    Ok((builder.build(), folds))
+}
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    type Input = (Paper, Vec<Fold>);
+
+    fn parse(input: &str) -> Result<(Paper, Vec<Fold>), Error> {
+        load_paper(input)
+    }
+
+    fn part1((paper, folds): &(Paper, Vec<Fold>)) -> Result<Answer, Error> {
+        match folds.first() {
+            Some(fold) => Ok(fold.apply(paper.clone())?.len().into()),
+            None => Err(Error::NoSolution(String::from("The instructions do not contain any folds"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_errors() {
+        let errors = vec![
+            ParseError::new(1, "a number", "99999999999999999999").on_line(2),
+            ParseError::new(2, "','", "-1").on_line(3),
+            ParseError::new(12, "an axis (x or y)", "z").on_line(5),
+            ParseError::new(14, "a number", "").on_line(6),
+        ];
+        let input = "6,10\n99999999999999999999,1\n0-1\n\nfold along z=3\nfold along y=\n";
+        assert_eq!(Some(Error::Parse(errors)), load_paper(input).err());
+    }
+
+    #[test]
+    fn test_fold_errors() {
+        let (paper, folds) = load_paper("1,1\n\nfold along x=0\n").unwrap();
+        assert_eq!(
+            Some(Error::NoSolution(String::from("The dot at (1,1) folds past the left edge of the paper"))),
+            folds[0].apply(paper).err()
+        );
+
+        let (paper, folds) = load_paper("1,2\n\nfold along y=2\n").unwrap();
+        assert_eq!(
+            Some(Error::NoSolution(String::from("The fold along y=2 runs through the dot at (1,2)"))),
+            folds[0].apply(paper).err()
+        );
+    }
+}
@@ -1,3 +1,6 @@
+use aoc_common::{Grid, Position, Frame, Cell, Colour, Error};
+use crate::Fold;
+
 type Dot = (usize, usize);
 
 pub struct PaperBuilder {
@@ -20,6 +23,7 @@ impl PaperBuilder {
     }
 }
 
+#[derive(Clone)]
 pub struct Paper {
     dots: Vec<Dot>,
 }
@@ -35,15 +39,15 @@ impl Paper {
         self.dots.len()
     }
 
-    pub fn fold_horizontal(self, row: usize) -> Paper {
+    pub fn fold_horizontal(self, row: usize) -> Result<Paper, Error> {
         self.fold(row, fold_dot_horizontal)
     }
 
-    pub fn fold_vertical(self, column: usize) -> Paper {
+    pub fn fold_vertical(self, column: usize) -> Result<Paper, Error> {
         self.fold(column, fold_dot_vertical)
     }
 
-    fn fold(self, offset: usize, fold_dot: fn(Dot, usize) -> Dot) -> Paper {
+    fn fold(self, offset: usize, fold_dot: fn(Dot, usize) -> Result<Dot, Error>) -> Result<Paper, Error> {
         let mut folded_dots = vec![];
 
         let mut add_dot = |d: Dot| {
@@ -54,34 +58,83 @@ impl Paper {
 
         for dot in self.dots.into_iter() {
 
-            let folded_dot = fold_dot(dot, offset);
+            let folded_dot = fold_dot(dot, offset)?;
 
             add_dot(folded_dot);
         }
 
-        Paper::new(folded_dots)
+        Ok(Paper::new(folded_dots))
+    }
+
+    /// The dots on the paper, along with the line it is about to be folded on when there is one.
+    pub fn to_frame(&self, title: &str, fold: Option<&Fold>) -> Frame {
+        let mut width = self.dots.iter().map(|d| d.0 + 1).max().unwrap_or(0);
+        let mut height = self.dots.iter().map(|d| d.1 + 1).max().unwrap_or(0);
+        // the fold is always along the middle of the paper, even when there are no dots near the far edge
+        match fold {
+            Some(&Fold::Vertical(column)) => width = width.max(column * 2 + 1),
+            Some(&Fold::Horizontal(row)) => height = height.max(row * 2 + 1),
+            None => {},
+        }
+
+        let mut cells = Grid::filled(width, height, Cell::coloured('.', Colour::Dim));
+        for position in cells.positions().collect::<Vec<Position>>() {
+            match fold {
+                Some(&Fold::Vertical(column)) if position.x() == column => cells[position] = Cell::coloured('|', Colour::Yellow),
+                Some(&Fold::Horizontal(row)) if position.y() == row => cells[position] = Cell::coloured('-', Colour::Yellow),
+                _ => {},
+            }
+        }
+        for &(x, y) in self.dots.iter() {
+            cells[Position::new(x, y)] = Cell::coloured('#', Colour::Bright);
+        }
+
+        Frame::new(title, cells)
+    }
+
+    pub fn to_string(&self) -> String {
+        let width = self.dots.iter().map(|d| d.0 + 1).max().unwrap_or(0);
+        let height = self.dots.iter().map(|d| d.1 + 1).max().unwrap_or(0);
+
+        let mut output = String::new();
+        for row in 0..height {
+            for column in 0..width {
+                let dot = self.dots.iter().find(|d| d.0 == column && d.1 == row);
+                match dot {
+                    Some(_) => output.push_str("#"),
+                    None => output.push_str("."),
+                }
+            }
+            output.push_str("\n");
+        }
+
+        output
     }
 }
 
-fn fold_dot_horizontal(dot: Dot, row: usize) -> Dot {
+fn fold_dot_horizontal(dot: Dot, row: usize) -> Result<Dot, Error> {
     if dot.1 > row {
-        let offset = (dot.1 - row) * 2;
-        (dot.0, dot.1 - offset)
+        match row.checked_sub(dot.1 - row) {
+            Some(y) => Ok((dot.0, y)),
+            None => Err(Error::NoSolution(format!("The dot at ({},{}) folds past the top edge of the paper", dot.0, dot.1))),
+        }
     } else if dot.1 < row {
-        dot.clone()
+        Ok(dot)
     } else {
-        panic!("Folding on a dot is not supported - row {} intersects with dot ({},{})", row, dot.0, dot.1)
+        Err(Error::NoSolution(format!("The fold along y={} runs through the dot at ({},{})", row, dot.0, dot.1)))
     }
 }
 
-fn fold_dot_vertical(dot: Dot, column: usize) -> Dot {
+fn fold_dot_vertical(dot: Dot, column: usize) -> Result<Dot, Error> {
     if dot.0 > column {
-        let offset = (dot.0 - column) * 2;
-        (dot.0 - offset, dot.1)
+        match column.checked_sub(dot.0 - column) {
+            Some(x) => Ok((x, dot.1)),
+            None => Err(Error::NoSolution(format!("The dot at ({},{}) folds past the left edge of the paper", dot.0, dot.1))),
+        }
     } else if dot.0 < column {
-        dot.clone()
+        Ok(dot)
     } else {
-        panic!("Folding on a dot is not supported - column {} intersects with dot ({},{})", column, dot.0, dot.1)
+        Err(Error::NoSolution(format!("The fold along x={} runs through the dot at ({},{})", column, dot.0, dot.1)))
     }
 }
 
@@ -112,7 +165,7 @@ mod tests {
         builder.add_dot(9, 0);
 
         let paper = builder.build();
-        let folded = paper.fold_horizontal(7);
+        let folded = paper.fold_horizontal(7).unwrap();
 
         assert_eq!(17, folded.dots.len());
     }
@@ -139,7 +192,7 @@ mod tests {
         builder.add_dot(10, 4);
 
         let paper = builder.build();
-        let folded = paper.fold_vertical(5);
+        let folded = paper.fold_vertical(5).unwrap();
 
         assert_eq!(16, folded.dots.len());
     }
@@ -0,0 +1,86 @@
+pub use day_13_puzzle_01::{paper, Fold, load_paper};
+
+use aoc_common::{Puzzle, Answer, Error, Frame, Picture, Raster, Rgb};
+use paper::Paper;
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    type Input = (Paper, Vec<Fold>);
+
+    fn parse(input: &str) -> Result<(Paper, Vec<Fold>), Error> {
+        load_paper(input)
+    }
+
+    fn part2((paper, folds): &(Paper, Vec<Fold>)) -> Result<Answer, Error> {
+        let mut paper = paper.clone();
+        for fold in folds.iter() {
+            paper = fold.apply(paper)?;
+        }
+
+        if paper.len() == 0 {
+            return Err(Error::NoSolution(String::from("There are no dots on the paper to read a code from")));
+        }
+
+        Ok(paper.to_string().into())
+    }
+}
+
+/// The paper before each fold, showing the line it is folded along, and then once it is fully folded.
+pub fn visualize(input: &str) -> Result<Vec<Frame>, Error> {
+    let (mut paper, folds) = load_paper(input)?;
+
+    let mut frames = vec![];
+    for (i, fold) in folds.iter().enumerate() {
+        frames.push(paper.to_frame(&format!("Fold {} of {} - {}", i + 1, folds.len(), fold), Some(fold)));
+        paper = fold.apply(paper)?;
+    }
+    frames.push(paper.to_frame("Folded", None));
+
+    Ok(frames)
+}
+
+/// The fully folded paper, with the dots in white.
+pub fn export(input: &str) -> Result<Picture, Error> {
+    let (mut paper, folds) = load_paper(input)?;
+    for fold in folds.iter() {
+        paper = fold.apply(paper)?;
+    }
+
+    let frame = paper.to_frame("", None);
+    let raster = Raster::from_grid(frame.cells(), |_, cell| if cell.symbol() == '#' { Rgb::WHITE } else { Rgb::BLACK });
+    Ok(Picture::Raster(raster.scaled(8)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visualize() {
+        let input = "6,10\n0,14\n9,10\n0,3\n10,4\n4,11\n6,0\n6,12\n4,1\n0,13\n10,12\n3,4\n3,0\n8,4\n1,10\n2,14\n8,10\n9,0\n\nfold along y=7\nfold along x=5\n";
+        let frames = visualize(input).unwrap();
+
+        assert_eq!(3, frames.len());
+        assert_eq!("Fold 1 of 2 - fold along y=7", frames[0].title());
+        assert_eq!((11, 15), (frames[0].cells().width(), frames[0].cells().height()));
+        assert_eq!("-----------", frames[0].to_string().lines().nth(8).unwrap());
+        assert_eq!(
+            "Fold 2 of 2 - fold along x=5\n#.##.|#..#.\n#...#|.....\n.....|#...#\n#...#|.....\n.#.#.|#.###\n",
+            frames[1].to_string()
+        );
+        assert_eq!("Folded\n#####\n#...#\n#...#\n#...#\n#####\n", frames[2].to_string());
+    }
+
+    #[test]
+    fn test_export() {
+        let input = "0,0\n2,0\n1,1\n\nfold along y=3\n";
+        let mut output = vec![];
+        export(input).unwrap().write(aoc_common::ImageFormat::Pgm, &mut output).unwrap();
+
+        assert!(output.starts_with(b"P5\n24 16\n255\n"));
+        assert_eq!(13 + 24 * 16, output.len());
+        // the first row of dots, eight pixels to a dot
+        assert_eq!([[255; 8], [0; 8], [255; 8]].concat(), output[13..13 + 24]);
+    }
+}
@@ -0,0 +1,47 @@
+pub mod polymer;
+
+use aoc_common::{Puzzle, Answer, Error, ParseError, Cursor};
+use polymer::PairInsertionRule;
+
+/// Reads the starting polymer, a run of elements like `NNCB`.
+fn parse_template(line: &str) -> Result<String, ParseError> {
+    let mut cursor = Cursor::new(line);
+    let template = cursor.word("a polymer template")?;
+    cursor.end()?;
+
+    Ok(template.to_string())
+}
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    type Input = (String, Vec<PairInsertionRule>);
+
+    fn parse(input: &str) -> Result<(String, Vec<PairInsertionRule>), Error> {
+        let mut lines = aoc_common::numbered_lines(input);
+        let (number, template) = lines.next().ok_or_else(|| ParseError::new(1, "a polymer template", ""))?;
+        let template = parse_template(template).map_err(|error| error.on_line(number))?;
+
+        Ok((template.to_string(), aoc_common::parse_numbered_lines(lines, PairInsertionRule::from)?))
+    }
+
+    fn part1((template, rules): &(String, Vec<PairInsertionRule>)) -> Result<Answer, Error> {
+        let mut polymer = template.clone();
+        for _ in 1..=10 {
+            polymer = polymer::polymerization(&polymer, rules);
+        }
+
+        Ok(polymer::score_polymer(&polymer).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_template() {
+        assert_eq!(Some(Error::Parse(vec![ParseError::new(3, "end of line", "-x").on_line(2)])), Day14::parse("\nNN-x\n").err());
+        assert_eq!(Some(Error::Parse(vec![ParseError::new(1, "a polymer template", "")])), Day14::parse("").err());
+    }
+}
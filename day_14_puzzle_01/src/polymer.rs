@@ -1,30 +1,56 @@
 use std::collections::HashMap;
 use std::cmp;
+use aoc_common::{ParseError, Cursor};
 
-pub struct PairInsertionRule<'input> {
-    pair: &'input str,
+pub struct PairInsertionRule {
+    pair: String,
     element: char,
 }
 
-impl<'input> PairInsertionRule<'input> {
-    pub fn from(input: &'input str) -> PairInsertionRule<'input> {
-        let mut split = input.split("->");
-        let pair = split.next().unwrap().trim();
-        let element = split.next().unwrap().trim().chars().next().unwrap();
-        PairInsertionRule {
+impl PairInsertionRule {
+    /// Parses a rule like `CH -> B`.
+    pub fn from(input: &str) -> Result<PairInsertionRule, ParseError> {
+        let mut cursor = Cursor::new(input);
+        let pair = String::from(cursor.letters(2, "a pair of elements")?);
+        cursor.expect("->")?;
+        let element = cursor.letters(1, "an element")?.chars().next().unwrap();
+        cursor.end()?;
+        Ok(PairInsertionRule {
             pair,
             element,
-        }
+        })
+    }
+
+    pub fn pair(&self) -> &str {
+        &self.pair
+    }
+
+    pub fn element(&self) -> char {
+        self.element
+    }
+
+    pub fn split(&self) -> Vec<String> {
+        let mut pair_chars = self.pair.chars();
+        
+        let mut left_split = String::new();
+        left_split.push(pair_chars.next().unwrap());
+        left_split.push(self.element);
+
+        let mut right_split = String::new();
+        right_split.push(self.element);
+        right_split.push(pair_chars.next().unwrap());
+
+        vec![left_split, right_split]
     }
 }
 
-pub fn polymerization(input_polymer: &str, insertion_rules: &Vec<PairInsertionRule>) -> String {
+pub fn polymerization(input_polymer: &str, insertion_rules: &[PairInsertionRule]) -> String {
     let mut todo_insertions: Vec<Insertion> = vec![];
     let mut done_insertions: Vec<Insertion> = vec![];
 
     for rule in insertion_rules.iter() {
         let mut start = 0;
-        while let Some(m) = input_polymer[start..].find(rule.pair) {
+        while let Some(m) = input_polymer[start..].find(rule.pair()) {
             let index = start + m + 1;
             todo_insertions.push(Insertion { index, element: rule.element });
             start = index;
@@ -75,9 +101,9 @@ mod tests {
         let template = "NNCB";
 
         let rules = vec![
-            PairInsertionRule { pair: "NN", element: 'C' },
-            PairInsertionRule { pair: "NC", element: 'B' },
-            PairInsertionRule { pair: "CB", element: 'H' },
+            PairInsertionRule { pair: String::from("NN"), element: 'C' },
+            PairInsertionRule { pair: String::from("NC"), element: 'B' },
+            PairInsertionRule { pair: String::from("CB"), element: 'H' },
         ];
 
         let polymer = polymerization(&template, &rules);
@@ -108,7 +134,7 @@ CC -> N
 CN -> C"
         .split("\n")
         .filter(|s| !s.is_empty())
-        .map(|s| PairInsertionRule::from(s))
+        .map(|s| PairInsertionRule::from(s).unwrap())
         .collect();
 
         let polymer = polymerization(&template, &rules);
@@ -132,4 +158,11 @@ CN -> C"
 
         assert_eq!(1588, score_polymer(&polymer));
     }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Err(ParseError::new(1, "a pair of elements", "CHH")), PairInsertionRule::from("CHH -> B").map(|_| ()));
+        assert_eq!(Err(ParseError::new(4, "'->'", "B")), PairInsertionRule::from("CH B").map(|_| ()));
+        assert_eq!(Err(ParseError::new(7, "an element", "")), PairInsertionRule::from("CH -> ").map(|_| ()));
+    }
 }
\ No newline at end of file
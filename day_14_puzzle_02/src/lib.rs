@@ -0,0 +1,23 @@
+pub mod polymer;
+
+use aoc_common::{Puzzle, Answer, Error};
+use polymer::{PolymerCounts, PairInsertionRule};
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    type Input = (String, Vec<PairInsertionRule>);
+
+    fn parse(input: &str) -> Result<(String, Vec<PairInsertionRule>), Error> {
+        day_14_puzzle_01::Day14::parse(input)
+    }
+
+    fn part2((template, rules): &(String, Vec<PairInsertionRule>)) -> Result<Answer, Error> {
+        let mut polymer = PolymerCounts::from(template);
+        for _ in 1..=40 {
+            polymer = polymer.apply(rules)?;
+        }
+
+        Ok(polymer.score()?.into())
+    }
+}
@@ -1,45 +1,8 @@
 use std::collections::HashMap;
 use std::cmp;
 use std::fmt::{self, Display};
-
-pub struct PairInsertionRule {
-    pair: String,
-    element: char,
-}
-
-impl PairInsertionRule {
-    pub fn from(input: &str) -> PairInsertionRule {
-        let mut split = input.split("->");
-        let pair = split.next().unwrap().trim();
-        if pair.chars().count() != 2 {
-            panic!("Elements should consist of exactly two characters - found '{}' of length {}", pair, pair.chars().count());
-        }
-        let pair = String::from(pair);
-        let element = split.next().unwrap().trim();
-        if element.chars().count() != 1 {
-            panic!("Elements should consist of exactly one character - found '{}' of length {}", element, element.chars().count());
-        }
-        let element = element.chars().next().unwrap();
-        PairInsertionRule {
-            pair,
-            element,
-        }
-    }
-
-    pub fn split(&self) -> Vec<String> {
-        let mut pair_chars = self.pair.chars();
-        
-        let mut left_split = String::new();
-        left_split.push(pair_chars.next().unwrap());
-        left_split.push(self.element);
-
-        let mut right_split = String::new();
-        right_split.push(self.element);
-        right_split.push(pair_chars.next().unwrap());
-
-        vec![left_split, right_split]
-    }
-}
+use aoc_common::{Checked, Error};
+pub use day_14_puzzle_01::polymer::PairInsertionRule;
 
 #[derive(PartialEq, Debug)]
 pub struct PolymerCounts {
@@ -52,8 +15,9 @@ impl PolymerCounts {
         let mut pair_counts = HashMap::new();
         let mut element_counts = HashMap::new();
 
-        for i in 0..input.chars().count() - 1 {
-            let pair = String::from(&input[i..i+2]);
+        let elements: Vec<char> = input.chars().collect();
+        for pair in elements.windows(2) {
+            let pair = String::from_iter(pair);
             let count = pair_counts.entry(pair).or_insert(0);
             *count += 1;
         }
@@ -69,37 +33,41 @@ impl PolymerCounts {
         }
     }
 
-    pub fn apply(self, rules: &Vec<PairInsertionRule>) -> PolymerCounts {
+    pub fn apply(self, rules: &[PairInsertionRule]) -> Result<PolymerCounts, Error> {
         let mut new_pair_counts = HashMap::new();
         let mut new_element_counts = self.element_counts.clone();
 
         for (pair, count) in self.pair_counts.into_iter() {
             let mut split_pair = false;
             for rule in rules.iter() {
-                if pair == rule.pair {
+                if pair == rule.pair() {
                     split_pair = true;
                     for insert in rule.split() {
                         let pair_count = new_pair_counts.entry(insert).or_insert(0);
-                        *pair_count += count;
+                        *pair_count = pair_count.add_checked(count, "pair count + inserted pairs")?;
                     }
-                    let element_count = new_element_counts.entry(rule.element).or_insert(0);
-                    *element_count += count;
+                    let element_count = new_element_counts.entry(rule.element()).or_insert(0);
+                    *element_count = element_count.add_checked(count, "element count + inserted elements")?;
                 }
             }
             // no matching rule found, stays as-is
             if !split_pair {
                 let pair_count = new_pair_counts.entry(pair).or_insert(0);
-                *pair_count += count;
+                *pair_count = pair_count.add_checked(count, "pair count + unchanged pairs")?;
             }
         }
 
-        PolymerCounts {
+        Ok(PolymerCounts {
             pair_counts: new_pair_counts,
             element_counts: new_element_counts,
-        }
+        })
     }
 
-    pub fn score(&self) -> u64 {
+    pub fn score(&self) -> Result<u64, Error> {
+        if self.element_counts.is_empty() {
+            return Err(Error::NoSolution(String::from("The polymer has no elements to score")));
+        }
+
         let mut max = u64::MIN;
         let mut min = u64::MAX;
     
@@ -108,7 +76,7 @@ impl PolymerCounts {
             min = cmp::min(min, *count);
         }
 
-        max - min 
+        max.sub_checked(min, "most common count - least common count")
     }
 
     
@@ -157,6 +125,11 @@ mod tests {
         assert_eq!(Some(1), counts.count("NC".to_string()));
         assert_eq!(Some(1), counts.count("CB".to_string()));
     }
+
+    #[test]
+    fn test_empty_polymer() {
+        assert_eq!(Err(Error::NoSolution(String::from("The polymer has no elements to score"))), PolymerCounts::from("").score());
+    }
     
     #[test]
     fn test_demo_1() {
@@ -179,16 +152,16 @@ CC -> N
 CN -> C"
         .split("\n")
         .filter(|s| !s.is_empty())
-        .map(|s| PairInsertionRule::from(s))
+        .map(|s| PairInsertionRule::from(s).unwrap())
         .collect();
 
         let mut polymer_counts = PolymerCounts::from("NNCB");
 
         for _ in 0..10 {
-            polymer_counts = polymer_counts.apply(&rules);
+            polymer_counts = polymer_counts.apply(&rules).unwrap();
         }
 
-        assert_eq!(1588, polymer_counts.score());
+        assert_eq!(Ok(1588), polymer_counts.score());
     }
 
     #[test]
@@ -212,15 +185,28 @@ CC -> N
 CN -> C"
         .split("\n")
         .filter(|s| !s.is_empty())
-        .map(|s| PairInsertionRule::from(s))
+        .map(|s| PairInsertionRule::from(s).unwrap())
         .collect();
 
         let mut polymer_counts = PolymerCounts::from("NNCB");
 
         for _ in 0..40 {
-            polymer_counts = polymer_counts.apply(&rules);
+            polymer_counts = polymer_counts.apply(&rules).unwrap();
         }
 
-        assert_eq!(2188189693529, polymer_counts.score());
+        assert_eq!(Ok(2188189693529), polymer_counts.score());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_overflow() {
+        let rules = vec![PairInsertionRule::from("NN -> N").unwrap()];
+
+        // the count of NN pairs doubles every step, so it runs out of room after 64 of them
+        let mut polymer_counts = PolymerCounts::from("NN");
+        for _ in 0..63 {
+            polymer_counts = polymer_counts.apply(&rules).unwrap();
+        }
+
+        assert_eq!(Err(Error::Overflow(String::from("pair count + inserted pairs"))), polymer_counts.apply(&rules));
+    }
+}
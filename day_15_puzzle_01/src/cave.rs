@@ -1,110 +1,271 @@
-use std::cmp;
+use std::collections::HashSet;
+use aoc_common::{Grid, Position, Frame, Cell, Colour, Raster, Rgb};
+
+/// The most frames a path is drawn over, however long it is.
+const PATH_FRAMES: usize = 25;
 
 pub struct CaveBuilder {
-	width: Option<usize>,
-	risk_levels: Vec<Vec<u32>>,
+	source_risk_levels: Grid<u32>,
 }
 
 impl CaveBuilder {
 	pub fn new() -> CaveBuilder {
 		CaveBuilder {
-			width: None,
-			risk_levels: vec![],
+			source_risk_levels: Grid::new(),
 		}
 	}
 
-	pub fn add_row(&mut self, row: Vec<u32>) {
-		if let Some(width) = self.width {
-			if width != row.len() {
-				panic!("Row does not match the expected length of {} - found {}", width, row.len());
-			}
-		} else {
-			self.width = Some(row.len());
+	pub fn from(source_risk_levels: Grid<u32>) -> CaveBuilder {
+		CaveBuilder {
+			source_risk_levels,
 		}
+	}
 
-		self.risk_levels.push(row);
+	pub fn add_row(&mut self, row: Vec<u32>) {
+		self.source_risk_levels.push_row(row);
 	}
 
 	pub fn build(self) -> Cave {
-		Cave::new(self.risk_levels)
+		Cave::new(self.source_risk_levels)
+	}
+
+	pub fn build_full(self) -> Cave {
+		let source_height = self.source_risk_levels.height();
+		let source_width = self.source_risk_levels.width();
+		let full_height = source_height * 5;
+		let full_width = source_width * 5;
+		let find_source_risk = |x, y| -> u32 {
+			let source_x: usize = x % source_width;
+			let source_y: usize = y % source_height;
+
+			self.source_risk_levels[Position::new(source_x, source_y)]
+		};
+		let transform_risk = |x, y| -> u32 {
+			let x_offset = x / source_width;
+			let y_offset = y / source_height;
+
+			let source_risk = find_source_risk(x, y);
+			let transformed_risk = source_risk + x_offset as u32 + y_offset as u32;
+			if transformed_risk < 10 {
+				transformed_risk
+			} else {
+				transformed_risk - 9
+			}
+		};
+		let mut full_cave = Grid::new();
+		for y in 0..full_height {
+			let mut full_row = vec![];
+			for x in 0..full_width {
+				let new_risk = transform_risk(x, y);
+				full_row.push(new_risk);
+			}
+			full_cave.push_row(full_row);
+		}
+		Cave::new(full_cave)
 	}
 }
 
 pub struct Cave {
-	risk_levels: Vec<Position>,
-	width: usize,
-	height: usize,
+	risk_levels: Grid<u32>,
 }
 
 impl Cave {
-	pub fn new(risk_grid: Vec<Vec<u32>>) -> Cave {
-		let mut risk_levels = vec![];
-		for (y, row) in risk_grid.iter().enumerate() {
-			for (x, risk) in row.iter().enumerate() {
-				risk_levels.push(Position::new(x, y, *risk));
-			}
+	pub fn new(risk_levels: Grid<u32>) -> Cave {
+		if risk_levels.is_empty() {
+			panic!("Cave needs at least one position");
 		}
 		Cave {
 			risk_levels,
-			width: risk_grid[0].len(),
-			height: risk_grid.len(),
 		}
 	}
 
-	pub fn start(&self) -> &Position {
-		self.get_pos(0, 0)
+	pub fn start(&self) -> Position {
+		self.risk_levels.first_position().unwrap()
+	}
+
+	pub fn end(&self) -> Position {
+		self.risk_levels.last_position().unwrap()
 	}
 
-	pub fn end(&self) -> &Position {
-		self.get_pos(self.width - 1, self.height - 1)
+	pub fn risk(&self, pos: Position) -> u32 {
+		self.risk_levels[pos]
 	}
 
-	fn get_pos(&self, x: usize, y: usize) -> &Position {
-		let index = x + y * self.width;
-		&self.risk_levels[index]
+	pub fn neighbors(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+		self.risk_levels.neighbours_4(pos)
 	}
 
-	pub fn neighbors(&self, pos: &Position) -> Vec<&Position> {
-		let mut neighbors = vec![];
+	/// The cave with a path through it drawn a stretch at a time, the newest position on it highlighted.
+	pub fn path_frames(&self, path: &[Position]) -> Vec<Frame> {
+		let mut cells = Frame::from_grid("", &self.risk_levels, |_, &risk| {
+			Cell::coloured(char::from_digit(risk, 10).unwrap_or('?'), Colour::Dim)
+		}).cells().clone();
 
-		if pos.x > 0 {
-			neighbors.push(self.get_pos(pos.x - 1, pos.y));
-		}
-		if pos.x < self.width - 1 {
-			neighbors.push(self.get_pos(pos.x + 1, pos.y));
-		}
-		if pos.y > 0 {
-			neighbors.push(self.get_pos(pos.x, pos.y - 1));
-		}
-		if pos.y < self.height - 1 {
-			neighbors.push(self.get_pos(pos.x, pos.y + 1));
+		let mut frames = vec![];
+		let mut risk = 0;
+		let stretch = path.len().div_ceil(PATH_FRAMES).max(1);
+		for (i, &position) in path.iter().enumerate() {
+			if position != self.start() {
+				risk += self.risk(position);
+			}
+			if i > 0 {
+				let previous = path[i - 1];
+				cells[previous] = Cell::coloured(cells[previous].symbol(), Colour::Green);
+			}
+			cells[position] = Cell::coloured(cells[position].symbol(), Colour::Bright);
+
+			if (i + 1) % stretch == 0 || i + 1 == path.len() {
+				frames.push(Frame::new(&format!("Step {} of {} - risk {}", i + 1, path.len(), risk), cells.clone()));
+			}
 		}
+		frames
+	}
 
-		neighbors
+	/// The cave shaded darker the riskier each position is, with the path through it in red.
+	pub fn path_raster(&self, path: &[Position]) -> Raster {
+		let path: HashSet<Position> = path.iter().copied().collect();
+		Raster::from_grid(&self.risk_levels, |position, &risk| {
+			if path.contains(&position) {
+				Rgb::RED
+			} else {
+				Rgb::grey(255 - risk.min(9) as u8 * 25)
+			}
+		})
 	}
 }
 
-#[derive(Eq, PartialEq, Hash)]
-pub struct Position {
-	x: usize,
-	y: usize,
-	risk: u32,
-}
+#[cfg(test)]
+mod tests {
+	use super::*;
 
-impl Position {
-	pub fn new(x: usize, y: usize, risk: u32) -> Position {
-		Position {
-			x,
-			y,
-			risk,
-		}
+	#[test]
+	fn test_build() {
+		let mut builder = CaveBuilder::new();
+		builder.add_row(vec![1, 1, 6]);
+		builder.add_row(vec![1, 3, 8]);
+
+		let cave = builder.build();
+
+		assert_eq!(Position::new(0, 0), cave.start());
+		assert_eq!(Position::new(2, 1), cave.end());
+		assert_eq!(8, cave.risk(cave.end()));
+		assert_eq!(vec![Position::new(1, 0), Position::new(0, 1)], cave.neighbors(cave.start()).collect::<Vec<Position>>());
 	}
 
-	pub fn risk(&self) -> u32 {
-		self.risk
+	#[test]
+	fn test_path_frames() {
+		let mut builder = CaveBuilder::new();
+		builder.add_row(vec![1, 1, 6]);
+		builder.add_row(vec![1, 3, 8]);
+		let cave = builder.build();
+
+		let path = vec![Position::new(0, 0), Position::new(1, 0), Position::new(2, 0), Position::new(2, 1)];
+		let frames = cave.path_frames(&path);
+
+		assert_eq!(4, frames.len());
+		assert_eq!("Step 4 of 4 - risk 15\n116\n138\n", frames[3].to_string());
+		assert_eq!(Colour::Green, frames[3].cells()[Position::new(2, 0)].colour());
+		assert_eq!(Colour::Bright, frames[3].cells()[Position::new(2, 1)].colour());
+		assert_eq!(Colour::Dim, frames[3].cells()[Position::new(1, 1)].colour());
+		assert_eq!(Colour::Dim, frames[0].cells()[Position::new(1, 0)].colour());
+
+		let raster = cave.path_raster(&path);
+		assert_eq!(Rgb::RED, raster.pixels()[Position::new(2, 0)]);
+		assert_eq!(Rgb::grey(180), raster.pixels()[Position::new(1, 1)]);
 	}
 
-	pub fn distance(&self, other: &Self) -> usize {
-		(cmp::max(self.x, other.x) - cmp::min(self.x, other.x)) + (cmp::max(self.y, other.y) - cmp::min(self.y, other.y))
+	#[test]
+	fn test_build_full() {
+		let source_rows = "
+		1163751742
+		1381373672
+		2136511328
+		3694931569
+		7463417111
+		1319128137
+		1359912421
+		3125421639
+		1293138521
+		2311944581"
+			.trim()
+            .split("\n")
+            .map(|r| r.trim().chars()
+                .map(|c| c.to_digit(10).unwrap())
+                .collect::<Vec<u32>>()
+            ).collect::<Vec<Vec<u32>>>();
+
+		let mut builder = CaveBuilder::new();
+		for row in source_rows.into_iter() {
+			builder.add_row(row);
+		}
+
+		let built_cave = builder.build_full();
+
+		let full_rows = "
+		11637517422274862853338597396444961841755517295286
+		13813736722492484783351359589446246169155735727126
+		21365113283247622439435873354154698446526571955763
+		36949315694715142671582625378269373648937148475914
+		74634171118574528222968563933317967414442817852555
+		13191281372421239248353234135946434524615754563572
+		13599124212461123532357223464346833457545794456865
+		31254216394236532741534764385264587549637569865174
+		12931385212314249632342535174345364628545647573965
+		23119445813422155692453326671356443778246755488935
+		22748628533385973964449618417555172952866628316397
+		24924847833513595894462461691557357271266846838237
+		32476224394358733541546984465265719557637682166874
+		47151426715826253782693736489371484759148259586125
+		85745282229685639333179674144428178525553928963666
+		24212392483532341359464345246157545635726865674683
+		24611235323572234643468334575457944568656815567976
+		42365327415347643852645875496375698651748671976285
+		23142496323425351743453646285456475739656758684176
+		34221556924533266713564437782467554889357866599146
+		33859739644496184175551729528666283163977739427418
+		35135958944624616915573572712668468382377957949348
+		43587335415469844652657195576376821668748793277985
+		58262537826937364893714847591482595861259361697236
+		96856393331796741444281785255539289636664139174777
+		35323413594643452461575456357268656746837976785794
+		35722346434683345754579445686568155679767926678187
+		53476438526458754963756986517486719762859782187396
+		34253517434536462854564757396567586841767869795287
+		45332667135644377824675548893578665991468977611257
+		44961841755517295286662831639777394274188841538529
+		46246169155735727126684683823779579493488168151459
+		54698446526571955763768216687487932779859814388196
+		69373648937148475914825958612593616972361472718347
+		17967414442817852555392896366641391747775241285888
+		46434524615754563572686567468379767857948187896815
+		46833457545794456865681556797679266781878137789298
+		64587549637569865174867197628597821873961893298417
+		45364628545647573965675868417678697952878971816398
+		56443778246755488935786659914689776112579188722368
+		55172952866628316397773942741888415385299952649631
+		57357271266846838237795794934881681514599279262561
+		65719557637682166874879327798598143881961925499217
+		71484759148259586125936169723614727183472583829458
+		28178525553928963666413917477752412858886352396999
+		57545635726865674683797678579481878968159298917926
+		57944568656815567976792667818781377892989248891319
+		75698651748671976285978218739618932984172914319528
+		56475739656758684176786979528789718163989182927419
+		67554889357866599146897761125791887223681299833479"
+			.trim()
+            .split("\n")
+            .map(|r| r.trim().chars()
+                .map(|c| c.to_digit(10).unwrap())
+                .collect::<Vec<u32>>()
+            ).collect::<Vec<Vec<u32>>>();
+		
+		let mut builder = CaveBuilder::new();
+		for row in full_rows.into_iter() {
+			builder.add_row(row);
+		}
+
+		let full_cave = builder.build();
+
+		assert_eq!(full_cave.risk_levels, built_cave.risk_levels);
 	}
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+pub use day_15_puzzle_01::{cave, path_finder};
+
+use aoc_common::{Puzzle, Answer, Error, Grid, Frame, Picture, Position};
+use cave::{Cave, CaveBuilder};
+
+pub struct Day15;
+
+impl Puzzle for Day15 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Grid<u32>, Error> {
+        aoc_common::parse_digit_grid(input)
+    }
+
+    fn part2(risk_levels: &Grid<u32>) -> Result<Answer, Error> {
+        if risk_levels.is_empty() {
+            return Err(Error::NoSolution(String::from("The cave has no positions to path through")));
+        }
+
+        let cave = CaveBuilder::from(risk_levels.clone()).build_full();
+
+        let safest_path = path_finder::find_safest_path(&cave, cave.start(), cave.end());
+
+        let total_risk: u32 = safest_path
+            .iter()
+            .map(|p| if *p == cave.start() { 0 } else { cave.risk(*p) })
+            .sum();
+
+        Ok(total_risk.into())
+    }
+}
+
+fn safest_path(input: &str) -> Result<(Cave, Vec<Position>), Error> {
+    let risk_levels = Day15::parse(input)?;
+    if risk_levels.is_empty() {
+        return Err(Error::NoSolution(String::from("The cave has no positions to path through")));
+    }
+
+    let cave = CaveBuilder::from(risk_levels).build_full();
+    let safest_path = path_finder::find_safest_path(&cave, cave.start(), cave.end());
+    Ok((cave, safest_path))
+}
+
+/// The safest path through the full cave, five times the size of the input in each direction, drawn as it is followed.
+pub fn visualize(input: &str) -> Result<Vec<Frame>, Error> {
+    let (cave, safest_path) = safest_path(input)?;
+    Ok(cave.path_frames(&safest_path))
+}
+
+/// The risk levels with the safest path through them.
+pub fn export(input: &str) -> Result<Picture, Error> {
+    let (cave, safest_path) = safest_path(input)?;
+    Ok(Picture::Raster(cave.path_raster(&safest_path).scaled(2)))
+}
@@ -1,50 +1,68 @@
+use std::ops::Range;
+use aoc_common::Error;
 use crate::packet::{Packet, LiteralPacket, OperatorPacket};
 
-pub fn extract_packet(packet: &str) -> (Packet, u32) {
-	let id = get_id(&packet);
+pub fn extract_packet(packet: &str) -> Result<(Packet, u32), Error> {
+	let id = get_id(packet)?;
 	match id {
+		TYPE_SUM_VALUE => {
+			let (operator, bits_read) = extract_operator_packet(packet)?;
+			Ok((Packet::Sum(Box::new(operator)), bits_read))
+		},
+		TYPE_PRODUCT_VALUE => {
+			let (operator, bits_read) = extract_operator_packet(packet)?;
+			Ok((Packet::Product(Box::new(operator)), bits_read))
+		},
+		TYPE_MIN_VALUE => {
+			let (operator, bits_read) = extract_operator_packet(packet)?;
+			Ok((Packet::Min(Box::new(operator)), bits_read))
+		},
+		TYPE_MAX_VALUE => {
+			let (operator, bits_read) = extract_operator_packet(packet)?;
+			Ok((Packet::Max(Box::new(operator)), bits_read))
+		},
 		TYPE_LITERAL_VALUE => {
-			let (literal, bits_read) = extract_literal_packet(&packet);
-			(Packet::Literal(Box::new(literal)), bits_read)
+			let (literal, bits_read) = extract_literal_packet(packet)?;
+			Ok((Packet::Literal(Box::new(literal)), bits_read))
 		},
-		_ => {
-			let (operator, bits_read) = extract_operator_packet(&packet);
-			(Packet::Operator(Box::new(operator)), bits_read)
+		TYPE_GREATER_THAN_VALUE => {
+			let (operator, bits_read) = extract_operator_packet(packet)?;
+			Ok((Packet::GreaterThan(Box::new(operator)), bits_read))
 		},
+		TYPE_LESS_THAN_VALUE => {
+			let (operator, bits_read) = extract_operator_packet(packet)?;
+			Ok((Packet::LessThan(Box::new(operator)), bits_read))
+		},
+		TYPE_EQUALS_TO_VALUE => {
+			let (operator, bits_read) = extract_operator_packet(packet)?;
+			Ok((Packet::Equalsto(Box::new(operator)), bits_read))
+		},
+		_ => panic!("Unknown packet id {}", id),
 	}
 }
 
-fn extract_literal_packet(packet: &str) -> (LiteralPacket, u32) {
-	let version = get_version(&packet);
-	let (value, bits_read) = get_literal_value(&packet);
-	(LiteralPacket::new(version, value), bits_read)
+fn extract_literal_packet(packet: &str) -> Result<(LiteralPacket, u32), Error> {
+	let version = get_version(packet)?;
+	let (value, bits_read) = get_literal_value(packet)?;
+	Ok((LiteralPacket::new(version, value), bits_read))
 }
 
-fn extract_operator_packet(packet: &str) -> (OperatorPacket, u32) {
-	let version = get_version(&packet);
-	let (sub_packets, bits_read) = get_sub_packets(&packet);
-	(OperatorPacket::new(version, sub_packets), bits_read)
-
+fn extract_operator_packet(packet: &str) -> Result<(OperatorPacket, u32), Error> {
+	let version = get_version(packet)?;
+	let (sub_packets, bits_read) = get_sub_packets(packet)?;
+	Ok((OperatorPacket::new(version, sub_packets), bits_read))
 }
 
-fn get_version(packet: &str) -> u64 {
-	if packet.len() < 3 {
-		panic!("packet is too small - found size {}", packet.len())
-	}
-
-	convert_to_integer(&packet[..3])
+fn get_version(packet: &str) -> Result<u64, Error> {
+	read_bits(packet, 0..3)
 }
 
-fn get_id(packet: &str) -> u64 {
-	if packet.len() < 6 {
-		panic!("packet is too small - found size {}", packet.len())
-	}
-
-	convert_to_integer(&packet[3..6])
+fn get_id(packet: &str) -> Result<u64, Error> {
+	read_bits(packet, 3..6)
 }
 
-fn get_literal_value(packet: &str) -> (u64, u32) {
-	let id = get_id(&packet);
+fn get_literal_value(packet: &str) -> Result<(u64, u32), Error> {
+	let id = get_id(packet)?;
 	if id != TYPE_LITERAL_VALUE {
 		panic!("Expect type {} for literal value - found {}", TYPE_LITERAL_VALUE, id)
 	}
@@ -54,7 +72,7 @@ fn get_literal_value(packet: &str) -> (u64, u32) {
 
 	loop {
 		// data in kept in groups of size 5
-		let group = &packet[index..index + 5];
+		let group = packet.get(index..index + 5).ok_or_else(ends_early)?;
 		// where the actual data is the last 4 bits
 		literal_value.push_str(&group[1..]);
 		index += 5;
@@ -64,53 +82,68 @@ fn get_literal_value(packet: &str) -> (u64, u32) {
 		}
 	}
 
-	(convert_to_integer(&literal_value.as_str()), index as u32)
+	Ok((convert_to_integer(literal_value.as_str())?, index as u32))
 }
 
-fn get_sub_packets(packet: &str) -> (Vec<Packet>, u32) {
-	let id = get_id(&packet);
+fn get_sub_packets(packet: &str) -> Result<(Vec<Packet>, u32), Error> {
+	let id = get_id(packet)?;
 	if id == TYPE_LITERAL_VALUE {
 		panic!("Expected type to be an operator value - found {}", id)
 	}
 
-	let length_type_id = convert_to_integer(&packet[6..7]);
+	let length_type_id = read_bits(packet, 6..7)?;
 	if length_type_id == LENGTH_TYPE_PACKETS_SIZE {
-		let sub_packets_bit_length = convert_to_integer(&packet[7..22]) as u32;
+		let sub_packets_bit_length = read_bits(packet, 7..22)? as u32;
 		let mut sub_packets = vec![];
 		let mut total_bits_read = 0;
 		let mut index = 22;
-		loop {
-			let (packet, bits_read) = extract_packet(&packet[index..]);
+		while total_bits_read < sub_packets_bit_length {
+			let (packet, bits_read) = extract_packet(&packet[index..])?;
 			sub_packets.push(packet);
 			index += bits_read as usize;
 			total_bits_read += bits_read;
-			if total_bits_read == sub_packets_bit_length {
-				break;
-			} else if total_bits_read > sub_packets_bit_length {
-				panic!("Read more bits than expected while extracting sub-packets - expected {}, read {}", sub_packets_bit_length, total_bits_read)
-			}
 		}
-		(sub_packets, index as u32)
+		if total_bits_read > sub_packets_bit_length {
+			return Err(Error::NoSolution(format!("Read more bits than expected while extracting sub-packets - expected {}, read {}", sub_packets_bit_length, total_bits_read)));
+		}
+		Ok((sub_packets, index as u32))
 	} else if length_type_id == LENGTH_TYPE_PACKETS_COUNT {
-		let sub_packet_count = convert_to_integer(&packet[7..18]);
+		let sub_packet_count = read_bits(packet, 7..18)?;
 		let mut sub_packets = vec![];
 		let mut index = 18;
 		while sub_packets.len() < sub_packet_count as usize {
-			let (packet, bits_read) = extract_packet(&packet[index..]);
+			let (packet, bits_read) = extract_packet(&packet[index..])?;
 			sub_packets.push(packet);
 			index += bits_read as usize;
 		}
-		(sub_packets, index as u32)
+		Ok((sub_packets, index as u32))
 	} else {
 		panic!("Unknown length type id for operator packet - expected 0 or 1, found {}", length_type_id)
 	}
 }
 
-fn convert_to_integer(binary_value: &str) -> u64 {
-	u64::from_str_radix(&binary_value, 2).unwrap()
+/// The number held in some of a packet's bits, which could be missing if the transmission was cut short.
+fn read_bits(packet: &str, bits: Range<usize>) -> Result<u64, Error> {
+	convert_to_integer(packet.get(bits).ok_or_else(ends_early)?)
+}
+
+fn ends_early() -> Error {
+	Error::NoSolution(String::from("The transmission ends part way through a packet"))
+}
+
+fn convert_to_integer(binary_value: &str) -> Result<u64, Error> {
+	// the bits always come from hex digits, so the only way this fails is a number too big for 64 bits
+	u64::from_str_radix(binary_value, 2).map_err(|_| Error::Overflow(String::from("literal value")))
 }
 
+const TYPE_SUM_VALUE: u64 = 0;
+const TYPE_PRODUCT_VALUE: u64 = 1;
+const TYPE_MIN_VALUE: u64 = 2;
+const TYPE_MAX_VALUE: u64 = 3;
 const TYPE_LITERAL_VALUE: u64 = 4;
+const TYPE_GREATER_THAN_VALUE: u64 = 5;
+const TYPE_LESS_THAN_VALUE: u64 = 6;
+const TYPE_EQUALS_TO_VALUE: u64 = 7;
 
 const LENGTH_TYPE_PACKETS_SIZE: u64 = 0;
 
@@ -123,35 +156,35 @@ mod tests {
 
 	#[test]
 	fn test_convert_to_integer() {
-		assert_eq!(0, convert_to_integer("0000"));
+		assert_eq!(Ok(0), convert_to_integer("0000"));
 
-		assert_eq!(5, convert_to_integer("101"));
+		assert_eq!(Ok(5), convert_to_integer("101"));
 
-		assert_eq!(13, convert_to_integer("1101"));
+		assert_eq!(Ok(13), convert_to_integer("1101"));
 	}
 
 	#[test]
 	fn test_version() {
-		assert_eq!(6, get_version("11000"));
-		assert_eq!(1, get_version("001111111"));
-		assert_eq!(7, get_version("11110101101010101101"));
+		assert_eq!(Ok(6), get_version("11000"));
+		assert_eq!(Ok(1), get_version("001111111"));
+		assert_eq!(Ok(7), get_version("11110101101010101101"));
 	}
 
 	#[test]
 	fn test_id() {
-		assert_eq!(4, get_id("000100"));
-		assert_eq!(6, get_id("111110010111010001"));
-		assert_eq!(3, get_id("10101111001010"));
+		assert_eq!(Ok(4), get_id("000100"));
+		assert_eq!(Ok(6), get_id("111110010111010001"));
+		assert_eq!(Ok(3), get_id("10101111001010"));
 	}
 
 	#[test]
 	fn test_literal_value() {
-		assert_eq!((2021, 21), get_literal_value("110100101111111000101000"));
+		assert_eq!(Ok((2021, 21)), get_literal_value("110100101111111000101000"));
 	}
 
 	#[test]
 	fn test_operator_type_length() {
-		let (sub_packets, bits_read) = get_sub_packets("00111000000000000110111101000101001010010001001000000000");
+		let (sub_packets, bits_read) = get_sub_packets("00111000000000000110111101000101001010010001001000000000").unwrap();
 		assert_eq!(2, sub_packets.len());
 		assert_eq!(49, bits_read);
 
@@ -168,7 +201,7 @@ mod tests {
 
 	#[test]
 	fn test_operator_type_count() {
-		let (sub_packets, bits_read) = get_sub_packets("11101110000000001101010000001100100000100011000001100000");
+		let (sub_packets, bits_read) = get_sub_packets("11101110000000001101010000001100100000100011000001100000").unwrap();
 		assert_eq!(3, sub_packets.len());
 		assert_eq!(51, bits_read);
 
@@ -190,29 +223,117 @@ mod tests {
 
 	#[test]
 	fn test_example_1() {
-		let binary_data = hex::convert_hex_value_to_binary("8A004A801A8002F478");
-		let (packet, _) = extract_packet(&binary_data);
+		let binary_data = hex::convert_hex_value_to_binary("8A004A801A8002F478").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
 		assert_eq!(16, packet.version_total());
 	}
 
 	#[test]
 	fn test_example_2() {
-		let binary_data = hex::convert_hex_value_to_binary("620080001611562C8802118E34");
-		let (packet, _) = extract_packet(&binary_data);
+		let binary_data = hex::convert_hex_value_to_binary("620080001611562C8802118E34").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
 		assert_eq!(12, packet.version_total());
 	}
 
 	#[test]
 	fn test_example_3() {
-		let binary_data = hex::convert_hex_value_to_binary("C0015000016115A2E0802F182340");
-		let (packet, _) = extract_packet(&binary_data);
+		let binary_data = hex::convert_hex_value_to_binary("C0015000016115A2E0802F182340").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
 		assert_eq!(23, packet.version_total());
 	}
 
 	#[test]
 	fn test_example_4() {
-		let binary_data = hex::convert_hex_value_to_binary("A0016C880162017C3686B18A3D4780");
-		let (packet, _) = extract_packet(&binary_data);
+		let binary_data = hex::convert_hex_value_to_binary("A0016C880162017C3686B18A3D4780").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
 		assert_eq!(31, packet.version_total());
 	}
+
+	#[test]
+	fn test_value_example_1() {
+		let binary_data = hex::convert_hex_value_to_binary("C200B40A82").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
+		assert_eq!(Ok(3), packet.value());
+	}
+
+	#[test]
+	fn test_value_example_2() {
+		let binary_data = hex::convert_hex_value_to_binary("04005AC33890").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
+		assert_eq!(Ok(54), packet.value());
+	}
+
+	#[test]
+	fn test_value_example_3() {
+		let binary_data = hex::convert_hex_value_to_binary("880086C3E88112").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
+		assert_eq!(Ok(7), packet.value());
+	}
+
+	#[test]
+	fn test_value_example_4() {
+		let binary_data = hex::convert_hex_value_to_binary("CE00C43D881120").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
+		assert_eq!(Ok(9), packet.value());
+	}
+
+	#[test]
+	fn test_value_example_5() {
+		let binary_data = hex::convert_hex_value_to_binary("D8005AC2A8F0").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
+		assert_eq!(Ok(1), packet.value());
+	}
+
+	#[test]
+	fn test_value_example_6() {
+		let binary_data = hex::convert_hex_value_to_binary("F600BC2D8F").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
+		assert_eq!(Ok(0), packet.value());
+	}
+
+	#[test]
+	fn test_value_example_7() {
+		let binary_data = hex::convert_hex_value_to_binary("9C005AC2F8F0").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
+		assert_eq!(Ok(0), packet.value());
+	}
+
+	#[test]
+	fn test_value_example_8() {
+		let binary_data = hex::convert_hex_value_to_binary("9C0141080250320F1802104A08").unwrap();
+		let (packet, _) = extract_packet(&binary_data).unwrap();
+		assert_eq!(Ok(1), packet.value());
+	}
+
+	#[test]
+	fn test_cut_short() {
+		let ends_early = Err(Error::NoSolution(String::from("The transmission ends part way through a packet")));
+		assert_eq!(ends_early, extract_packet("1101").map(|_| ()));
+		// a literal whose last group is missing
+		assert_eq!(ends_early, extract_packet("1101001011111110").map(|_| ()));
+		// an operator that promises two sub-packets but only has one
+		assert_eq!(ends_early, extract_packet("111011100000000010110100101111111000101000").map(|_| ()));
+	}
+
+	#[test]
+	fn test_sub_packets_overrun() {
+		// the sub-packets are said to be 10 bits long, but the literal in them is 11
+		let error = Err(Error::NoSolution(String::from("Read more bits than expected while extracting sub-packets - expected 10, read 11")));
+		assert_eq!(error, extract_packet("001000000000000000101011010001010").map(|_| ()));
+	}
+
+	#[test]
+	fn test_literal_overflow() {
+		// seventeen groups of four bits is too many for a u64
+		let literal = format!("110100{}01111", "11111".repeat(16));
+		assert_eq!(Err(Error::Overflow(String::from("literal value"))), extract_packet(&literal).map(|_| ()));
+	}
+
+	#[test]
+	fn test_comparison_needs_two_values() {
+		let max = hex::convert_hex_value_to_binary("EE00D40C823060").unwrap();
+		// the same three sub-packets under a greater-than packet
+		let (packet, _) = extract_packet(&format!("111101{}", &max[6..])).unwrap();
+		assert_eq!(Err(Error::NoSolution(String::from("There should be exactly two sub-packets to determine greater-than"))), packet.value());
+	}
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
-fn convert_hex_char_to_binary(hex: char) -> &'static str {
-	match hex {
+use aoc_common::{Error, ParseError};
+
+fn convert_hex_char_to_binary(hex: char) -> Option<&'static str> {
+	let bits = match hex {
 		'0' => "0000",
 		'1' => "0001",
 		'2' => "0010",
@@ -16,18 +18,30 @@ fn convert_hex_char_to_binary(hex: char) -> &'static str {
 		'D' => "1101",
 		'E' => "1110",
 		'F' => "1111",
-		_ => panic!("Invalid hex value {}", hex)
-	}
+		_ => return None,
+	};
+
+	Some(bits)
 }
 
-pub fn convert_hex_value_to_binary(hex_value: &str) -> String {
+/// The bits of a hex value, four to each digit. Every bad digit is reported, not just the first.
+pub fn convert_hex_value_to_binary(hex_value: &str) -> Result<String, Error> {
+	let indent = hex_value[..hex_value.len() - hex_value.trim_start().len()].chars().count();
 	let mut binary_value = String::new();
+	let mut errors = vec![];
 
-	for hex_code in hex_value.chars() {
-		binary_value.push_str(convert_hex_char_to_binary(hex_code));
+	for (i, hex_code) in hex_value.trim().chars().enumerate() {
+		match convert_hex_char_to_binary(hex_code) {
+			Some(bits) => binary_value.push_str(bits),
+			None => errors.push(ParseError::new(indent + i + 1, "a hex digit", &hex_code.to_string())),
+		}
 	}
 
-	binary_value
+	if errors.is_empty() {
+		Ok(binary_value)
+	} else {
+		Err(Error::Parse(errors))
+	}
 }
 
 
@@ -37,10 +51,16 @@ mod tests {
 
 	#[test]
 	fn test_convert() {
-		assert_eq!("110100101111111000101000", convert_hex_value_to_binary(&"D2FE28").as_str());
+		assert_eq!("110100101111111000101000", convert_hex_value_to_binary("D2FE28").unwrap());
 
-		assert_eq!("00111000000000000110111101000101001010010001001000000000", convert_hex_value_to_binary(&"38006F45291200").as_str());
+		assert_eq!("00111000000000000110111101000101001010010001001000000000", convert_hex_value_to_binary("38006F45291200").unwrap());
 
-		assert_eq!("11101110000000001101010000001100100000100011000001100000", convert_hex_value_to_binary(&"EE00D40C823060").as_str());
+		assert_eq!("11101110000000001101010000001100100000100011000001100000", convert_hex_value_to_binary("EE00D40C823060").unwrap());
+	}
+
+	#[test]
+	fn test_convert_errors() {
+		let errors = vec![ParseError::new(4, "a hex digit", "x"), ParseError::new(6, "a hex digit", "g")];
+		assert_eq!(Err(Error::Parse(errors)), convert_hex_value_to_binary(" D2xEg8"));
 	}
 }
\ No newline at end of file
@@ -0,0 +1,57 @@
+pub mod bits;
+pub mod hex;
+pub mod packet;
+
+use aoc_common::{Puzzle, Answer, Error, ParseError};
+use packet::Packet;
+
+/// Reads the outermost packet of the transmission, which is all on the first line.
+pub fn load_packet(input: &str) -> Result<Packet, Error> {
+    let mut lines = aoc_common::numbered_lines(input);
+    let (number, line) = lines.next().ok_or_else(|| ParseError::new(1, "a hex transmission", ""))?;
+
+    let mut errors = vec![];
+    let binary_data = hex::convert_hex_value_to_binary(line).map_err(|e| e.on_line(number));
+    if let Err(Error::Parse(line_errors)) = &binary_data {
+        errors.extend(line_errors.iter().cloned());
+    }
+    for (number, line) in lines {
+        let column = line.len() - line.trim_start().len() + 1;
+        errors.push(ParseError::new(column, "the end of the transmission", line.trim()).on_line(number));
+    }
+    if !errors.is_empty() {
+        return Err(Error::Parse(errors));
+    }
+
+    let (packet, _) = bits::extract_packet(&binary_data?)?;
+    Ok(packet)
+}
+
+pub struct Day16;
+
+impl Puzzle for Day16 {
+    type Input = Packet;
+
+    fn parse(input: &str) -> Result<Packet, Error> {
+        load_packet(input)
+    }
+
+    fn part1(packet: &Packet) -> Result<Answer, Error> {
+        Ok(packet.version_total().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_errors() {
+        let errors = vec![
+            ParseError::new(3, "a hex digit", "x").on_line(2),
+            ParseError::new(2, "the end of the transmission", "D2FE28").on_line(3),
+        ];
+        assert_eq!(Err(Error::Parse(errors)), load_packet("\nD2x\n D2FE28\n").map(|_| ()));
+        assert_eq!(Err(Error::Parse(vec![ParseError::new(1, "a hex transmission", "")])), load_packet("\n").map(|_| ()));
+    }
+}
\ No newline at end of file
@@ -1,19 +1,16 @@
-mod bits;
-mod hex;
-mod packet;
-
-use std::fs;
+use aoc_common::InputSource;
+use day_16_puzzle_01::Day16;
 
 fn main() -> std::io::Result<()> {
-    let hex_data = fs::read_to_string("./day_16_puzzle_01/input.txt").unwrap();
-
-    let binary_data = hex::convert_hex_value_to_binary(hex_data.trim());
-
-    let (packet, _) = bits::extract_packet(&binary_data);
-
-    let version_totals = packet.version_total();
+    let input = InputSource::from_args("./day_16_puzzle_01/input.txt").read_to_string()?;
 
-    println!("{}", version_totals);
+    match aoc_common::solve_part1::<Day16>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
+    }
 
     Ok(())
 }
\ No newline at end of file
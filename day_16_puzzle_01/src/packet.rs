@@ -1,14 +1,28 @@
+use aoc_common::{Checked, Error};
+
 #[derive(Debug, PartialEq)]
 pub enum Packet {
+    Sum(Box<OperatorPacket>),
+    Product(Box<OperatorPacket>),
+    Min(Box<OperatorPacket>),
+    Max(Box<OperatorPacket>),
     Literal(Box<LiteralPacket>),
-    Operator(Box<OperatorPacket>),
+    GreaterThan(Box<OperatorPacket>),
+    LessThan(Box<OperatorPacket>),
+    Equalsto(Box<OperatorPacket>),
 }
 
 impl Packet {
     pub fn version_total(&self) -> u64 {
         match self {
             Packet::Literal(d) => d.version(),
-            Packet::Operator(d) => {
+            Packet::Sum(d)
+            | Packet::Product(d)
+            | Packet::Min(d)
+            | Packet::Max(d)
+            | Packet::GreaterThan(d)
+            | Packet::LessThan(d)
+            | Packet::Equalsto(d) => {
                 let mut sub_total = d.version();
                 for sub in d.sub_packets().iter() {
                     sub_total += sub.version_total();
@@ -17,6 +31,19 @@ impl Packet {
             }
         }
     }
+
+    pub fn value(&self) -> Result<u64, Error> {
+        match self {
+            Packet::Sum(p) => p.sum(),
+            Packet::Product(p) => p.product(),
+            Packet::Min(p) => p.min(),
+            Packet::Max(p) => p.max(),
+            Packet::Literal(p) => Ok(p.value()),
+            Packet::GreaterThan(p) => p.greater_than(),
+            Packet::LessThan(p) => p.less_than(),
+            Packet::Equalsto(p) => p.equals_to(),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -42,6 +69,10 @@ impl LiteralPacket {
     pub fn version(&self) -> u64 {
         self.version
     }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
 }
 
 impl OperatorPacket {
@@ -59,4 +90,64 @@ impl OperatorPacket {
     pub fn sub_packets(&self) -> &Vec<Packet> {
         &self.sub_packets
     }
+
+    fn values(&self) -> Result<Vec<u64>, Error> {
+        self.sub_packets
+            .iter()
+            .map(|s| s.value())
+            .collect()
+    }
+
+    pub fn sum(&self) -> Result<u64, Error> {
+        let mut sum: u64 = 0;
+        for value in self.values()? {
+            sum = sum.add_checked(value, "sum of sub-packets")?;
+        }
+        Ok(sum)
+    }
+    
+    pub fn product(&self) -> Result<u64, Error> {
+        let mut product: u64 = 1;
+        for value in self.values()? {
+            product = product.mul_checked(value, "product of sub-packets")?;
+        }
+        Ok(product)
+    }
+
+    pub fn min(&self) -> Result<u64, Error> {
+        self.values()?
+            .into_iter()
+            .min()
+            .ok_or_else(|| Error::NoSolution(String::from("There should be at least one sub-packet to determine minimum")))
+    }
+
+    pub fn max(&self) -> Result<u64, Error> {
+        self.values()?
+            .into_iter()
+            .max()
+            .ok_or_else(|| Error::NoSolution(String::from("There should be at least one sub-packet to determine maximum")))
+    }
+
+    pub fn greater_than(&self) -> Result<u64, Error> {
+        let (first, second) = self.pair("greater-than")?;
+        Ok(if first > second { 1 } else { 0 })
+    }
+
+    pub fn less_than(&self) -> Result<u64, Error> {
+        let (first, second) = self.pair("less-than")?;
+        Ok(if first < second { 1 } else { 0 })
+    }
+
+    pub fn equals_to(&self) -> Result<u64, Error> {
+        let (first, second) = self.pair("equals-to")?;
+        Ok(if first == second { 1 } else { 0 })
+    }
+
+    /// The values of the two sub-packets a comparison needs.
+    fn pair(&self, comparison: &str) -> Result<(u64, u64), Error> {
+        match self.values()?[..] {
+            [first, second] => Ok((first, second)),
+            _ => Err(Error::NoSolution(format!("There should be exactly two sub-packets to determine {}", comparison))),
+        }
+    }
 }
\ No newline at end of file
@@ -1,278 +0,0 @@
-use crate::packet::{Packet, LiteralPacket, OperatorPacket};
-
-pub fn extract_packet(packet: &str) -> (Packet, u32) {
-	let id = get_id(&packet);
-	match id {
-		TYPE_SUM_VALUE => {
-			let (operator, bits_read) = extract_operator_packet(&packet);
-			(Packet::Sum(Box::new(operator)), bits_read)
-		},
-		TYPE_PRODUCT_VALUE => {
-			let (operator, bits_read) = extract_operator_packet(&packet);
-			(Packet::Product(Box::new(operator)), bits_read)
-		},
-		TYPE_MIN_VALUE => {
-			let (operator, bits_read) = extract_operator_packet(&packet);
-			(Packet::Min(Box::new(operator)), bits_read)
-		},
-		TYPE_MAX_VALUE => {
-			let (operator, bits_read) = extract_operator_packet(&packet);
-			(Packet::Max(Box::new(operator)), bits_read)
-		},
-		TYPE_LITERAL_VALUE => {
-			let (literal, bits_read) = extract_literal_packet(&packet);
-			(Packet::Literal(Box::new(literal)), bits_read)
-		},
-		TYPE_GREATER_THAN_VALUE => {
-			let (operator, bits_read) = extract_operator_packet(&packet);
-			(Packet::GreaterThan(Box::new(operator)), bits_read)
-		},
-		TYPE_LESS_THAN_VALUE => {
-			let (operator, bits_read) = extract_operator_packet(&packet);
-			(Packet::LessThan(Box::new(operator)), bits_read)
-		},
-		TYPE_EQUALS_TO_VALUE => {
-			let (operator, bits_read) = extract_operator_packet(&packet);
-			(Packet::Equalsto(Box::new(operator)), bits_read)
-		},
-		_ => panic!("Unknown packet id {}", id),
-	}
-}
-
-fn extract_literal_packet(packet: &str) -> (LiteralPacket, u32) {
-	let version = get_version(&packet);
-	let (value, bits_read) = get_literal_value(&packet);
-	(LiteralPacket::new(version, value), bits_read)
-}
-
-fn extract_operator_packet(packet: &str) -> (OperatorPacket, u32) {
-	let version = get_version(&packet);
-	let (sub_packets, bits_read) = get_sub_packets(&packet);
-	(OperatorPacket::new(version, sub_packets), bits_read)
-
-}
-
-fn get_version(packet: &str) -> u64 {
-	if packet.len() < 3 {
-		panic!("packet is too small - found size {}", packet.len())
-	}
-
-	convert_to_integer(&packet[..3])
-}
-
-fn get_id(packet: &str) -> u64 {
-	if packet.len() < 6 {
-		panic!("packet is too small - found size {}", packet.len())
-	}
-
-	convert_to_integer(&packet[3..6])
-}
-
-fn get_literal_value(packet: &str) -> (u64, u32) {
-	let id = get_id(&packet);
-	if id != TYPE_LITERAL_VALUE {
-		panic!("Expect type {} for literal value - found {}", TYPE_LITERAL_VALUE, id)
-	}
-
-	let mut literal_value = String::new();
-	let mut index = 6;
-
-	loop {
-		// data in kept in groups of size 5
-		let group = &packet[index..index + 5];
-		// where the actual data is the last 4 bits
-		literal_value.push_str(&group[1..]);
-		index += 5;
-		// and the first bit is a 'is there more data' flag
-		if &group[..1] == "0" {
-			break;
-		}
-	}
-
-	(convert_to_integer(&literal_value.as_str()), index as u32)
-}
-
-fn get_sub_packets(packet: &str) -> (Vec<Packet>, u32) {
-	let id = get_id(&packet);
-	if id == TYPE_LITERAL_VALUE {
-		panic!("Expected type to be an operator value - found {}", id)
-	}
-
-	let length_type_id = convert_to_integer(&packet[6..7]);
-	if length_type_id == LENGTH_TYPE_PACKETS_SIZE {
-		let sub_packets_bit_length = convert_to_integer(&packet[7..22]) as u32;
-		let mut sub_packets = vec![];
-		let mut total_bits_read = 0;
-		let mut index = 22;
-		loop {
-			let (packet, bits_read) = extract_packet(&packet[index..]);
-			sub_packets.push(packet);
-			index += bits_read as usize;
-			total_bits_read += bits_read;
-			if total_bits_read == sub_packets_bit_length {
-				break;
-			} else if total_bits_read > sub_packets_bit_length {
-				panic!("Read more bits than expected while extracting sub-packets - expected {}, read {}", sub_packets_bit_length, total_bits_read)
-			}
-		}
-		(sub_packets, index as u32)
-	} else if length_type_id == LENGTH_TYPE_PACKETS_COUNT {
-		let sub_packet_count = convert_to_integer(&packet[7..18]);
-		let mut sub_packets = vec![];
-		let mut index = 18;
-		while sub_packets.len() < sub_packet_count as usize {
-			let (packet, bits_read) = extract_packet(&packet[index..]);
-			sub_packets.push(packet);
-			index += bits_read as usize;
-		}
-		(sub_packets, index as u32)
-	} else {
-		panic!("Unknown length type id for operator packet - expected 0 or 1, found {}", length_type_id)
-	}
-}
-
-fn convert_to_integer(binary_value: &str) -> u64 {
-	u64::from_str_radix(&binary_value, 2).unwrap()
-}
-
-const TYPE_SUM_VALUE: u64 = 0;
-const TYPE_PRODUCT_VALUE: u64 = 1;
-const TYPE_MIN_VALUE: u64 = 2;
-const TYPE_MAX_VALUE: u64 = 3;
-const TYPE_LITERAL_VALUE: u64 = 4;
-const TYPE_GREATER_THAN_VALUE: u64 = 5;
-const TYPE_LESS_THAN_VALUE: u64 = 6;
-const TYPE_EQUALS_TO_VALUE: u64 = 7;
-
-const LENGTH_TYPE_PACKETS_SIZE: u64 = 0;
-
-const LENGTH_TYPE_PACKETS_COUNT: u64 = 1;
-
-#[cfg(test)]
-mod tests {
-	use super::*;
-	use crate::hex;
-
-	#[test]
-	fn test_convert_to_integer() {
-		assert_eq!(0, convert_to_integer("0000"));
-
-		assert_eq!(5, convert_to_integer("101"));
-
-		assert_eq!(13, convert_to_integer("1101"));
-	}
-
-	#[test]
-	fn test_version() {
-		assert_eq!(6, get_version("11000"));
-		assert_eq!(1, get_version("001111111"));
-		assert_eq!(7, get_version("11110101101010101101"));
-	}
-
-	#[test]
-	fn test_id() {
-		assert_eq!(4, get_id("000100"));
-		assert_eq!(6, get_id("111110010111010001"));
-		assert_eq!(3, get_id("10101111001010"));
-	}
-
-	#[test]
-	fn test_literal_value() {
-		assert_eq!((2021, 21), get_literal_value("110100101111111000101000"));
-	}
-
-	#[test]
-	fn test_operator_type_length() {
-		let (sub_packets, bits_read) = get_sub_packets("00111000000000000110111101000101001010010001001000000000");
-		assert_eq!(2, sub_packets.len());
-		assert_eq!(49, bits_read);
-
-		assert_eq!(
-			Packet::Literal(Box::new(LiteralPacket::new(6, 10))),
-			sub_packets[0]
-		);
-
-		assert_eq!(
-			Packet::Literal(Box::new(LiteralPacket::new(2, 20))),
-			sub_packets[1]
-		);
-	}
-
-	#[test]
-	fn test_operator_type_count() {
-		let (sub_packets, bits_read) = get_sub_packets("11101110000000001101010000001100100000100011000001100000");
-		assert_eq!(3, sub_packets.len());
-		assert_eq!(51, bits_read);
-
-		assert_eq!(
-			Packet::Literal(Box::new(LiteralPacket::new(2, 1))),
-			sub_packets[0]
-		);
-
-		assert_eq!(
-			Packet::Literal(Box::new(LiteralPacket::new(4, 2))),
-			sub_packets[1]
-		);
-
-		assert_eq!(
-			Packet::Literal(Box::new(LiteralPacket::new(1, 3))),
-			sub_packets[2]
-		);
-	}
-
-	#[test]
-	fn test_example_1() {
-		let binary_data = hex::convert_hex_value_to_binary("C200B40A82");
-		let (packet, _) = extract_packet(&binary_data);
-		assert_eq!(3, packet.value());
-	}
-
-	#[test]
-	fn test_example_2() {
-		let binary_data = hex::convert_hex_value_to_binary("04005AC33890");
-		let (packet, _) = extract_packet(&binary_data);
-		assert_eq!(54, packet.value());
-	}
-
-	#[test]
-	fn test_example_3() {
-		let binary_data = hex::convert_hex_value_to_binary("880086C3E88112");
-		let (packet, _) = extract_packet(&binary_data);
-		assert_eq!(7, packet.value());
-	}
-
-	#[test]
-	fn test_example_4() {
-		let binary_data = hex::convert_hex_value_to_binary("CE00C43D881120");
-		let (packet, _) = extract_packet(&binary_data);
-		assert_eq!(9, packet.value());
-	}
-
-	#[test]
-	fn test_example_5() {
-		let binary_data = hex::convert_hex_value_to_binary("D8005AC2A8F0");
-		let (packet, _) = extract_packet(&binary_data);
-		assert_eq!(1, packet.value());
-	}
-
-	#[test]
-	fn test_example_6() {
-		let binary_data = hex::convert_hex_value_to_binary("F600BC2D8F");
-		let (packet, _) = extract_packet(&binary_data);
-		assert_eq!(0, packet.value());
-	}
-
-	#[test]
-	fn test_example_7() {
-		let binary_data = hex::convert_hex_value_to_binary("9C005AC2F8F0");
-		let (packet, _) = extract_packet(&binary_data);
-		assert_eq!(0, packet.value());
-	}
-
-	#[test]
-	fn test_example_8() {
-		let binary_data = hex::convert_hex_value_to_binary("9C0141080250320F1802104A08");
-		let (packet, _) = extract_packet(&binary_data);
-		assert_eq!(1, packet.value());
-	}
-}
\ No newline at end of file
@@ -0,0 +1,18 @@
+pub use day_16_puzzle_01::{bits, hex, packet, load_packet};
+
+use aoc_common::{Puzzle, Answer, Error};
+use packet::Packet;
+
+pub struct Day16;
+
+impl Puzzle for Day16 {
+    type Input = Packet;
+
+    fn parse(input: &str) -> Result<Packet, Error> {
+        day_16_puzzle_01::load_packet(input)
+    }
+
+    fn part2(packet: &Packet) -> Result<Answer, Error> {
+        Ok(packet.value()?.into())
+    }
+}
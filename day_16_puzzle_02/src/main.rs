@@ -1,18 +1,16 @@
-mod bits;
-mod hex;
-mod packet;
+use aoc_common::InputSource;
+use day_16_puzzle_02::Day16;
 
-use std::fs;
 fn main() -> std::io::Result<()> {
-    let hex_data = fs::read_to_string("./day_16_puzzle_01/input.txt").unwrap();
+    let input = InputSource::from_args("./day_16_puzzle_01/input.txt").read_to_string()?;
 
-    let binary_data = hex::convert_hex_value_to_binary(hex_data.trim());
-
-    let (packet, _) = bits::extract_packet(&binary_data);
-
-    let value = packet.value();
-
-    println!("{}", value);
+    match aoc_common::solve_part2::<Day16>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
+    }
 
     Ok(())
 }
\ No newline at end of file
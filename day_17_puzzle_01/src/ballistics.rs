@@ -1,15 +1,16 @@
-use crate::geometry::{Position, Rectangle};
+use aoc_common::Point;
+use crate::geometry::Rectangle;
 use crate::triangle_number::TriangleNumber;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Vector {
-    pos: Position,
+    pos: Point,
     velocity_x: i32,
     velocity_y: i32,
 }
 
 impl Vector {
-    pub fn new(pos: Position, velocity_x: i32, velocity_y: i32) -> Vector {
+    pub fn new(pos: Point, velocity_x: i32, velocity_y: i32) -> Vector {
         Vector {
             pos,
             velocity_x,
@@ -18,7 +19,7 @@ impl Vector {
     }
 
     pub fn next_step(&self) -> Vector {
-        let next_pos = Position::new(self.pos.x() + self.velocity_x, self.pos.y() + self.velocity_y);
+        let next_pos = Point::new(self.pos.x() + self.velocity_x, self.pos.y() + self.velocity_y);
         let next_velocity_x = if self.velocity_x > 0 { self.velocity_x - 1 } else if self.velocity_x == 0 { 0 } else { panic!("x velocity should never be zero") };
         let next_velocity_y = self.velocity_y - 1;
         Vector::new(next_pos, next_velocity_x, next_velocity_y)
@@ -27,6 +28,14 @@ impl Vector {
     pub fn p_y(&self) -> i32 {
         self.pos.y()
     }
+
+    pub fn pos(&self) -> &Point {
+        &self.pos
+    }
+
+    pub fn velocity(&self) -> (i32, i32) {
+        (self.velocity_x, self.velocity_y)
+    }
 }
 
 fn find_max_steps_initial_velocity_x(distance_range: (i32, i32)) -> (i32, i32, i32) {
@@ -126,7 +135,7 @@ pub fn find_fanciest_hit_arc(target: &Rectangle) -> Option<Vec<Vector>> {
 
     let initial_velocity_y = find_max_initial_velocity_y(target.vertical_range(), max_steps);
 
-    let launch = Vector::new(Position::origin(), initial_velocity_x, initial_velocity_y);
+    let launch = Vector::new(Point::origin(), initial_velocity_x, initial_velocity_y);
 
     hit_arc(&launch, target)
 }
@@ -138,78 +147,78 @@ mod tests {
 
     #[test]
     fn test_vector_next() {
-        let origin = Vector::new(Position::origin(), 5, 5);
+        let origin = Vector::new(Point::origin(), 5, 5);
 
         let step_1 = origin.next_step();
-        assert_eq!(Vector::new(Position::new(5, 5), 4, 4), step_1);
+        assert_eq!(Vector::new(Point::new(5, 5), 4, 4), step_1);
 
         let step_2 = step_1.next_step();
-        assert_eq!(Vector::new(Position::new(9, 9), 3, 3), step_2);
+        assert_eq!(Vector::new(Point::new(9, 9), 3, 3), step_2);
 
         let step_3 = step_2.next_step();
-        assert_eq!(Vector::new(Position::new(12, 12), 2, 2), step_3);
+        assert_eq!(Vector::new(Point::new(12, 12), 2, 2), step_3);
 
         let step_4 = step_3.next_step();
-        assert_eq!(Vector::new(Position::new(14, 14), 1, 1), step_4);
+        assert_eq!(Vector::new(Point::new(14, 14), 1, 1), step_4);
 
         let step_5 = step_4.next_step();
-        assert_eq!(Vector::new(Position::new(15, 15), 0, 0), step_5);
+        assert_eq!(Vector::new(Point::new(15, 15), 0, 0), step_5);
 
         let step_6 = step_5.next_step();
-        assert_eq!(Vector::new(Position::new(15, 15), 0, -1), step_6);
+        assert_eq!(Vector::new(Point::new(15, 15), 0, -1), step_6);
 
         let step_7 = step_6.next_step();
-        assert_eq!(Vector::new(Position::new(15, 14), 0, -2), step_7);
+        assert_eq!(Vector::new(Point::new(15, 14), 0, -2), step_7);
 
         let step_8 = step_7.next_step();
-        assert_eq!(Vector::new(Position::new(15, 12), 0, -3), step_8);
+        assert_eq!(Vector::new(Point::new(15, 12), 0, -3), step_8);
 
         let step_9 = step_8.next_step();
-        assert_eq!(Vector::new(Position::new(15, 9), 0, -4), step_9);
+        assert_eq!(Vector::new(Point::new(15, 9), 0, -4), step_9);
 
         let step_10 = step_9.next_step();
-        assert_eq!(Vector::new(Position::new(15, 5), 0, -5), step_10);
+        assert_eq!(Vector::new(Point::new(15, 5), 0, -5), step_10);
     }
 
     #[test]
     fn test_example_1() {
-        let launch = Vector::new(Position::origin(), 7, 2);
+        let launch = Vector::new(Point::origin(), 7, 2);
 
         let target = Rectangle::new(20, 30, -10, -5);
 
         let arc = hit_arc(&launch, &target);
 
         assert_eq!(true, arc.is_some());
-        assert_eq!(Position::new(28, -7), arc.unwrap().last().unwrap().pos);
+        assert_eq!(Point::new(28, -7), arc.unwrap().last().unwrap().pos);
     }
 
     #[test]
     fn test_example_2() {
-        let launch = Vector::new(Position::origin(), 6, 3);
+        let launch = Vector::new(Point::origin(), 6, 3);
 
         let target = Rectangle::new(20, 30, -10, -5);
 
         let arc = hit_arc(&launch, &target);
 
         assert_eq!(true, arc.is_some());
-        assert_eq!(Position::new(21, -9), arc.unwrap().last().unwrap().pos);
+        assert_eq!(Point::new(21, -9), arc.unwrap().last().unwrap().pos);
     }
 
     #[test]
     fn test_example_3() {
-        let launch = Vector::new(Position::origin(), 9, 0);
+        let launch = Vector::new(Point::origin(), 9, 0);
 
         let target = Rectangle::new(20, 30, -10, -5);
 
         let arc = hit_arc(&launch, &target);
 
         assert_eq!(true, arc.is_some());
-        assert_eq!(Position::new(30, -6), arc.unwrap().last().unwrap().pos);
+        assert_eq!(Point::new(30, -6), arc.unwrap().last().unwrap().pos);
     }
 
     #[test]
     fn test_example_4() {
-        let launch = Vector::new(Position::origin(), 17, -4);
+        let launch = Vector::new(Point::origin(), 17, -4);
 
         let target = Rectangle::new(20, 30, -10, -5);
 
@@ -1,37 +1,10 @@
-use regex::Regex;
-
-#[derive(Clone, Debug, PartialEq)]
-pub struct Position {
-    x: i32,
-    y: i32,
-}
-
-impl Position {
-    pub fn new(x: i32, y: i32) -> Position {
-        Position {
-            x,
-            y,
-        }
-    }
-
-    pub fn origin() -> Position {
-        Position::new(0, 0)
-    }
-
-    pub fn x(&self) -> i32 {
-        self.x
-    }
-
-    pub fn y(&self) -> i32 {
-        self.y
-    }
-}
+use aoc_common::{Point, ParseError, Cursor};
 
 pub struct Rectangle {
-    top_left: Position,
-    top_right: Position,
-    bottom_left: Position,
-    bottom_right: Position,
+    top_left: Point,
+    top_right: Point,
+    bottom_left: Point,
+    bottom_right: Point,
 }
 
 impl Rectangle {
@@ -43,41 +16,34 @@ impl Rectangle {
             panic!("Start-y {} is expected to be smaller than end-y {}", start_y, end_y)
         }
         Rectangle {
-            top_left: Position::new(start_x, end_y),
-            top_right: Position::new(end_x, end_y),
-            bottom_left: Position::new(start_x, start_y),
-            bottom_right: Position::new(end_x, start_y),
+            top_left: Point::new(start_x, end_y),
+            top_right: Point::new(end_x, end_y),
+            bottom_left: Point::new(start_x, start_y),
+            bottom_right: Point::new(end_x, start_y),
         }
     }
 
-    pub fn from(input: &str) -> Rectangle {
-        lazy_static! {
-            static ref AREA_REGEX: Regex = Regex::new(r"x=(?P<start_x>-?\d+)\.\.(?P<end_x>-?\d+),\s*y=(?P<start_y>-?\d+)\.\.(?P<end_y>-?\d+)$").unwrap();
-        }
-
-        let captures = AREA_REGEX.captures(input);
-        if let Some(captures) = captures {
-            Rectangle::new(
-                i32::from_str_radix(&captures["start_x"], 10).unwrap(),
-                i32::from_str_radix(&captures["end_x"], 10).unwrap(),
-                i32::from_str_radix(&captures["start_y"], 10).unwrap(),
-                i32::from_str_radix(&captures["end_y"], 10).unwrap()
-            )
-        } else {
-            panic!("Failed to parse area from '{}'", input)
-        }
+    /// Parses a target area like `target area: x=20..30, y=-10..-5`.
+    pub fn from(input: &str) -> Result<Rectangle, ParseError> {
+        let mut cursor = Cursor::new(input);
+        cursor.accept("target area:");
+        let (start_x, end_x) = range_from(&mut cursor, "x=")?;
+        cursor.expect(",")?;
+        let (start_y, end_y) = range_from(&mut cursor, "y=")?;
+        cursor.end()?;
+        Ok(Rectangle::new(start_x, end_x, start_y, end_y))
     }
 
-    pub fn within(&self, pos: &Position) -> bool {
-        pos.x >= self.top_left.x &&
-        pos.x <= self.bottom_right.x &&
-        pos.y <= self.top_right.y &&
-        pos.y >= self.bottom_left.y
+    pub fn within(&self, pos: &Point) -> bool {
+        pos.x() >= self.top_left.x() &&
+        pos.x() <= self.bottom_right.x() &&
+        pos.y() <= self.top_right.y() &&
+        pos.y() >= self.bottom_left.y()
     }
 
-    pub fn far(&self, pos: &Position) -> bool {
-        pos.x > self.top_right.x ||
-        pos.y < self.bottom_right.y
+    pub fn far(&self, pos: &Point) -> bool {
+        pos.x() > self.top_right.x() ||
+        pos.y() < self.bottom_right.y()
     }
 
     pub fn horizontal_range(&self) -> (i32, i32) {
@@ -89,15 +55,27 @@ impl Rectangle {
     }
 }
 
+fn range_from(cursor: &mut Cursor, axis: &str) -> Result<(i32, i32), ParseError> {
+    cursor.expect(axis)?;
+    let column = cursor.column();
+    let start = cursor.number()?;
+    cursor.expect("..")?;
+    let end = cursor.number()?;
+    if start >= end {
+        return Err(ParseError::new(column, "a range from low to high", &format!("{}..{}", start, end)));
+    }
+    Ok((start, end))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     impl Rectangle {
-        pub fn short(&self, pos: &Position) -> bool {
-            (pos.x < self.top_left.x && pos.y > self.top_left.y) ||
-            (pos.x < self.top_left.x && pos.y >= self.bottom_left.y) ||
-            (pos.y > self.top_right.y && pos.x <= self.top_right.x)
+        pub fn short(&self, pos: &Point) -> bool {
+            (pos.x() < self.top_left.x() && pos.y() > self.top_left.y()) ||
+            (pos.x() < self.top_left.x() && pos.y() >= self.bottom_left.y()) ||
+            (pos.y() > self.top_right.y() && pos.x() <= self.top_right.x())
         }
     }
 
@@ -105,13 +83,13 @@ mod tests {
     fn test_within() {
         let rect = Rectangle::new(5, 10, -20, -10);
 
-        let center = Position::new(7, -15);
+        let center = Point::new(7, -15);
         assert_eq!(true, rect.within(&center));
 
-        let corner = Position::new(5, -20);
+        let corner = Point::new(5, -20);
         assert_eq!(true, rect.within(&corner));
 
-        let side = Position::new(10, -12);
+        let side = Point::new(10, -12);
         assert_eq!(true, rect.within(&side));
     }
 
@@ -119,24 +97,31 @@ mod tests {
     fn test_without() {
         let rect = Rectangle::new(5, 10, -20, -10);
 
-        let top_left = Position::new(2, -8);
+        let top_left = Point::new(2, -8);
         assert_eq!(false, rect.within(&top_left));
         assert_eq!(true, rect.short(&top_left));
         assert_eq!(false, rect.far(&top_left));
 
-        let bottom_right = Position::new(17, -23);
+        let bottom_right = Point::new(17, -23);
         assert_eq!(false, rect.within(&bottom_right));
         assert_eq!(false, rect.short(&bottom_right));
         assert_eq!(true, rect.far(&bottom_right));
 
-        let to_the_left_to_the_left = Position::new(4, -15);
+        let to_the_left_to_the_left = Point::new(4, -15);
         assert_eq!(false, rect.within(&to_the_left_to_the_left));
         assert_eq!(true, rect.short(&to_the_left_to_the_left));
         assert_eq!(false, rect.far(&to_the_left_to_the_left));
 
-        let bottoms_away = Position::new(6, -27);
+        let bottoms_away = Point::new(6, -27);
         assert_eq!(false, rect.within(&bottoms_away));
         assert_eq!(false, rect.short(&bottoms_away));
         assert_eq!(true, rect.far(&bottoms_away));
     }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Err(ParseError::new(1, "'x='", "x")), Rectangle::from("x").map(|_| ()));
+        assert_eq!(Err(ParseError::new(11, "'y='", "z")), Rectangle::from("x=20..30, z=-10..-5").map(|_| ()));
+        assert_eq!(Err(ParseError::new(26, "a range from low to high", "-5..-10")), Rectangle::from("target area: x=20..30, y=-5..-10").map(|_| ()));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+pub mod geometry;
+pub mod ballistics;
+pub mod triangle_number;
+
+use std::cmp;
+use aoc_common::{Puzzle, Answer, Error, ParseError, Point, Picture, Rgb, Svg};
+use geometry::Rectangle;
+
+/// Reads the target area from the first line. The launcher fires from the origin, so only a target
+/// below it and in front of it can be aimed at.
+pub fn load_target(input: &str) -> Result<Rectangle, Error> {
+    let mut lines = aoc_common::numbered_lines(input);
+    let (number, line) = lines.next().ok_or_else(|| ParseError::new(1, "a target area", ""))?;
+
+    let mut errors = vec![];
+    let target = Rectangle::from(line).map_err(|e| e.on_line(number));
+    if let Err(error) = &target {
+        errors.push(error.clone());
+    }
+    for (number, line) in lines {
+        let column = line.len() - line.trim_start().len() + 1;
+        errors.push(ParseError::new(column, "the end of the input", line.trim()).on_line(number));
+    }
+    if !errors.is_empty() {
+        return Err(Error::Parse(errors));
+    }
+
+    let target = target?;
+    if target.horizontal_range().0 <= 0 || target.vertical_range().0 >= 0 {
+        return Err(Error::NoSolution(String::from("The target area has to be below the launcher and in front of it")));
+    }
+    Ok(target)
+}
+
+pub struct Day17;
+
+impl Puzzle for Day17 {
+    type Input = Rectangle;
+
+    fn parse(input: &str) -> Result<Rectangle, Error> {
+        load_target(input)
+    }
+
+    fn part1(target: &Rectangle) -> Result<Answer, Error> {
+        match ballistics::find_fanciest_hit_arc(target) {
+            Some(hit) => Ok(hit.iter().map(|p| p.p_y()).fold(0, cmp::max).into()),
+            None => Err(Error::NoSolution(String::from("No hit was found for target area"))),
+        }
+    }
+}
+
+/// Plots the target area and the arcs of the probes launched at it, flipped so up is up.
+pub fn plot(target: &Rectangle, arcs: &[Vec<Point>]) -> Svg {
+    let (left, right) = target.horizontal_range();
+    let (top, bottom) = target.vertical_range();
+    let points = arcs.iter().flatten().map(|p| (p.x(), p.y())).chain([(0, 0), (left, top), (right, bottom)]);
+    let (mut min_x, mut max_x, mut min_y, mut max_y) = (0, 0, 0, 0);
+    for (x, y) in points {
+        min_x = cmp::min(min_x, x);
+        max_x = cmp::max(max_x, x);
+        min_y = cmp::min(min_y, y);
+        max_y = cmp::max(max_y, y);
+    }
+
+    let margin = 2;
+    let width = (max_x - min_x) as i64 + margin * 2;
+    let height = (max_y - min_y) as i64 + margin * 2;
+    let mut svg = Svg::new(min_x as i64 - margin, -(max_y as i64) - margin, width, height).sized(800, 600);
+    svg.rect(left as i64, -(top as i64), (right - left) as i64, (top - bottom) as i64, Rgb::grey(200));
+    for (i, arc) in arcs.iter().enumerate() {
+        let points: Vec<(i64, i64)> = arc.iter().map(|p| (p.x() as i64, -(p.y() as i64))).collect();
+        svg.polyline(&points, Rgb::PALETTE[i % Rgb::PALETTE.len()]);
+    }
+    svg
+}
+
+/// The arc of the probe that flies highest while still hitting the target.
+pub fn export(input: &str) -> Result<Picture, Error> {
+    let target = Day17::parse(input)?;
+    let hit = ballistics::find_fanciest_hit_arc(&target)
+        .ok_or_else(|| Error::NoSolution(String::from("No hit was found for target area")))?;
+
+    let arc: Vec<Point> = hit.iter().map(|v| *v.pos()).collect();
+    Ok(Picture::Vector(plot(&target, &[arc])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plot() {
+        let target = Rectangle::new(20, 30, -10, -5);
+        let arc = vec![Point::new(0, 0), Point::new(7, 2), Point::new(13, 3), Point::new(18, 3), Point::new(22, 2), Point::new(25, 0), Point::new(27, -3), Point::new(28, -7)];
+
+        let mut output = vec![];
+        Picture::Vector(plot(&target, &[arc])).write(aoc_common::ImageFormat::Svg, &mut output).unwrap();
+        let svg = String::from_utf8(output).unwrap();
+
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-2 -5 34 17" width="800" height="600""#));
+        assert!(svg.contains(r##"<rect x="20" y="5" width="10" height="5" fill="#c8c8c8"/>"##));
+        assert!(svg.contains(r#"points="0,0 7,-2 13,-3 18,-3 22,-2 25,0 27,3 28,7""#));
+    }
+
+    #[test]
+    fn test_load_target() {
+        let errors = vec![
+            ParseError::new(10, "','", "y").on_line(1),
+            ParseError::new(1, "the end of the input", "x=1..2, y=-2..-1").on_line(3),
+        ];
+        assert_eq!(Err(Error::Parse(errors)), load_target("x=20..30 y=-10..-5\n\nx=1..2, y=-2..-1\n").map(|_| ()));
+        assert_eq!(
+            Err(Error::NoSolution(String::from("The target area has to be below the launcher and in front of it"))),
+            load_target("target area: x=-30..-20, y=-10..-5").map(|_| ())
+        );
+    }
+}
\ No newline at end of file
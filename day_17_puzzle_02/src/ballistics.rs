@@ -1,32 +1,10 @@
 use std::cmp;
 use std::collections::{HashMap, HashSet};
 
-use crate::geometry::{Position, Rectangle};
-use crate::triangle_number::TriangleNumber;
-
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct Vector {
-    pos: Position,
-    velocity_x: i32,
-    velocity_y: i32,
-}
-
-impl Vector {
-    pub fn new(pos: Position, velocity_x: i32, velocity_y: i32) -> Vector {
-        Vector {
-            pos,
-            velocity_x,
-            velocity_y,
-        }
-    }
-
-    pub fn next_step(&self) -> Vector {
-        let next_pos = Position::new(self.pos.x() + self.velocity_x, self.pos.y() + self.velocity_y);
-        let next_velocity_x = if self.velocity_x > 0 { self.velocity_x - 1 } else if self.velocity_x == 0 { 0 } else { panic!("x velocity should never be zero") };
-        let next_velocity_y = self.velocity_y - 1;
-        Vector::new(next_pos, next_velocity_x, next_velocity_y)
-    }
-}
+use aoc_common::Point;
+pub use day_17_puzzle_01::ballistics::{Vector, hit_arc};
+use day_17_puzzle_01::triangle_number::TriangleNumber;
+use crate::geometry::Rectangle;
 
 fn find_valid_horizontal_velocities(distance_range: (i32, i32)) -> Vec<(i32, i32, i32)> {
     if distance_range.0 >= distance_range.1 {
@@ -66,8 +44,7 @@ fn find_hit_vertical_velocities(distance_range: (i32, i32), steps: i32) -> Vec<i
     let mut velocities = vec![];
 
     for velocity in 0.. {
-        let gravity = 1 - steps;
-        let fall = velocity * steps + gravity.triangle_number();
+        let fall = velocity * steps - (steps - 1).triangle_number();
         if fall >= distance_range.1 && fall <= distance_range.0 {
             velocities.push(velocity);
         } else if fall > distance_range.0 {
@@ -77,8 +54,7 @@ fn find_hit_vertical_velocities(distance_range: (i32, i32), steps: i32) -> Vec<i
 
     let mut velocity = -1;
     loop {
-        let gravity = 1 - steps;
-        let fall = velocity * steps + gravity.triangle_number();
+        let fall = velocity * steps - (steps - 1).triangle_number();
         if fall >= distance_range.1 && fall <= distance_range.0 {
             velocities.push(velocity);
         } else if fall < distance_range.1 {
@@ -90,28 +66,6 @@ fn find_hit_vertical_velocities(distance_range: (i32, i32), steps: i32) -> Vec<i
     velocities
 }
 
-pub fn hit_arc(launch: &Vector, target: &Rectangle) -> Option<Vec<Vector>> {
-    let mut ballistic_arc = vec![];
-
-    if target.within(&launch.pos) {
-        panic!("Danger close! Launching within the target zone")
-    }
-
-    let mut current = launch.clone();
-
-    loop {
-        ballistic_arc.push(current.clone());
-
-        if target.within(&current.pos) {
-            return Some(ballistic_arc);
-        } else if target.far(&current.pos) {
-            return None;
-        } else {
-            current = current.next_step();
-        }
-    }
-}
-
 pub fn find_all_hit_launches(target: &Rectangle) -> HashSet<Vector> {
 
     let horizontal_velocities = find_valid_horizontal_velocities(target.horizontal_range());
@@ -151,7 +105,7 @@ pub fn find_all_hit_launches(target: &Rectangle) -> HashSet<Vector> {
     let mut find_hits = |&steps, horizontal| {
         if let Some(verticals) = step_vertical_velocities.get(&steps) {
             for vertical in verticals.iter() {
-                let launch = Vector::new(Position::origin(), horizontal, *vertical);
+                let launch = Vector::new(Point::origin(), horizontal, *vertical);
                 let hit = hit_arc(&launch, &target);
                 if hit.is_some() {
                     hit_launches.insert(launch);
@@ -179,88 +133,6 @@ mod tests {
 
     use super::*;
 
-    #[test]
-    fn test_vector_next() {
-        let origin = Vector::new(Position::origin(), 5, 5);
-
-        let step_1 = origin.next_step();
-        assert_eq!(Vector::new(Position::new(5, 5), 4, 4), step_1);
-
-        let step_2 = step_1.next_step();
-        assert_eq!(Vector::new(Position::new(9, 9), 3, 3), step_2);
-
-        let step_3 = step_2.next_step();
-        assert_eq!(Vector::new(Position::new(12, 12), 2, 2), step_3);
-
-        let step_4 = step_3.next_step();
-        assert_eq!(Vector::new(Position::new(14, 14), 1, 1), step_4);
-
-        let step_5 = step_4.next_step();
-        assert_eq!(Vector::new(Position::new(15, 15), 0, 0), step_5);
-
-        let step_6 = step_5.next_step();
-        assert_eq!(Vector::new(Position::new(15, 15), 0, -1), step_6);
-
-        let step_7 = step_6.next_step();
-        assert_eq!(Vector::new(Position::new(15, 14), 0, -2), step_7);
-
-        let step_8 = step_7.next_step();
-        assert_eq!(Vector::new(Position::new(15, 12), 0, -3), step_8);
-
-        let step_9 = step_8.next_step();
-        assert_eq!(Vector::new(Position::new(15, 9), 0, -4), step_9);
-
-        let step_10 = step_9.next_step();
-        assert_eq!(Vector::new(Position::new(15, 5), 0, -5), step_10);
-    }
-
-    #[test]
-    fn test_example_1() {
-        let launch = Vector::new(Position::origin(), 7, 2);
-
-        let target = Rectangle::new(20, 30, -10, -5);
-
-        let arc = hit_arc(&launch, &target);
-
-        assert_eq!(true, arc.is_some());
-        assert_eq!(Position::new(28, -7), arc.unwrap().last().unwrap().pos);
-    }
-
-    #[test]
-    fn test_example_2() {
-        let launch = Vector::new(Position::origin(), 6, 3);
-
-        let target = Rectangle::new(20, 30, -10, -5);
-
-        let arc = hit_arc(&launch, &target);
-
-        assert_eq!(true, arc.is_some());
-        assert_eq!(Position::new(21, -9), arc.unwrap().last().unwrap().pos);
-    }
-
-    #[test]
-    fn test_example_3() {
-        let launch = Vector::new(Position::origin(), 9, 0);
-
-        let target = Rectangle::new(20, 30, -10, -5);
-
-        let arc = hit_arc(&launch, &target);
-
-        assert_eq!(true, arc.is_some());
-        assert_eq!(Position::new(30, -6), arc.unwrap().last().unwrap().pos);
-    }
-
-    #[test]
-    fn test_example_4() {
-        let launch = Vector::new(Position::origin(), 17, -4);
-
-        let target = Rectangle::new(20, 30, -10, -5);
-
-        let arc = hit_arc(&launch, &target);
-
-        assert_eq!(false, arc.is_some());
-    }
-
     #[test]
     fn test_example_all_valid_launches() {
         let target = Rectangle::new(20, 30, -10, -5);
@@ -291,7 +163,7 @@ mod tests {
         let mut actual_hit_launches = HashSet::new();
         for pair in pairs.iter() {
             let v_pair: Vec<i32> = pair.split(",").map(|v| i32::from_str_radix(v, 10).unwrap()).collect();
-            actual_hit_launches.insert(Vector::new(Position::origin(), v_pair[0], v_pair[1]));
+            actual_hit_launches.insert(Vector::new(Point::origin(), v_pair[0], v_pair[1]));
         }
 
         assert_eq!(112, actual_hit_launches.len());
@@ -308,7 +180,7 @@ mod tests {
     #[test]
     fn tests_7_6() {
         let target = Rectangle::new(20, 30, -10, -5);
-        let launch = Vector::new(Position::origin(), 6, 5);
+        let launch = Vector::new(Point::origin(), 6, 5);
         let hit = hit_arc(&launch, &target);
         assert_eq!(true, hit.is_some());
 
@@ -0,0 +1,33 @@
+pub mod ballistics;
+
+pub use day_17_puzzle_01::{geometry, triangle_number, load_target};
+
+use aoc_common::{Puzzle, Answer, Error, Point, Picture};
+use geometry::Rectangle;
+
+pub struct Day17;
+
+impl Puzzle for Day17 {
+    type Input = Rectangle;
+
+    fn parse(input: &str) -> Result<Rectangle, Error> {
+        day_17_puzzle_01::load_target(input)
+    }
+
+    fn part2(target: &Rectangle) -> Result<Answer, Error> {
+        Ok(ballistics::find_all_hit_launches(target).len().into())
+    }
+}
+
+/// Every arc that hits the target, in order of launch velocity.
+pub fn export(input: &str) -> Result<Picture, Error> {
+    let target = Day17::parse(input)?;
+    let mut launches: Vec<_> = ballistics::find_all_hit_launches(&target).into_iter().collect();
+    launches.sort_by_key(|l| l.velocity());
+
+    let arcs: Vec<Vec<Point>> = launches.iter()
+        .filter_map(|l| ballistics::hit_arc(l, &target))
+        .map(|arc| arc.iter().map(|v| *v.pos()).collect())
+        .collect();
+    Ok(Picture::Vector(day_17_puzzle_01::plot(&target, &arcs)))
+}
@@ -1,20 +1,16 @@
-#[macro_use]
-extern crate lazy_static;
-
-mod geometry;
-mod ballistics;
-mod triangle_number;
-
-use std::fs;
-use crate::geometry::Rectangle;
+use aoc_common::InputSource;
+use day_17_puzzle_02::Day17;
 
 fn main() -> std::io::Result<()> {
-    let input = fs::read_to_string("./day_17_puzzle_01/input.txt").unwrap();
-
-    let target = Rectangle::from(&input.trim());
+    let input = InputSource::from_args("./day_17_puzzle_01/input.txt").read_to_string()?;
 
-    let all_hit_launches = ballistics::find_all_hit_launches(&target);
-    println!("Found {} unique hit launches", all_hit_launches.len());
+    match aoc_common::solve_part2::<Day17>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
+    }
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,3 @@
+// there is no `Puzzle` for this day yet, so the `aoc` runner leaves it out until the scanners can be matched up
+pub mod location;
+pub mod scanner;
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::cmp;
+use aoc_common::{ParseError, Cursor};
 
 const AXES_COUNT: usize = 3;
 
@@ -13,18 +14,18 @@ const MIN_MATCHING_LOCATION_COUNT: usize = 12;
 
 pub type Location = [i32; AXES_COUNT];
 
-pub fn location_from(input: &str) -> Location {
-    if input.split(",").count() != 3 {
-        panic!("Locations should be triples - found {} values in '{}'", input.split(",").count(), input)
+/// Parses a triple like `-618,-824,-621`.
+pub fn location_from(input: &str) -> Result<Location, ParseError> {
+    let mut cursor = Cursor::new(input);
+    let mut location = [0; AXES_COUNT];
+    for (axis, value) in location.iter_mut().enumerate() {
+        if axis > 0 {
+            cursor.expect(",")?;
+        }
+        *value = cursor.number()?;
     }
-    let parse = |v| i32::from_str_radix(v, 10).unwrap();
-    let mut split = input.split(",");
-
-    [
-        parse(split.next().unwrap()),
-        parse(split.next().unwrap()),
-        parse(split.next().unwrap()),
-    ]
+    cursor.end()?;
+    Ok(location)
 }
 
 #[derive(PartialEq, Debug, Clone, Copy)]
@@ -114,14 +115,6 @@ fn rotate_on_axis(location: &mut Location, axis: usize, angle: Angle) {
     }
 }
 
-fn locations_from(input: &str) -> Vec<Location> {
-    input
-        .split("\n")
-        .filter(|l| !l.trim().is_empty())
-        .map(|l| location_from(l.trim()))
-        .collect()
-}
-
 fn sort_locations(locations: &mut Vec<Location>) {
     locations.sort_by(|a, b| {
         let a_sum: i32 = a.iter().map(|v| v.abs()).sum();
@@ -273,6 +266,20 @@ pub fn distance_matrices_common_pairs(matrix_a: &DistanceMatrix, matrix_b: &Dist
 mod tests {
     use super::*;
     use Angle::*;
+    use aoc_common::Error;
+
+    #[test]
+    fn parse_location() {
+        assert_eq!(Ok([-618, -824, -621]), location_from("-618,-824,-621"));
+        assert_eq!(Err(ParseError::new(8, "','", "")), location_from("404,-58"));
+        assert_eq!(Err(ParseError::new(5, "a number", "x")), location_from("404,x,1"));
+
+        let errors = vec![
+            ParseError::new(6, "end of line", ",").on_line(1),
+            ParseError::new(1, "a number", "y").on_line(3),
+        ];
+        assert_eq!(Err(Error::Parse(errors)), locations_from("1,2,3,4\n5,6,7\ny,8,9"));
+    }
 
     #[test]
     fn relative_to_zero() {
@@ -503,7 +510,7 @@ mod tests {
         630,319,-379
         443,580,662
         -789,900,-551
-        459,-707,401");
+        459,-707,401").unwrap();
 
         let b = locations_from("686,422,578
         605,423,415
@@ -529,7 +536,7 @@ mod tests {
         -364,-763,-893
         807,-499,-711
         755,-354,-619
-        553,889,-390");
+        553,889,-390").unwrap();
 
         let matrix_a = distance_matrix(&a);
         let matrix_b = distance_matrix(&b);
@@ -563,7 +570,7 @@ mod tests {
         630,319,-379
         443,580,662
         -789,900,-551
-        459,-707,401");
+        459,-707,401").unwrap();
 
         let b = locations_from("686,422,578
         605,423,415
@@ -589,8 +596,12 @@ mod tests {
         -364,-763,-893
         807,-499,-711
         755,-354,-619
-        553,889,-390");
+        553,889,-390").unwrap();
 
         assert_eq!(true, sets_match(&a, &b));
     }
-}
\ No newline at end of file
+
+    fn locations_from(input: &str) -> Result<Vec<Location>, Error> {
+        aoc_common::parse_lines(input, location_from)
+    }
+}
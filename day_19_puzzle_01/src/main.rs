@@ -1,8 +1,6 @@
-mod location;
-mod scanner;
-
 use std::collections::HashMap;
-use crate::location::{Location, Angle, Rotation};
+use day_19_puzzle_01::location::{Location, Angle, Rotation};
+use day_19_puzzle_01::location;
 
 fn main() {
     let all_angles = [Angle::Zero, Angle::Ninety, Angle::OneEighty, Angle::TwoSeventy];
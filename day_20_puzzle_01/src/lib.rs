@@ -0,0 +1,88 @@
+pub mod pixel;
+
+use aoc_common::{Puzzle, Answer, Error, ParseError, Picture};
+use pixel::Image;
+
+/// Enhances the image the given number of times.
+pub fn enhanced(enhancement: &str, image: &Image, steps: usize) -> Image {
+    let mut image = image.clone();
+    for _ in 0..steps {
+        image = image.enhance(enhancement);
+    }
+
+    image
+}
+
+fn lit_pixels_after(enhancement: &str, image: &Image, steps: usize) -> usize {
+    enhanced(enhancement, image, steps).lit_pixel_count()
+}
+
+/// Reads the enhancement algorithm from the first line and the image from the rest. Every bad line
+/// is reported, not just the first.
+pub fn load_image(input: &str) -> Result<(String, Image), Error> {
+    let mut lines = aoc_common::numbered_lines(input).map(|(number, line)| (number, line.trim()));
+    let (number, enhancement) = lines.next().ok_or_else(|| ParseError::new(1, "the enhancement algorithm", ""))?;
+
+    let mut errors = vec![];
+    match pixel::parse_pixels(enhancement) {
+        Ok(pixels) if pixels.len() != pixel::ENHANCEMENT_SIZE => {
+            let expected = format!("{} pixels in the enhancement algorithm", pixel::ENHANCEMENT_SIZE);
+            errors.push(ParseError::new(1, &expected, &format!("{} pixels", pixels.len())).on_line(number));
+        },
+        Ok(_) => {},
+        Err(error) => errors.push(error.on_line(number)),
+    }
+
+    let mut rows: Vec<String> = vec![];
+    for (number, line) in lines {
+        match pixel::parse_pixels(line) {
+            Ok(row) if !rows.is_empty() && row.len() != rows[0].len() => {
+                let expected = format!("a row of {} pixels", rows[0].len());
+                errors.push(ParseError::new(1, &expected, line).on_line(number));
+            },
+            Ok(_) => rows.push(String::from(line)),
+            Err(error) => errors.push(error.on_line(number)),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::Parse(errors));
+    }
+
+    Ok((enhancement.to_string(), Image::new(rows)))
+}
+
+pub struct Day20;
+
+impl Puzzle for Day20 {
+    type Input = (String, Image);
+
+    fn parse(input: &str) -> Result<(String, Image), Error> {
+        load_image(input)
+    }
+
+    fn part1((enhancement, image): &(String, Image)) -> Result<Answer, Error> {
+        Ok(lit_pixels_after(enhancement, image, 2).into())
+    }
+}
+
+/// The image after being enhanced twice.
+pub fn export(input: &str) -> Result<Picture, Error> {
+    let (enhancement, image) = Day20::parse(input)?;
+    Ok(Picture::Raster(enhanced(&enhancement, &image, 2).to_raster().scaled(4)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_errors() {
+        let errors = vec![
+            ParseError::new(1, "512 pixels in the enhancement algorithm", "3 pixels").on_line(1),
+            ParseError::new(2, "a pixel ('.' or '#')", "x").on_line(3),
+            ParseError::new(1, "a row of 2 pixels", "#.#").on_line(5),
+        ];
+        assert_eq!(Err(Error::Parse(errors)), load_image("..#\n\n#x\n##\n#.#\n").map(|_| ()));
+    }
+}
\ No newline at end of file
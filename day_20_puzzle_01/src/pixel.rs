@@ -1,51 +1,34 @@
-use regex::Regex;
 use std::fmt::{self, Display};
-
-pub type PixelGrid = Vec<String>;
+use aoc_common::{Grid, Frame, Cell, Colour, Raster, Rgb, Pool, ParseError};
 
 const DARK: char = '.';
 const LIGHT: char = '#';
 
+/// How many pixels the enhancement algorithm has, one for every 3x3 square of pixels.
+pub const ENHANCEMENT_SIZE: usize = 512;
+
+#[derive(Clone)]
 pub struct Image {
-	pixels: PixelGrid,
+	pixels: Grid<char>,
 	infinite_pixels: char,
 }
 
 impl Image {
-	pub fn new(pixels: PixelGrid) -> Image {
+	pub fn new(rows: Vec<String>) -> Image {
+		let pixels = Grid::from_rows(rows.iter().map(|r| r.chars().collect()).collect());
 		Image {
 			pixels,
 			infinite_pixels: DARK,
 		}
 	}
 
-	pub fn size(&self) -> isize {
-		self.pixels.len() as isize
-	}
-
 	fn decode_pixel(&self, x: isize, y: isize) -> u32 {
 		let mut line = String::new();
 
-		let lower_limit = 0;
-		let upper_limit = self.size() - 1;
-		
 		for row in y - 1..=y + 1 {
-			if row < lower_limit || row > upper_limit {
-				for _ in 0..3 {
-					line.push(self.infinite_pixels);
-				}
-			} else {
-				for column in x - 1..=x + 1 {
-					if column < lower_limit || column > upper_limit {
-						line.push(self.infinite_pixels);
-					} else {
-						if let Some(p) = self.pixels[row as usize].chars().nth(column as usize) {
-							line.push(p);
-						} else {
-							panic!("Failed to find a pixel as location ({}, {}) in a square of size {}", column, row, self.size())
-						}
-					}
-				}
+			for column in x - 1..=x + 1 {
+				// everything past the edge of the image matches the infinite pixels
+				line.push(*self.pixels.at(column, row).unwrap_or(&self.infinite_pixels));
 			}
 		}
 
@@ -57,19 +40,25 @@ impl Image {
 		enhancement.chars().nth(decoded as usize).unwrap()
 	}
 
+	/// Enhances every pixel, and the infinite ones around them. The enhancement has to have all
+	/// `ENHANCEMENT_SIZE` pixels, which `load_image` checks.
 	pub fn enhance(&self, enhancement: &str) -> Image {
-		let mut enhanced_pixels: PixelGrid = vec![];
-		for row in -1..=self.size() {
-			let mut line = String::new();
-			for column in -1..=self.size() {
-				line.push(self.enhance_pixel(enhancement, column, row));
-			}
-			enhanced_pixels.push(line);
+		// every pixel only depends on the image before it, so the rows are worked out on separate threads
+		let rows: Vec<isize> = (-1..=self.pixels.height() as isize).collect();
+		let lines = Pool::configured().map(&rows, |&row| {
+			(-1..=self.pixels.width() as isize)
+				.map(|column| self.enhance_pixel(enhancement, column, row))
+				.collect()
+		});
+
+		let mut enhanced_pixels = Grid::new();
+		for line in lines {
+			enhanced_pixels.push_row(line);
 		}
 
 		let enhanced_infinite_pixels = if self.infinite_pixels == DARK {
 			// all zeros means an index of 000000000
-			enhancement.chars().nth(0).unwrap()
+			enhancement.chars().next().unwrap()
 		} else {
 			// all ones means an index of 111111111
 			enhancement.chars().nth(511).unwrap()
@@ -82,46 +71,48 @@ impl Image {
 	}
 
 	pub fn lit_pixel_count(&self) -> usize {
-		let mut count = 0;
-		for row in self.pixels.iter() {
-			count += row.chars()
-				.filter(|p| *p == '#')
-				.count();
-		}
-		count
+		self.pixels.values().filter(|p| **p == LIGHT).count()
+	}
+
+	pub fn to_frame(&self, title: &str) -> Frame {
+		Frame::from_grid(title, &self.pixels, |_, &pixel| {
+			if pixel == LIGHT {
+				Cell::coloured(LIGHT, Colour::Bright)
+			} else {
+				Cell::coloured(DARK, Colour::Dim)
+			}
+		})
+	}
+
+	pub fn to_raster(&self) -> Raster {
+		Raster::from_grid(&self.pixels, |_, &pixel| if pixel == LIGHT { Rgb::WHITE } else { Rgb::BLACK })
 	}
 }
 
 impl Display for Image {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		for (index, row) in self.pixels.iter().enumerate() {
-			if index > 0 {
-				write!(f, "\n")?;
-			}
-			write!(f, "{}", row)?;
-		}
-		Ok(())
+		write!(f, "{}", self.pixels)
 	}
 }
 
-pub fn decode(input: &str) -> u32 {
-	lazy_static! {
-		static ref PIXEL_FORMAT_REGEX: Regex = Regex::new(r"^[.#]+$").unwrap();
-	}
-	if !PIXEL_FORMAT_REGEX.is_match(input) {
-		panic!("Invalid input '{}' - expected a series of . and # characters", input)
-	}
+/// Reads a line of pixels, reporting the first character that isn't one.
+pub fn parse_pixels(input: &str) -> Result<Vec<char>, ParseError> {
+	input
+		.chars()
+		.enumerate()
+		.map(|(i, c)| if c == DARK || c == LIGHT { Ok(c) } else { Err(ParseError::new(i + 1, "a pixel ('.' or '#')", &c.to_string())) })
+		.collect()
+}
 
-	let binary = input
-		.replace(DARK, "0")
-		.replace(LIGHT, "1");
-	
-	u32::from_str_radix(&binary, 2).unwrap()
+/// The number a line of pixels spells out in binary, with the light pixels as ones.
+pub fn decode(input: &str) -> u32 {
+	input.chars().fold(0, |value, pixel| value * 2 + if pixel == LIGHT { 1 } else { 0 })
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_common::Position;
 
 	pub fn from(input: &str) -> Image {
 		let pixels = input
@@ -130,22 +121,18 @@ mod tests {
 			.filter(|l| !l.is_empty())
 			.collect();
 
-		Image {
-			pixels,
-			infinite_pixels: DARK,
-		}
+		Image::new(pixels)
 	}
 
 	#[test]
-	#[should_panic(expected = "Invalid input 'this is not pixel data' - expected a series of . and # characters")]
-	fn decode_invalid_content() {
-		decode("this is not pixel data");
+	fn parse_invalid_content() {
+		assert_eq!(Err(ParseError::new(1, "a pixel ('.' or '#')", "t")), parse_pixels("this is not pixel data"));
+		assert_eq!(Err(ParseError::new(3, "a pixel ('.' or '#')", "x")), parse_pixels("#.x#"));
 	}
 
 	#[test]
-	#[should_panic(expected = "Invalid input '' - expected a series of . and # characters")]
-	fn decode_invalid_empty() {
-		decode("");
+	fn parse_valid() {
+		assert_eq!(Ok(vec!['#', '.', '.']), parse_pixels("#.."));
 	}
 
 	#[test]
@@ -231,4 +218,40 @@ mod tests {
 
 		assert_eq!(35, enhanced_enhanced_image.lit_pixel_count());
 	}
+
+	#[test]
+	fn image_to_frame() {
+		let image = from("
+#..
+.##
+		");
+
+		let frame = image.to_frame("Step 0");
+		assert_eq!("Step 0\n#..\n.##\n", frame.to_string());
+		assert_eq!(Colour::Bright, frame.cells()[Position::new(0, 0)].colour());
+		assert_eq!(Colour::Dim, frame.cells()[Position::new(1, 0)].colour());
+
+		let raster = image.to_raster();
+		assert_eq!(Rgb::WHITE, raster.pixels()[Position::new(0, 0)]);
+		assert_eq!(Rgb::BLACK, raster.pixels()[Position::new(0, 1)]);
+	}
+
+	#[test]
+	fn image_enhance_50() {
+		let mut image = from("
+#..#.
+#....
+##..#
+..#..
+..###
+		");
+
+		let enhancement = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#";
+
+		for _ in 0..50 {
+			image = image.enhance(enhancement);
+		}
+
+		assert_eq!(3351, image.lit_pixel_count());
+	}
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+pub use day_20_puzzle_01::{pixel, load_image, enhanced};
+
+use aoc_common::{Puzzle, Answer, Error, Frame, Picture};
+use pixel::Image;
+
+pub struct Day20;
+
+impl Puzzle for Day20 {
+    type Input = (String, Image);
+
+    fn parse(input: &str) -> Result<(String, Image), Error> {
+        day_20_puzzle_01::load_image(input)
+    }
+
+    fn part2((enhancement, image): &(String, Image)) -> Result<Answer, Error> {
+        Ok(enhanced(enhancement, image, 50).lit_pixel_count().into())
+    }
+}
+
+/// The image as it starts and after each of the 50 enhancements, growing by a pixel on every side each time.
+pub fn visualize(input: &str) -> Result<Vec<Frame>, Error> {
+    let (enhancement, mut image) = Day20::parse(input)?;
+
+    let mut frames = vec![image.to_frame(&format!("Step 0 - {} lit", image.lit_pixel_count()))];
+    for step in 1..=50 {
+        image = image.enhance(&enhancement);
+        frames.push(image.to_frame(&format!("Step {} - {} lit", step, image.lit_pixel_count())));
+    }
+
+    Ok(frames)
+}
+
+/// The image after being enhanced 50 times.
+pub fn export(input: &str) -> Result<Picture, Error> {
+    let (enhancement, image) = Day20::parse(input)?;
+    Ok(Picture::Raster(day_20_puzzle_01::enhanced(&enhancement, &image, 50).to_raster().scaled(2)))
+}
@@ -1,42 +1,16 @@
-#[macro_use]
-extern crate lazy_static;
-
-mod pixel;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use crate::pixel::Image;
+use aoc_common::InputSource;
+use day_20_puzzle_02::Day20;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_20_puzzle_01/input.txt")?;
-    let lines = BufReader::new(file).lines();
-
-    let mut enhancement: Option<String> = None;
-    let mut pixels = vec![];
-    
-    for (index, line) in lines.enumerate() {
-        if line.is_err() {
-            continue;
-        }
-        let line = line.unwrap();
-        if line.trim().is_empty() {
-            continue;
-        }
-        if index == 0 {
-            enhancement = Some(line);
-        } else {
-            pixels.push(line);
-        }
-    }
-
-    let enhancement = enhancement.unwrap();
+    let input = InputSource::from_args("./day_20_puzzle_01/input.txt").read_to_string()?;
 
-    let mut image = Image::new(pixels);
-    for i in 0..50 {
-        image = image.enhance(&enhancement);
-        println!("{}:{}", i + 1, image.lit_pixel_count());
+    match aoc_common::solve_part2::<Day20>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
-    
 
     Ok(())
-}
+}
\ No newline at end of file
@@ -1,15 +1,18 @@
+use aoc_common::Error;
 use crate::die::Die;
 use crate::player::Player;
 
-pub fn play_game(die: &mut Die, player_1: &mut Player, player_2: &mut Player) {
+pub fn play_game(die: &mut Die, player_1: &mut Player, player_2: &mut Player) -> Result<(), Error> {
 	loop {
-		if player_1.take_turn(die) {
+		if player_1.take_turn(die)? {
 			break;
 		}
-		if player_2.take_turn(die) {
+		if player_2.take_turn(die)? {
 			break;
 		}
 	}
+
+	Ok(())
 }
 
 #[cfg(test)]
@@ -23,7 +26,7 @@ mod tests {
 		let mut player_1 = Player::new(4);
 		let mut player_2 = Player::new(8);
 
-		play_game(&mut die, &mut player_1, &mut player_2);
+		play_game(&mut die, &mut player_1, &mut player_2).unwrap();
 
 		assert_eq!(1000, player_1.score());
 		assert_eq!(745, player_2.score());
@@ -0,0 +1,52 @@
+pub mod die;
+pub mod player;
+pub mod game;
+
+use aoc_common::{Puzzle, Answer, Error, ParseError, Cursor, Checked};
+use die::Die;
+use player::Player;
+
+/// Reads a line like `Player 1 starting position: 4`.
+fn starting_position(line: Option<(usize, &str)>, player: u8) -> Result<u8, ParseError> {
+    let (number, line) = line.ok_or_else(|| ParseError::new(1, &format!("a starting position for player {}", player), ""))?;
+    let parse = || {
+        let mut cursor = Cursor::new(line);
+        cursor.expect(&format!("Player {} starting position:", player))?;
+        let position = cursor.number()?;
+        cursor.end()?;
+        Ok(position)
+    };
+    parse().map_err(|e: ParseError| e.on_line(number))
+}
+
+pub struct Day21;
+
+impl Puzzle for Day21 {
+    type Input = (u8, u8);
+
+    fn parse(input: &str) -> Result<(u8, u8), Error> {
+        let mut lines = aoc_common::numbered_lines(input);
+        let player_1 = starting_position(lines.next(), 1);
+        let player_2 = starting_position(lines.next(), 2);
+
+        match (player_1, player_2) {
+            (Ok(player_1), Ok(player_2)) => Ok((player_1, player_2)),
+            (player_1, player_2) => Err(Error::Parse(player_1.err().into_iter().chain(player_2.err()).collect())),
+        }
+    }
+
+    fn part1(&(start_1, start_2): &(u8, u8)) -> Result<Answer, Error> {
+        let mut player_1 = Player::new(start_1);
+        let mut player_2 = Player::new(start_2);
+        let mut die = Die::new();
+
+        game::play_game(&mut die, &mut player_1, &mut player_2)?;
+
+        let (winner, looser) = if player_1.winner() { (&player_1, &player_2) } else { (&player_2, &player_1) };
+
+        Ok(Answer::from((looser.score() as u32).mul_checked(die.roll_count(), "losing score * roll count")?)
+            .with_detail("winner_score", winner.score())
+            .with_detail("loser_score", looser.score())
+            .with_detail("roll_count", die.roll_count()))
+    }
+}
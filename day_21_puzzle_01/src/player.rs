@@ -1,3 +1,4 @@
+use aoc_common::{Checked, Error};
 use crate::die::Die;
 
 pub struct Player {
@@ -17,11 +18,11 @@ impl Player {
 		self.score
 	}
 
-	pub fn take_turn(&mut self, die: &mut Die) -> bool {
+	pub fn take_turn(&mut self, die: &mut Die) -> Result<bool, Error> {
 		for _ in 0..3 {
 			let roll = die.next().unwrap();
 			let movement = roll % 10;
-			let new_position = self.position + movement;
+			let new_position = self.position.add_checked(movement, "position + roll")?;
 			let adjusted_position = if new_position <= 10 {
 				new_position
 			} else {
@@ -37,9 +38,9 @@ impl Player {
 			};
 			self.position = adjusted_position;
 		}
-		self.score += self.position as u16;
+		self.score = self.score.add_checked(self.position as u16, "score + position")?;
 
-		self.winner()
+		Ok(self.winner())
 	}
 
 	pub fn winner(&self) -> bool {
@@ -58,35 +59,35 @@ mod tests {
 		let mut player_1 = Player::new(4);
 		let mut player_2 = Player::new(8);
 
-		player_1.take_turn(&mut die);
+		player_1.take_turn(&mut die).unwrap();
 		assert_eq!(10, player_1.position);
 		assert_eq!(10, player_1.score);
 
-		player_2.take_turn(&mut die);
+		player_2.take_turn(&mut die).unwrap();
 		assert_eq!(3, player_2.position);
 		assert_eq!(3, player_2.score);
 
-		player_1.take_turn(&mut die);
+		player_1.take_turn(&mut die).unwrap();
 		assert_eq!(4, player_1.position);
 		assert_eq!(14, player_1.score);
 
-		player_2.take_turn(&mut die);
+		player_2.take_turn(&mut die).unwrap();
 		assert_eq!(6, player_2.position);
 		assert_eq!(9, player_2.score);
 
-		player_1.take_turn(&mut die);
+		player_1.take_turn(&mut die).unwrap();
 		assert_eq!(6, player_1.position);
 		assert_eq!(20, player_1.score);
 
-		player_2.take_turn(&mut die);
+		player_2.take_turn(&mut die).unwrap();
 		assert_eq!(7, player_2.position);
 		assert_eq!(16, player_2.score);
 
-		player_1.take_turn(&mut die);
+		player_1.take_turn(&mut die).unwrap();
 		assert_eq!(6, player_1.position);
 		assert_eq!(26, player_1.score);
 
-		player_2.take_turn(&mut die);
+		player_2.take_turn(&mut die).unwrap();
 		assert_eq!(6, player_2.position);
 		assert_eq!(22, player_2.score);
 	}
@@ -99,10 +100,10 @@ mod tests {
 		let mut player_2 = Player::new(8);
 
 		loop {
-			if player_1.take_turn(&mut die) {
+			if player_1.take_turn(&mut die).unwrap() {
 				break;
 			}
-			if player_2.take_turn(&mut die) {
+			if player_2.take_turn(&mut die).unwrap() {
 				break;
 			}
 		}
@@ -111,4 +112,16 @@ mod tests {
 		assert_eq!(745, player_2.score);
 		assert_eq!(993, die.roll_count());
 	}
+
+	#[test]
+	fn score_overflow() {
+		let mut die = Die::new();
+
+		let mut player = Player {
+			position: 4,
+			score: u16::MAX - 5,
+		};
+
+		assert_eq!(Err(Error::Overflow(String::from("score + position"))), player.take_turn(&mut die));
+	}
 }
\ No newline at end of file
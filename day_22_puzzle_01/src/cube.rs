@@ -1,7 +1,7 @@
 use std::cmp;
 use crate::range::{self, CubeRange};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Cuboid {
     ranges: Vec<CubeRange>,
 }
@@ -39,22 +39,25 @@ impl Cuboid {
         }
     }
 
-    pub fn area(&self) -> i64 {
-        let limited_area_ranges = self.ranges
-            .iter()
-            .map(|r| apply_area_limit(r))
-            .filter(|r| r.is_some())
-            .map(|r| r.unwrap()).
-            collect::<Vec<_>>();
-        
-        // if any dimension is fully outside the limit then the whole thing is
-        if limited_area_ranges.len() == 3 {
-            limited_area_ranges.iter().map(|r| (r.end() - r.start() + 1) as i64).product()
+    pub fn area(&self, apply_limit: bool) -> i64 {
+        if apply_limit {
+            let limited_area_ranges = self.ranges
+                .iter()
+                .map(|r| apply_area_limit(r))
+                .filter(|r| r.is_some())
+                .map(|r| r.unwrap()).
+                collect::<Vec<_>>();
+            
+            // if any dimension is fully outside the limit then the whole thing is
+            if limited_area_ranges.len() == 3 {
+                limited_area_ranges.iter().map(|r| (r.end() - r.start() + 1) as i64).product()
+            } else {
+                0
+            }
         } else {
-            0
+            // without the limit (why did they give a limit?)
+            self.ranges.iter().map(|r| (r.end() - r.start() + 1) as i64).product()
         }
-        // without the limit (why did they give a limit?)
-        // self.ranges.iter().map(|r| (r.end() - r.start() + 1) as i64).product()
     }
 
     pub fn intersection(&self, other: &Cuboid) ->Option<Cuboid> {
@@ -150,13 +153,13 @@ mod tests {
     #[test]
     fn cuboid_area() {
         let three_cubed = Cuboid::new(10..=12, 10..=12, 10..=12);
-        assert_eq!(27, three_cubed.area());
+        assert_eq!(27, three_cubed.area(false));
 
         let flat_square = Cuboid::new(1..=10, 11..=20, -9..=-9);
-        assert_eq!(100, flat_square.area());
+        assert_eq!(100, flat_square.area(false));
 
         let irregular_cube = Cuboid::new(13..=37, -5..=5, 0..=12);
-        assert_eq!(3575, irregular_cube.area());
+        assert_eq!(3575, irregular_cube.area(false));
     }
 
     #[test]
@@ -290,7 +293,7 @@ mod tests {
         }
 
         let sum = |s: &CuboidSet| -> i64 {
-            s.iter().map(|c| c.area()).sum()
+            s.iter().map(|c| c.area(false)).sum()
         };
 
         assert_eq!(sum(left), sum(right));
@@ -0,0 +1,23 @@
+pub use day_22_puzzle_01::{cube, range, reactor};
+
+use aoc_common::{Puzzle, Answer, Error};
+use reactor::{Reactor, RebootStep};
+
+pub struct Day22;
+
+impl Puzzle for Day22 {
+    type Input = Vec<RebootStep>;
+
+    fn parse(input: &str) -> Result<Vec<RebootStep>, Error> {
+        aoc_common::parse_lines(input, RebootStep::parse)
+    }
+
+    fn part2(steps: &Vec<RebootStep>) -> Result<Answer, Error> {
+        let mut reactor = Reactor::new();
+        for step in steps.iter() {
+            reactor = reactor.run(step);
+        }
+
+        Ok(reactor.cube_count(false).into())
+    }
+}
@@ -1,31 +1,16 @@
-#[macro_use]
-extern crate lazy_static;
-
-mod cube;
-mod range;
-mod reactor;
-
-use std::fs::File;
-use std::io::{BufReader, BufRead};
-use crate::reactor::Reactor;
+use aoc_common::InputSource;
+use day_22_puzzle_02::Day22;
 
 fn main() -> std::io::Result<()> {
-    let file = File::open("./day_22_puzzle_01/input.txt")?;
-
-    let lines = BufReader::new(file).lines();
-
-    let mut reactor = Reactor::new();
-
-    for line in lines {
-        if let Ok(entry) = line {
-            if entry.trim().is_empty() {
-                continue;
-            }
-            reactor = reactor.run_command(&entry.trim());
-        }
+    let input = InputSource::from_args("./day_22_puzzle_01/input.txt").read_to_string()?;
+
+    match aoc_common::solve_part2::<Day22>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        },
     }
 
-    println!("{}", reactor.cube_count(false));
-
     Ok(())
-}
+}
\ No newline at end of file